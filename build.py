@@ -17,8 +17,11 @@ def compile_process(process_dir, pkg_dir, root_dir):
     # Change to the process directory
     os.chdir(process_dir)
 
+    # Processes are members of the workspace at root_dir, which owns the target directory
+    target_dir = os.path.join(root_dir, "target")
+
     # Create the target/bindings/$name/ directory
-    bindings_dir = os.path.join(process_dir, "target", "bindings", os.path.basename(process_dir))
+    bindings_dir = os.path.join(target_dir, "bindings", os.path.basename(process_dir))
     os.makedirs(bindings_dir, exist_ok=True)
 
     # Create target.wasm (compiled .wit) & world
@@ -33,9 +36,9 @@ def compile_process(process_dir, pkg_dir, root_dir):
     shutil.copytree(os.path.join(root_dir, "wit"), os.path.join(bindings_dir, "wit"), dirs_exist_ok=True)
     # shutil.copy(os.path.join(root_dir, "world"), os.path.join(bindings_dir, "world"))
 
-    # Create an empty world file
+    # Name the world to bind, as the wit package also has one for libraries
     with open(os.path.join(bindings_dir, "world"), 'w') as f:
-        pass
+        f.write("uq-process")
 
     # Build the module using Cargo
     subprocess.check_call([
@@ -46,7 +49,7 @@ def compile_process(process_dir, pkg_dir, root_dir):
     ])
 
     # Adapt the module using wasm-tools
-    wasm_file = os.path.join(process_dir, "target", "wasm32-wasi", "release", os.path.basename(process_dir) + ".wasm")
+    wasm_file = os.path.join(target_dir, "wasm32-wasi", "release", os.path.basename(process_dir) + ".wasm")
     adapted_wasm_file = wasm_file.replace(".wasm", "_adapted.wasm")
     subprocess.check_call([
         "wasm-tools", "component", "new",
@@ -72,8 +75,9 @@ if __name__ == "__main__":
         process_dir = os.abspath(os.path.join(root_dir, sys.argv[1]))
         compile_process(process_dir, pkg_dir, root_dir)
     else:
-        # Compile each base dir folder that has a Cargo.toml
-        for root, dirs, files in os.walk(root_dir):
-            if 'Cargo.toml' in files:
-                process_dir = os.path.abspath(root)
+        # Compile each workspace member that is a process, skipping libraries like process_lib
+        for entry in sorted(os.listdir(root_dir)):
+            cargo_toml = os.path.join(root_dir, entry, "Cargo.toml")
+            if os.path.isfile(cargo_toml) and "[package.metadata.component]" in open(cargo_toml).read():
+                process_dir = os.path.abspath(os.path.join(root_dir, entry))
                 compile_process(process_dir, pkg_dir, root_dir)
@@ -0,0 +1,191 @@
+//! HTTP requests answered by a server. The request to the server goes out
+//! without waiting on it, with a [`ServerCall`] as its context, and the
+//! server's answer, or the error in its place, comes back through the receive
+//! loop to be turned into the response. The kernel keeps the request being
+//! handled along with the context, so that response goes to the HTTP caller.
+//! Requests that did not get an answer are only sent again if doing what they
+//! ask twice is harmless.
+
+use process_lib::handler::Effect;
+use process_lib::http_server::HttpResponse;
+use process_lib::types::*;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::str::FromStr;
+
+use super::{json_response, server_answer, State, SERVER_PROCESS};
+
+/// actions that may be sent again without changing what they do
+const IDEMPOTENT_ACTIONS: &[&str] = &["Query", "QueryById", "Fetch", "Stats", "Ping"];
+
+/// What a route answers with: a response right away, or a request to a server
+/// whose answer becomes the response.
+pub enum HttpAnswer {
+    Now(HttpResponse),
+    Later(Effect),
+}
+
+impl From<HttpResponse> for HttpAnswer {
+    fn from(response: HttpResponse) -> Self {
+        HttpAnswer::Now(response)
+    }
+}
+
+impl HttpAnswer {
+    pub fn into_effect(self) -> Effect {
+        match self {
+            HttpAnswer::Now(response) => response.into_effect(),
+            HttpAnswer::Later(send) => send,
+        }
+    }
+}
+
+/// Whether `action` is one of the [`IDEMPOTENT_ACTIONS`].
+pub fn is_idempotent(action: &serde_json::Value) -> bool {
+    // an action is a variant name, or an object keyed by one
+    let name = match action {
+        serde_json::Value::String(name) => Some(name.as_str()),
+        serde_json::Value::Object(action) => action.keys().next().map(|name| name.as_str()),
+        _ => None,
+    };
+    name.is_some_and(|name| IDEMPOTENT_ACTIONS.contains(&name))
+}
+
+/// What to make of the server's answer.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Then {
+    /// answer with it as it is
+    Relay,
+    /// answer with the one document fetched, or 404
+    Document,
+}
+
+/// The context of a request forwarded to a server.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServerCall {
+    then: Then,
+    /// the server the request went to
+    node: String,
+    /// servers it went to before, which could not be reached
+    failed: Vec<String>,
+    /// whether it went to `node` again after timing out
+    retried: bool,
+    /// whether it may be sent again, see [`IDEMPOTENT_ACTIONS`]
+    idempotent: bool,
+}
+
+/// Sends `action` to the first server; see [`ServerCall`] for what happens to
+/// its answer.
+pub fn forward(
+    state: &State,
+    action: serde_json::Value,
+    payload: Option<Payload>,
+    timeout: u64,
+    then: Then,
+) -> HttpAnswer {
+    let Some(server) = state.servers.first() else {
+        return json_response(503, json!({ "error": "no librarian server configured" })).into();
+    };
+    let call = ServerCall {
+        then,
+        node: server.clone(),
+        failed: vec![],
+        retried: false,
+        idempotent: is_idempotent(&action),
+    };
+    HttpAnswer::Later(call.send(
+        Request {
+            inherit: false,
+            expects_response: Some(timeout),
+            ipc: Some(action.to_string()),
+            metadata: None,
+        },
+        payload,
+    ))
+}
+
+impl ServerCall {
+    /// The call `context` was sent with, if it is one.
+    pub fn from_context(context: Option<&Context>) -> Option<Self> {
+        serde_json::from_str(context?).ok()
+    }
+
+    fn send(self, request: Request, payload: Option<Payload>) -> Effect {
+        Effect::Send {
+            target: Address {
+                node: self.node.clone(),
+                process: ProcessId::from_str(SERVER_PROCESS).unwrap(),
+            },
+            request,
+            context: Some(serde_json::to_string(&self).unwrap()),
+            payload,
+        }
+    }
+
+    /// The response to the HTTP caller, given the server's.
+    pub fn answer(&self, response: &Response, payload: Option<Payload>) -> HttpResponse {
+        match (server_answer(response, payload), self.then) {
+            (Ok(body), Then::Relay) => json_response(200, body),
+            (Ok(mut body), Then::Document) => match body["documents"]
+                .as_array_mut()
+                .and_then(|documents| documents.pop())
+            {
+                Some(document) => json_response(200, document),
+                None => json_response(404, json!({ "error": "no such document" })),
+            },
+            (Err((status, body)), _) => json_response(status, body),
+        }
+    }
+
+    /// What to do about the request not reaching the server: a server that
+    /// timed out gets it once more, then it goes to the servers not tried yet,
+    /// in order, and once none are left the HTTP caller is told. A request
+    /// that is not idempotent is never sent again, as a server that timed out
+    /// may still have done what it asked. `error` has the request and payload
+    /// to send again.
+    pub fn recover(mut self, state: &State, error: SendError) -> Vec<Effect> {
+        let Message::Request(request) = error.message else {
+            return vec![Effect::print("librarian: got network error for a response")];
+        };
+        let timed_out = matches!(error.kind, SendErrorKind::Timeout);
+        let problem = match timed_out {
+            true => "timed out",
+            false => "is offline",
+        };
+        let node = self.node.clone();
+        let give_up = |why: &str| {
+            let (status, message) = match timed_out {
+                true => (504, "librarian server timed out"),
+                false => (502, "could not reach librarian server"),
+            };
+            vec![
+                Effect::print(format!("librarian: {} {}, {}", node, problem, why)),
+                json_response(status, json!({ "error": message })).into_effect(),
+            ]
+        };
+        if !self.idempotent {
+            return give_up("not sending the request again");
+        }
+        if timed_out && !self.retried {
+            self.retried = true;
+        } else {
+            self.failed.push(std::mem::take(&mut self.node));
+            self.retried = false;
+            let Some(next) = state
+                .servers
+                .iter()
+                .find(|server| !self.failed.contains(server))
+            else {
+                return give_up("no servers left");
+            };
+            self.node = next.clone();
+        }
+        vec![
+            Effect::print(format!(
+                "librarian: {} {}, trying {}",
+                node, problem, self.node
+            )),
+            self.send(request, error.payload),
+        ]
+    }
+}
@@ -37,4 +37,4 @@
 `+l[i].replace(" at new "," at ");return e.displayName&&s.includes("<anonymous>")&&(s=s.replace("<anonymous>",e.displayName)),s}while(1<=i&&0<=u);break}}}finally{kl=!1,Error.prepareStackTrace=t}return(e=e?e.displayName||e.name:"")?gt(e):""}function Rc(e){switch(e.tag){case 5:return gt(e.type);case 16:return gt("Lazy");case 13:return gt("Suspense");case 19:return gt("SuspenseList");case 0:case 2:case 15:return e=El(e.type,!1),e;case 11:return e=El(e.type.render,!1),e;case 1:return e=El(e.type,!0),e;default:return""}}function Zl(e){if(e==null)return null;if(typeof e=="function")return e.displayName||e.name||null;if(typeof e=="string")return e;switch(e){case Dn:return"Fragment";case Mn:return"Portal";case Yl:return"Profiler";case Ko:return"StrictMode";case Xl:return"Suspense";case Gl:return"SuspenseList"}if(typeof e=="object")switch(e.$$typeof){case os:return(e.displayName||"Context")+".Consumer";case ls:return(e._context.displayName||"Context")+".Provider";case Yo:var n=e.render;return e=e.displayName,e||(e=n.displayName||n.name||"",e=e!==""?"ForwardRef("+e+")":"ForwardRef"),e;case Xo:return n=e.displayName||null,n!==null?n:Zl(e.type)||"Memo";case Je:n=e._payload,e=e._init;try{return Zl(e(n))}catch{}}return null}function Oc(e){var n=e.type;switch(e.tag){case 24:return"Cache";case 9:return(n.displayName||"Context")+".Consumer";case 10:return(n._context.displayName||"Context")+".Provider";case 18:return"DehydratedFragment";case 11:return e=n.render,e=e.displayName||e.name||"",n.displayName||(e!==""?"ForwardRef("+e+")":"ForwardRef");case 7:return"Fragment";case 5:return n;case 4:return"Portal";case 3:return"Root";case 6:return"Text";case 16:return Zl(n);case 8:return n===Ko?"StrictMode":"Mode";case 22:return"Offscreen";case 12:return"Profiler";case 21:return"Scope";case 13:return"Suspense";case 19:return"SuspenseList";case 25:return"TracingMarker";case 1:case 0:case 17:case 2:case 14:case 15:if(typeof n=="function")return n.displayName||n.name||null;if(typeof n=="string")return n}return null}function dn(e){switch(typeof e){case"boolean":case"number":case"string":case"undefined":return e;case"object":return e;default:return""}}function us(e){var n=e.type;return(e=e.nodeName)&&e.toLowerCase()==="input"&&(n==="checkbox"||n==="radio")}function jc(e){var n=us(e)?"checked":"value",t=Object.getOwnPropertyDescriptor(e.constructor.prototype,n),r=""+e[n];if(!e.hasOwnProperty(n)&&typeof t<"u"&&typeof t.get=="function"&&typeof t.set=="function"){var l=t.get,o=t.set;return Object.defineProperty(e,n,{configurable:!0,get:function(){return l.call(this)},set:function(i){r=""+i,o.call(this,i)}}),Object.defineProperty(e,n,{enumerable:t.enumerable}),{getValue:function(){return r},setValue:function(i){r=""+i},stopTracking:function(){e._valueTracker=null,delete e[n]}}}}function rr(e){e._valueTracker||(e._valueTracker=jc(e))}function ss(e){if(!e)return!1;var n=e._valueTracker;if(!n)return!0;var t=n.getValue(),r="";return e&&(r=us(e)?e.checked?"true":"false":e.value),e=r,e!==t?(n.setValue(e),!0):!1}function Tr(e){if(e=e||(typeof document<"u"?document:void 0),typeof e>"u")return null;try{return e.activeElement||e.body}catch{return e.body}}function Jl(e,n){var t=n.checked;return V({},n,{defaultChecked:void 0,defaultValue:void 0,value:void 0,checked:t??e._wrapperState.initialChecked})}function Ai(e,n){var t=n.defaultValue==null?"":n.defaultValue,r=n.checked!=null?n.checked:n.defaultChecked;t=dn(n.value!=null?n.value:t),e._wrapperState={initialChecked:r,initialValue:t,controlled:n.type==="checkbox"||n.type==="radio"?n.checked!=null:n.value!=null}}function as(e,n){n=n.checked,n!=null&&Qo(e,"checked",n,!1)}function ql(e,n){as(e,n);var t=dn(n.value),r=n.type;if(t!=null)r==="number"?(t===0&&e.value===""||e.value!=t)&&(e.value=""+t):e.value!==""+t&&(e.value=""+t);else if(r==="submit"||r==="reset"){e.removeAttribute("value");return}n.hasOwnProperty("value")?bl(e,n.type,t):n.hasOwnProperty("defaultValue")&&bl(e,n.type,dn(n.defaultValue)),n.checked==null&&n.defaultChecked!=null&&(e.defaultChecked=!!n.defaultChecked)}function Vi(e,n,t){if(n.hasOwnProperty("value")||n.hasOwnProperty("defaultValue")){var r=n.type;if(!(r!=="submit"&&r!=="reset"||n.value!==void 0&&n.value!==null))return;n=""+e._wrapperState.initialValue,t||n===e.value||(e.value=n),e.defaultValue=n}t=e.name,t!==""&&(e.name=""),e.defaultChecked=!!e._wrapperState.initialChecked,t!==""&&(e.name=t)}function bl(e,n,t){(n!=="number"||Tr(e.ownerDocument)!==e)&&(t==null?e.defaultValue=""+e._wrapperState.initialValue:e.defaultValue!==""+t&&(e.defaultValue=""+t))}var wt=Array.isArray;function Kn(e,n,t,r){if(e=e.options,n){n={};for(var l=0;l<t.length;l++)n["$"+t[l]]=!0;for(t=0;t<e.length;t++)l=n.hasOwnProperty("$"+e[t].value),e[t].selected!==l&&(e[t].selected=l),l&&r&&(e[t].defaultSelected=!0)}else{for(t=""+dn(t),n=null,l=0;l<e.length;l++){if(e[l].value===t){e[l].selected=!0,r&&(e[l].defaultSelected=!0);return}n!==null||e[l].disabled||(n=e[l])}n!==null&&(n.selected=!0)}}function eo(e,n){if(n.dangerouslySetInnerHTML!=null)throw Error(y(91));return V({},n,{value:void 0,defaultValue:void 0,children:""+e._wrapperState.initialValue})}function Bi(e,n){var t=n.value;if(t==null){if(t=n.children,n=n.defaultValue,t!=null){if(n!=null)throw Error(y(92));if(wt(t)){if(1<t.length)throw Error(y(93));t=t[0]}n=t}n==null&&(n=""),t=n}e._wrapperState={initialValue:dn(t)}}function cs(e,n){var t=dn(n.value),r=dn(n.defaultValue);t!=null&&(t=""+t,t!==e.value&&(e.value=t),n.defaultValue==null&&e.defaultValue!==t&&(e.defaultValue=t)),r!=null&&(e.defaultValue=""+r)}function Hi(e){var n=e.textContent;n===e._wrapperState.initialValue&&n!==""&&n!==null&&(e.value=n)}function fs(e){switch(e){case"svg":return"http://www.w3.org/2000/svg";case"math":return"http://www.w3.org/1998/Math/MathML";default:return"http://www.w3.org/1999/xhtml"}}function no(e,n){return e==null||e==="http://www.w3.org/1999/xhtml"?fs(n):e==="http://www.w3.org/2000/svg"&&n==="foreignObject"?"http://www.w3.org/1999/xhtml":e}var lr,ds=function(e){return typeof MSApp<"u"&&MSApp.execUnsafeLocalFunction?function(n,t,r,l){MSApp.execUnsafeLocalFunction(function(){return e(n,t,r,l)})}:e}(function(e,n){if(e.namespaceURI!=="http://www.w3.org/2000/svg"||"innerHTML"in e)e.innerHTML=n;else{for(lr=lr||document.createElement("div"),lr.innerHTML="<svg>"+n.valueOf().toString()+"</svg>",n=lr.firstChild;e.firstChild;)e.removeChild(e.firstChild);for(;n.firstChild;)e.appendChild(n.firstChild)}});function Ot(e,n){if(n){var t=e.firstChild;if(t&&t===e.lastChild&&t.nodeType===3){t.nodeValue=n;return}}e.textContent=n}var Et={animationIterationCount:!0,aspectRatio:!0,borderImageOutset:!0,borderImageSlice:!0,borderImageWidth:!0,boxFlex:!0,boxFlexGroup:!0,boxOrdinalGroup:!0,columnCount:!0,columns:!0,flex:!0,flexGrow:!0,flexPositive:!0,flexShrink:!0,flexNegative:!0,flexOrder:!0,gridArea:!0,gridRow:!0,gridRowEnd:!0,gridRowSpan:!0,gridRowStart:!0,gridColumn:!0,gridColumnEnd:!0,gridColumnSpan:!0,gridColumnStart:!0,fontWeight:!0,lineClamp:!0,lineHeight:!0,opacity:!0,order:!0,orphans:!0,tabSize:!0,widows:!0,zIndex:!0,zoom:!0,fillOpacity:!0,floodOpacity:!0,stopOpacity:!0,strokeDasharray:!0,strokeDashoffset:!0,strokeMiterlimit:!0,strokeOpacity:!0,strokeWidth:!0},Mc=["Webkit","ms","Moz","O"];Object.keys(Et).forEach(function(e){Mc.forEach(function(n){n=n+e.charAt(0).toUpperCase()+e.substring(1),Et[n]=Et[e]})});function ps(e,n,t){return n==null||typeof n=="boolean"||n===""?"":t||typeof n!="number"||n===0||Et.hasOwnProperty(e)&&Et[e]?(""+n).trim():n+"px"}function ms(e,n){e=e.style;for(var t in n)if(n.hasOwnProperty(t)){var r=t.indexOf("--")===0,l=ps(t,n[t],r);t==="float"&&(t="cssFloat"),r?e.setProperty(t,l):e[t]=l}}var Dc=V({menuitem:!0},{area:!0,base:!0,br:!0,col:!0,embed:!0,hr:!0,img:!0,input:!0,keygen:!0,link:!0,meta:!0,param:!0,source:!0,track:!0,wbr:!0});function to(e,n){if(n){if(Dc[e]&&(n.children!=null||n.dangerouslySetInnerHTML!=null))throw Error(y(137,e));if(n.dangerouslySetInnerHTML!=null){if(n.children!=null)throw Error(y(60));if(typeof n.dangerouslySetInnerHTML!="object"||!("__html"in n.dangerouslySetInnerHTML))throw Error(y(61))}if(n.style!=null&&typeof n.style!="object")throw Error(y(62))}}function ro(e,n){if(e.indexOf("-")===-1)return typeof n.is=="string";switch(e){case"annotation-xml":case"color-profile":case"font-face":case"font-face-src":case"font-face-uri":case"font-face-format":case"font-face-name":case"missing-glyph":return!1;default:return!0}}var lo=null;function Go(e){return e=e.target||e.srcElement||window,e.correspondingUseElement&&(e=e.correspondingUseElement),e.nodeType===3?e.parentNode:e}var oo=null,Yn=null,Xn=null;function Wi(e){if(e=Jt(e)){if(typeof oo!="function")throw Error(y(280));var n=e.stateNode;n&&(n=ol(n),oo(e.stateNode,e.type,n))}}function hs(e){Yn?Xn?Xn.push(e):Xn=[e]:Yn=e}function vs(){if(Yn){var e=Yn,n=Xn;if(Xn=Yn=null,Wi(e),n)for(e=0;e<n.length;e++)Wi(n[e])}}function ys(e,n){return e(n)}function gs(){}var xl=!1;function ws(e,n,t){if(xl)return e(n,t);xl=!0;try{return ys(e,n,t)}finally{xl=!1,(Yn!==null||Xn!==null)&&(gs(),vs())}}function jt(e,n){var t=e.stateNode;if(t===null)return null;var r=ol(t);if(r===null)return null;t=r[n];e:switch(n){case"onClick":case"onClickCapture":case"onDoubleClick":case"onDoubleClickCapture":case"onMouseDown":case"onMouseDownCapture":case"onMouseMove":case"onMouseMoveCapture":case"onMouseUp":case"onMouseUpCapture":case"onMouseEnter":(r=!r.disabled)||(e=e.type,r=!(e==="button"||e==="input"||e==="select"||e==="textarea")),e=!r;break e;default:e=!1}if(e)return null;if(t&&typeof t!="function")throw Error(y(231,n,typeof t));return t}var io=!1;if(Qe)try{var ft={};Object.defineProperty(ft,"passive",{get:function(){io=!0}}),window.addEventListener("test",ft,ft),window.removeEventListener("test",ft,ft)}catch{io=!1}function Ic(e,n,t,r,l,o,i,u,s){var c=Array.prototype.slice.call(arguments,3);try{n.apply(t,c)}catch(h){this.onError(h)}}var xt=!1,Rr=null,Or=!1,uo=null,Fc={onError:function(e){xt=!0,Rr=e}};function Uc(e,n,t,r,l,o,i,u,s){xt=!1,Rr=null,Ic.apply(Fc,arguments)}function $c(e,n,t,r,l,o,i,u,s){if(Uc.apply(this,arguments),xt){if(xt){var c=Rr;xt=!1,Rr=null}else throw Error(y(198));Or||(Or=!0,uo=c)}}function On(e){var n=e,t=e;if(e.alternate)for(;n.return;)n=n.return;else{e=n;do n=e,n.flags&4098&&(t=n.return),e=n.return;while(e)}return n.tag===3?t:null}function Ss(e){if(e.tag===13){var n=e.memoizedState;if(n===null&&(e=e.alternate,e!==null&&(n=e.memoizedState)),n!==null)return n.dehydrated}return null}function Qi(e){if(On(e)!==e)throw Error(y(188))}function Ac(e){var n=e.alternate;if(!n){if(n=On(e),n===null)throw Error(y(188));return n!==e?null:e}for(var t=e,r=n;;){var l=t.return;if(l===null)break;var o=l.alternate;if(o===null){if(r=l.return,r!==null){t=r;continue}break}if(l.child===o.child){for(o=l.child;o;){if(o===t)return Qi(l),e;if(o===r)return Qi(l),n;o=o.sibling}throw Error(y(188))}if(t.return!==r.return)t=l,r=o;else{for(var i=!1,u=l.child;u;){if(u===t){i=!0,t=l,r=o;break}if(u===r){i=!0,r=l,t=o;break}u=u.sibling}if(!i){for(u=o.child;u;){if(u===t){i=!0,t=o,r=l;break}if(u===r){i=!0,r=o,t=l;break}u=u.sibling}if(!i)throw Error(y(189))}}if(t.alternate!==r)throw Error(y(190))}if(t.tag!==3)throw Error(y(188));return t.stateNode.current===t?e:n}function ks(e){return e=Ac(e),e!==null?Es(e):null}function Es(e){if(e.tag===5||e.tag===6)return e;for(e=e.child;e!==null;){var n=Es(e);if(n!==null)return n;e=e.sibling}return null}var xs=ye.unstable_scheduleCallback,Ki=ye.unstable_cancelCallback,Vc=ye.unstable_shouldYield,Bc=ye.unstable_requestPaint,Q=ye.unstable_now,Hc=ye.unstable_getCurrentPriorityLevel,Zo=ye.unstable_ImmediatePriority,Cs=ye.unstable_UserBlockingPriority,jr=ye.unstable_NormalPriority,Wc=ye.unstable_LowPriority,_s=ye.unstable_IdlePriority,nl=null,Ue=null;function Qc(e){if(Ue&&typeof Ue.onCommitFiberRoot=="function")try{Ue.onCommitFiberRoot(nl,e,void 0,(e.current.flags&128)===128)}catch{}}var Oe=Math.clz32?Math.clz32:Xc,Kc=Math.log,Yc=Math.LN2;function Xc(e){return e>>>=0,e===0?32:31-(Kc(e)/Yc|0)|0}var or=64,ir=4194304;function St(e){switch(e&-e){case 1:return 1;case 2:return 2;case 4:return 4;case 8:return 8;case 16:return 16;case 32:return 32;case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:return e&4194240;case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:return e&130023424;case 134217728:return 134217728;case 268435456:return 268435456;case 536870912:return 536870912;case 1073741824:return 1073741824;default:return e}}function Mr(e,n){var t=e.pendingLanes;if(t===0)return 0;var r=0,l=e.suspendedLanes,o=e.pingedLanes,i=t&268435455;if(i!==0){var u=i&~l;u!==0?r=St(u):(o&=i,o!==0&&(r=St(o)))}else i=t&~l,i!==0?r=St(i):o!==0&&(r=St(o));if(r===0)return 0;if(n!==0&&n!==r&&!(n&l)&&(l=r&-r,o=n&-n,l>=o||l===16&&(o&4194240)!==0))return n;if(r&4&&(r|=t&16),n=e.entangledLanes,n!==0)for(e=e.entanglements,n&=r;0<n;)t=31-Oe(n),l=1<<t,r|=e[t],n&=~l;return r}function Gc(e,n){switch(e){case 1:case 2:case 4:return n+250;case 8:case 16:case 32:case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:return n+5e3;case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:return-1;case 134217728:case 268435456:case 536870912:case 1073741824:return-1;default:return-1}}function Zc(e,n){for(var t=e.suspendedLanes,r=e.pingedLanes,l=e.expirationTimes,o=e.pendingLanes;0<o;){var i=31-Oe(o),u=1<<i,s=l[i];s===-1?(!(u&t)||u&r)&&(l[i]=Gc(u,n)):s<=n&&(e.expiredLanes|=u),o&=~u}}function so(e){return e=e.pendingLanes&-1073741825,e!==0?e:e&1073741824?1073741824:0}function Ns(){var e=or;return or<<=1,!(or&4194240)&&(or=64),e}function Cl(e){for(var n=[],t=0;31>t;t++)n.push(e);return n}function Gt(e,n,t){e.pendingLanes|=n,n!==536870912&&(e.suspendedLanes=0,e.pingedLanes=0),e=e.eventTimes,n=31-Oe(n),e[n]=t}function Jc(e,n){var t=e.pendingLanes&~n;e.pendingLanes=n,e.suspendedLanes=0,e.pingedLanes=0,e.expiredLanes&=n,e.mutableReadLanes&=n,e.entangledLanes&=n,n=e.entanglements;var r=e.eventTimes;for(e=e.expirationTimes;0<t;){var l=31-Oe(t),o=1<<l;n[l]=0,r[l]=-1,e[l]=-1,t&=~o}}function Jo(e,n){var t=e.entangledLanes|=n;for(e=e.entanglements;t;){var r=31-Oe(t),l=1<<r;l&n|e[r]&n&&(e[r]|=n),t&=~l}}var j=0;function Ps(e){return e&=-e,1<e?4<e?e&268435455?16:536870912:4:1}var zs,qo,Ls,Ts,Rs,ao=!1,ur=[],rn=null,ln=null,on=null,Mt=new Map,Dt=new Map,be=[],qc="mousedown mouseup touchcancel touchend touchstart auxclick dblclick pointercancel pointerdown pointerup dragend dragstart drop compositionend compositionstart keydown keypress keyup input textInput copy cut paste click change contextmenu reset submit".split(" ");function Yi(e,n){switch(e){case"focusin":case"focusout":rn=null;break;case"dragenter":case"dragleave":ln=null;break;case"mouseover":case"mouseout":on=null;break;case"pointerover":case"pointerout":Mt.delete(n.pointerId);break;case"gotpointercapture":case"lostpointercapture":Dt.delete(n.pointerId)}}function dt(e,n,t,r,l,o){return e===null||e.nativeEvent!==o?(e={blockedOn:n,domEventName:t,eventSystemFlags:r,nativeEvent:o,targetContainers:[l]},n!==null&&(n=Jt(n),n!==null&&qo(n)),e):(e.eventSystemFlags|=r,n=e.targetContainers,l!==null&&n.indexOf(l)===-1&&n.push(l),e)}function bc(e,n,t,r,l){switch(n){case"focusin":return rn=dt(rn,e,n,t,r,l),!0;case"dragenter":return ln=dt(ln,e,n,t,r,l),!0;case"mouseover":return on=dt(on,e,n,t,r,l),!0;case"pointerover":var o=l.pointerId;return Mt.set(o,dt(Mt.get(o)||null,e,n,t,r,l)),!0;case"gotpointercapture":return o=l.pointerId,Dt.set(o,dt(Dt.get(o)||null,e,n,t,r,l)),!0}return!1}function Os(e){var n=kn(e.target);if(n!==null){var t=On(n);if(t!==null){if(n=t.tag,n===13){if(n=Ss(t),n!==null){e.blockedOn=n,Rs(e.priority,function(){Ls(t)});return}}else if(n===3&&t.stateNode.current.memoizedState.isDehydrated){e.blockedOn=t.tag===3?t.stateNode.containerInfo:null;return}}}e.blockedOn=null}function Sr(e){if(e.blockedOn!==null)return!1;for(var n=e.targetContainers;0<n.length;){var t=co(e.domEventName,e.eventSystemFlags,n[0],e.nativeEvent);if(t===null){t=e.nativeEvent;var r=new t.constructor(t.type,t);lo=r,t.target.dispatchEvent(r),lo=null}else return n=Jt(t),n!==null&&qo(n),e.blockedOn=t,!1;n.shift()}return!0}function Xi(e,n,t){Sr(e)&&t.delete(n)}function ef(){ao=!1,rn!==null&&Sr(rn)&&(rn=null),ln!==null&&Sr(ln)&&(ln=null),on!==null&&Sr(on)&&(on=null),Mt.forEach(Xi),Dt.forEach(Xi)}function pt(e,n){e.blockedOn===n&&(e.blockedOn=null,ao||(ao=!0,ye.unstable_scheduleCallback(ye.unstable_NormalPriority,ef)))}function It(e){function n(l){return pt(l,e)}if(0<ur.length){pt(ur[0],e);for(var t=1;t<ur.length;t++){var r=ur[t];r.blockedOn===e&&(r.blockedOn=null)}}for(rn!==null&&pt(rn,e),ln!==null&&pt(ln,e),on!==null&&pt(on,e),Mt.forEach(n),Dt.forEach(n),t=0;t<be.length;t++)r=be[t],r.blockedOn===e&&(r.blockedOn=null);for(;0<be.length&&(t=be[0],t.blockedOn===null);)Os(t),t.blockedOn===null&&be.shift()}var Gn=Ge.ReactCurrentBatchConfig,Dr=!0;function nf(e,n,t,r){var l=j,o=Gn.transition;Gn.transition=null;try{j=1,bo(e,n,t,r)}finally{j=l,Gn.transition=o}}function tf(e,n,t,r){var l=j,o=Gn.transition;Gn.transition=null;try{j=4,bo(e,n,t,r)}finally{j=l,Gn.transition=o}}function bo(e,n,t,r){if(Dr){var l=co(e,n,t,r);if(l===null)Ml(e,n,r,Ir,t),Yi(e,r);else if(bc(l,e,n,t,r))r.stopPropagation();else if(Yi(e,r),n&4&&-1<qc.indexOf(e)){for(;l!==null;){var o=Jt(l);if(o!==null&&zs(o),o=co(e,n,t,r),o===null&&Ml(e,n,r,Ir,t),o===l)break;l=o}l!==null&&r.stopPropagation()}else Ml(e,n,r,null,t)}}var Ir=null;function co(e,n,t,r){if(Ir=null,e=Go(r),e=kn(e),e!==null)if(n=On(e),n===null)e=null;else if(t=n.tag,t===13){if(e=Ss(n),e!==null)return e;e=null}else if(t===3){if(n.stateNode.current.memoizedState.isDehydrated)return n.tag===3?n.stateNode.containerInfo:null;e=null}else n!==e&&(e=null);return Ir=e,null}function js(e){switch(e){case"cancel":case"click":case"close":case"contextmenu":case"copy":case"cut":case"auxclick":case"dblclick":case"dragend":case"dragstart":case"drop":case"focusin":case"focusout":case"input":case"invalid":case"keydown":case"keypress":case"keyup":case"mousedown":case"mouseup":case"paste":case"pause":case"play":case"pointercancel":case"pointerdown":case"pointerup":case"ratechange":case"reset":case"resize":case"seeked":case"submit":case"touchcancel":case"touchend":case"touchstart":case"volumechange":case"change":case"selectionchange":case"textInput":case"compositionstart":case"compositionend":case"compositionupdate":case"beforeblur":case"afterblur":case"beforeinput":case"blur":case"fullscreenchange":case"focus":case"hashchange":case"popstate":case"select":case"selectstart":return 1;case"drag":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"mousemove":case"mouseout":case"mouseover":case"pointermove":case"pointerout":case"pointerover":case"scroll":case"toggle":case"touchmove":case"wheel":case"mouseenter":case"mouseleave":case"pointerenter":case"pointerleave":return 4;case"message":switch(Hc()){case Zo:return 1;case Cs:return 4;case jr:case Wc:return 16;case _s:return 536870912;default:return 16}default:return 16}}var nn=null,ei=null,kr=null;function Ms(){if(kr)return kr;var e,n=ei,t=n.length,r,l="value"in nn?nn.value:nn.textContent,o=l.length;for(e=0;e<t&&n[e]===l[e];e++);var i=t-e;for(r=1;r<=i&&n[t-r]===l[o-r];r++);return kr=l.slice(e,1<r?1-r:void 0)}function Er(e){var n=e.keyCode;return"charCode"in e?(e=e.charCode,e===0&&n===13&&(e=13)):e=n,e===10&&(e=13),32<=e||e===13?e:0}function sr(){return!0}function Gi(){return!1}function we(e){function n(t,r,l,o,i){this._reactName=t,this._targetInst=l,this.type=r,this.nativeEvent=o,this.target=i,this.currentTarget=null;for(var u in e)e.hasOwnProperty(u)&&(t=e[u],this[u]=t?t(o):o[u]);return this.isDefaultPrevented=(o.defaultPrevented!=null?o.defaultPrevented:o.returnValue===!1)?sr:Gi,this.isPropagationStopped=Gi,this}return V(n.prototype,{preventDefault:function(){this.defaultPrevented=!0;var t=this.nativeEvent;t&&(t.preventDefault?t.preventDefault():typeof t.returnValue!="unknown"&&(t.returnValue=!1),this.isDefaultPrevented=sr)},stopPropagation:function(){var t=this.nativeEvent;t&&(t.stopPropagation?t.stopPropagation():typeof t.cancelBubble!="unknown"&&(t.cancelBubble=!0),this.isPropagationStopped=sr)},persist:function(){},isPersistent:sr}),n}var it={eventPhase:0,bubbles:0,cancelable:0,timeStamp:function(e){return e.timeStamp||Date.now()},defaultPrevented:0,isTrusted:0},ni=we(it),Zt=V({},it,{view:0,detail:0}),rf=we(Zt),_l,Nl,mt,tl=V({},Zt,{screenX:0,screenY:0,clientX:0,clientY:0,pageX:0,pageY:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,getModifierState:ti,button:0,buttons:0,relatedTarget:function(e){return e.relatedTarget===void 0?e.fromElement===e.srcElement?e.toElement:e.fromElement:e.relatedTarget},movementX:function(e){return"movementX"in e?e.movementX:(e!==mt&&(mt&&e.type==="mousemove"?(_l=e.screenX-mt.screenX,Nl=e.screenY-mt.screenY):Nl=_l=0,mt=e),_l)},movementY:function(e){return"movementY"in e?e.movementY:Nl}}),Zi=we(tl),lf=V({},tl,{dataTransfer:0}),of=we(lf),uf=V({},Zt,{relatedTarget:0}),Pl=we(uf),sf=V({},it,{animationName:0,elapsedTime:0,pseudoElement:0}),af=we(sf),cf=V({},it,{clipboardData:function(e){return"clipboardData"in e?e.clipboardData:window.clipboardData}}),ff=we(cf),df=V({},it,{data:0}),Ji=we(df),pf={Esc:"Escape",Spacebar:" ",Left:"ArrowLeft",Up:"ArrowUp",Right:"ArrowRight",Down:"ArrowDown",Del:"Delete",Win:"OS",Menu:"ContextMenu",Apps:"ContextMenu",Scroll:"ScrollLock",MozPrintableKey:"Unidentified"},mf={8:"Backspace",9:"Tab",12:"Clear",13:"Enter",16:"Shift",17:"Control",18:"Alt",19:"Pause",20:"CapsLock",27:"Escape",32:" ",33:"PageUp",34:"PageDown",35:"End",36:"Home",37:"ArrowLeft",38:"ArrowUp",39:"ArrowRight",40:"ArrowDown",45:"Insert",46:"Delete",112:"F1",113:"F2",114:"F3",115:"F4",116:"F5",117:"F6",118:"F7",119:"F8",120:"F9",121:"F10",122:"F11",123:"F12",144:"NumLock",145:"ScrollLock",224:"Meta"},hf={Alt:"altKey",Control:"ctrlKey",Meta:"metaKey",Shift:"shiftKey"};function vf(e){var n=this.nativeEvent;return n.getModifierState?n.getModifierState(e):(e=hf[e])?!!n[e]:!1}function ti(){return vf}var yf=V({},Zt,{key:function(e){if(e.key){var n=pf[e.key]||e.key;if(n!=="Unidentified")return n}return e.type==="keypress"?(e=Er(e),e===13?"Enter":String.fromCharCode(e)):e.type==="keydown"||e.type==="keyup"?mf[e.keyCode]||"Unidentified":""},code:0,location:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,repeat:0,locale:0,getModifierState:ti,charCode:function(e){return e.type==="keypress"?Er(e):0},keyCode:function(e){return e.type==="keydown"||e.type==="keyup"?e.keyCode:0},which:function(e){return e.type==="keypress"?Er(e):e.type==="keydown"||e.type==="keyup"?e.keyCode:0}}),gf=we(yf),wf=V({},tl,{pointerId:0,width:0,height:0,pressure:0,tangentialPressure:0,tiltX:0,tiltY:0,twist:0,pointerType:0,isPrimary:0}),qi=we(wf),Sf=V({},Zt,{touches:0,targetTouches:0,changedTouches:0,altKey:0,metaKey:0,ctrlKey:0,shiftKey:0,getModifierState:ti}),kf=we(Sf),Ef=V({},it,{propertyName:0,elapsedTime:0,pseudoElement:0}),xf=we(Ef),Cf=V({},tl,{deltaX:function(e){return"deltaX"in e?e.deltaX:"wheelDeltaX"in e?-e.wheelDeltaX:0},deltaY:function(e){return"deltaY"in e?e.deltaY:"wheelDeltaY"in e?-e.wheelDeltaY:"wheelDelta"in e?-e.wheelDelta:0},deltaZ:0,deltaMode:0}),_f=we(Cf),Nf=[9,13,27,32],ri=Qe&&"CompositionEvent"in window,Ct=null;Qe&&"documentMode"in document&&(Ct=document.documentMode);var Pf=Qe&&"TextEvent"in window&&!Ct,Ds=Qe&&(!ri||Ct&&8<Ct&&11>=Ct),bi=String.fromCharCode(32),eu=!1;function Is(e,n){switch(e){case"keyup":return Nf.indexOf(n.keyCode)!==-1;case"keydown":return n.keyCode!==229;case"keypress":case"mousedown":case"focusout":return!0;default:return!1}}function Fs(e){return e=e.detail,typeof e=="object"&&"data"in e?e.data:null}var In=!1;function zf(e,n){switch(e){case"compositionend":return Fs(n);case"keypress":return n.which!==32?null:(eu=!0,bi);case"textInput":return e=n.data,e===bi&&eu?null:e;default:return null}}function Lf(e,n){if(In)return e==="compositionend"||!ri&&Is(e,n)?(e=Ms(),kr=ei=nn=null,In=!1,e):null;switch(e){case"paste":return null;case"keypress":if(!(n.ctrlKey||n.altKey||n.metaKey)||n.ctrlKey&&n.altKey){if(n.char&&1<n.char.length)return n.char;if(n.which)return String.fromCharCode(n.which)}return null;case"compositionend":return Ds&&n.locale!=="ko"?null:n.data;default:return null}}var Tf={color:!0,date:!0,datetime:!0,"datetime-local":!0,email:!0,month:!0,number:!0,password:!0,range:!0,search:!0,tel:!0,text:!0,time:!0,url:!0,week:!0};function nu(e){var n=e&&e.nodeName&&e.nodeName.toLowerCase();return n==="input"?!!Tf[e.type]:n==="textarea"}function Us(e,n,t,r){hs(r),n=Fr(n,"onChange"),0<n.length&&(t=new ni("onChange","change",null,t,r),e.push({event:t,listeners:n}))}var _t=null,Ft=null;function Rf(e){Gs(e,0)}function rl(e){var n=$n(e);if(ss(n))return e}function Of(e,n){if(e==="change")return n}var $s=!1;if(Qe){var zl;if(Qe){var Ll="oninput"in document;if(!Ll){var tu=document.createElement("div");tu.setAttribute("oninput","return;"),Ll=typeof tu.oninput=="function"}zl=Ll}else zl=!1;$s=zl&&(!document.documentMode||9<document.documentMode)}function ru(){_t&&(_t.detachEvent("onpropertychange",As),Ft=_t=null)}function As(e){if(e.propertyName==="value"&&rl(Ft)){var n=[];Us(n,Ft,e,Go(e)),ws(Rf,n)}}function jf(e,n,t){e==="focusin"?(ru(),_t=n,Ft=t,_t.attachEvent("onpropertychange",As)):e==="focusout"&&ru()}function Mf(e){if(e==="selectionchange"||e==="keyup"||e==="keydown")return rl(Ft)}function Df(e,n){if(e==="click")return rl(n)}function If(e,n){if(e==="input"||e==="change")return rl(n)}function Ff(e,n){return e===n&&(e!==0||1/e===1/n)||e!==e&&n!==n}var Me=typeof Object.is=="function"?Object.is:Ff;function Ut(e,n){if(Me(e,n))return!0;if(typeof e!="object"||e===null||typeof n!="object"||n===null)return!1;var t=Object.keys(e),r=Object.keys(n);if(t.length!==r.length)return!1;for(r=0;r<t.length;r++){var l=t[r];if(!Kl.call(n,l)||!Me(e[l],n[l]))return!1}return!0}function lu(e){for(;e&&e.firstChild;)e=e.firstChild;return e}function ou(e,n){var t=lu(e);e=0;for(var r;t;){if(t.nodeType===3){if(r=e+t.textContent.length,e<=n&&r>=n)return{node:t,offset:n-e};e=r}e:{for(;t;){if(t.nextSibling){t=t.nextSibling;break e}t=t.parentNode}t=void 0}t=lu(t)}}function Vs(e,n){return e&&n?e===n?!0:e&&e.nodeType===3?!1:n&&n.nodeType===3?Vs(e,n.parentNode):"contains"in e?e.contains(n):e.compareDocumentPosition?!!(e.compareDocumentPosition(n)&16):!1:!1}function Bs(){for(var e=window,n=Tr();n instanceof e.HTMLIFrameElement;){try{var t=typeof n.contentWindow.location.href=="string"}catch{t=!1}if(t)e=n.contentWindow;else break;n=Tr(e.document)}return n}function li(e){var n=e&&e.nodeName&&e.nodeName.toLowerCase();return n&&(n==="input"&&(e.type==="text"||e.type==="search"||e.type==="tel"||e.type==="url"||e.type==="password")||n==="textarea"||e.contentEditable==="true")}function Uf(e){var n=Bs(),t=e.focusedElem,r=e.selectionRange;if(n!==t&&t&&t.ownerDocument&&Vs(t.ownerDocument.documentElement,t)){if(r!==null&&li(t)){if(n=r.start,e=r.end,e===void 0&&(e=n),"selectionStart"in t)t.selectionStart=n,t.selectionEnd=Math.min(e,t.value.length);else if(e=(n=t.ownerDocument||document)&&n.defaultView||window,e.getSelection){e=e.getSelection();var l=t.textContent.length,o=Math.min(r.start,l);r=r.end===void 0?o:Math.min(r.end,l),!e.extend&&o>r&&(l=r,r=o,o=l),l=ou(t,o);var i=ou(t,r);l&&i&&(e.rangeCount!==1||e.anchorNode!==l.node||e.anchorOffset!==l.offset||e.focusNode!==i.node||e.focusOffset!==i.offset)&&(n=n.createRange(),n.setStart(l.node,l.offset),e.removeAllRanges(),o>r?(e.addRange(n),e.extend(i.node,i.offset)):(n.setEnd(i.node,i.offset),e.addRange(n)))}}for(n=[],e=t;e=e.parentNode;)e.nodeType===1&&n.push({element:e,left:e.scrollLeft,top:e.scrollTop});for(typeof t.focus=="function"&&t.focus(),t=0;t<n.length;t++)e=n[t],e.element.scrollLeft=e.left,e.element.scrollTop=e.top}}var $f=Qe&&"documentMode"in document&&11>=document.documentMode,Fn=null,fo=null,Nt=null,po=!1;function iu(e,n,t){var r=t.window===t?t.document:t.nodeType===9?t:t.ownerDocument;po||Fn==null||Fn!==Tr(r)||(r=Fn,"selectionStart"in r&&li(r)?r={start:r.selectionStart,end:r.selectionEnd}:(r=(r.ownerDocument&&r.ownerDocument.defaultView||window).getSelection(),r={anchorNode:r.anchorNode,anchorOffset:r.anchorOffset,focusNode:r.focusNode,focusOffset:r.focusOffset}),Nt&&Ut(Nt,r)||(Nt=r,r=Fr(fo,"onSelect"),0<r.length&&(n=new ni("onSelect","select",null,n,t),e.push({event:n,listeners:r}),n.target=Fn)))}function ar(e,n){var t={};return t[e.toLowerCase()]=n.toLowerCase(),t["Webkit"+e]="webkit"+n,t["Moz"+e]="moz"+n,t}var Un={animationend:ar("Animation","AnimationEnd"),animationiteration:ar("Animation","AnimationIteration"),animationstart:ar("Animation","AnimationStart"),transitionend:ar("Transition","TransitionEnd")},Tl={},Hs={};Qe&&(Hs=document.createElement("div").style,"AnimationEvent"in window||(delete Un.animationend.animation,delete Un.animationiteration.animation,delete Un.animationstart.animation),"TransitionEvent"in window||delete Un.transitionend.transition);function ll(e){if(Tl[e])return Tl[e];if(!Un[e])return e;var n=Un[e],t;for(t in n)if(n.hasOwnProperty(t)&&t in Hs)return Tl[e]=n[t];return e}var Ws=ll("animationend"),Qs=ll("animationiteration"),Ks=ll("animationstart"),Ys=ll("transitionend"),Xs=new Map,uu="abort auxClick cancel canPlay canPlayThrough click close contextMenu copy cut drag dragEnd dragEnter dragExit dragLeave dragOver dragStart drop durationChange emptied encrypted ended error gotPointerCapture input invalid keyDown keyPress keyUp load loadedData loadedMetadata loadStart lostPointerCapture mouseDown mouseMove mouseOut mouseOver mouseUp paste pause play playing pointerCancel pointerDown pointerMove pointerOut pointerOver pointerUp progress rateChange reset resize seeked seeking stalled submit suspend timeUpdate touchCancel touchEnd touchStart volumeChange scroll toggle touchMove waiting wheel".split(" ");function mn(e,n){Xs.set(e,n),Rn(n,[e])}for(var Rl=0;Rl<uu.length;Rl++){var Ol=uu[Rl],Af=Ol.toLowerCase(),Vf=Ol[0].toUpperCase()+Ol.slice(1);mn(Af,"on"+Vf)}mn(Ws,"onAnimationEnd");mn(Qs,"onAnimationIteration");mn(Ks,"onAnimationStart");mn("dblclick","onDoubleClick");mn("focusin","onFocus");mn("focusout","onBlur");mn(Ys,"onTransitionEnd");qn("onMouseEnter",["mouseout","mouseover"]);qn("onMouseLeave",["mouseout","mouseover"]);qn("onPointerEnter",["pointerout","pointerover"]);qn("onPointerLeave",["pointerout","pointerover"]);Rn("onChange","change click focusin focusout input keydown keyup selectionchange".split(" "));Rn("onSelect","focusout contextmenu dragend focusin keydown keyup mousedown mouseup selectionchange".split(" "));Rn("onBeforeInput",["compositionend","keypress","textInput","paste"]);Rn("onCompositionEnd","compositionend focusout keydown keypress keyup mousedown".split(" "));Rn("onCompositionStart","compositionstart focusout keydown keypress keyup mousedown".split(" "));Rn("onCompositionUpdate","compositionupdate focusout keydown keypress keyup mousedown".split(" "));var kt="abort canplay canplaythrough durationchange emptied encrypted ended error loadeddata loadedmetadata loadstart pause play playing progress ratechange resize seeked seeking stalled suspend timeupdate volumechange waiting".split(" "),Bf=new Set("cancel close invalid load scroll toggle".split(" ").concat(kt));function su(e,n,t){var r=e.type||"unknown-event";e.currentTarget=t,$c(r,n,void 0,e),e.currentTarget=null}function Gs(e,n){n=(n&4)!==0;for(var t=0;t<e.length;t++){var r=e[t],l=r.event;r=r.listeners;e:{var o=void 0;if(n)for(var i=r.length-1;0<=i;i--){var u=r[i],s=u.instance,c=u.currentTarget;if(u=u.listener,s!==o&&l.isPropagationStopped())break e;su(l,u,c),o=s}else for(i=0;i<r.length;i++){if(u=r[i],s=u.instance,c=u.currentTarget,u=u.listener,s!==o&&l.isPropagationStopped())break e;su(l,u,c),o=s}}}if(Or)throw e=uo,Or=!1,uo=null,e}function D(e,n){var t=n[go];t===void 0&&(t=n[go]=new Set);var r=e+"__bubble";t.has(r)||(Zs(n,e,2,!1),t.add(r))}function jl(e,n,t){var r=0;n&&(r|=4),Zs(t,e,r,n)}var cr="_reactListening"+Math.random().toString(36).slice(2);function $t(e){if(!e[cr]){e[cr]=!0,rs.forEach(function(t){t!=="selectionchange"&&(Bf.has(t)||jl(t,!1,e),jl(t,!0,e))});var n=e.nodeType===9?e:e.ownerDocument;n===null||n[cr]||(n[cr]=!0,jl("selectionchange",!1,n))}}function Zs(e,n,t,r){switch(js(n)){case 1:var l=nf;break;case 4:l=tf;break;default:l=bo}t=l.bind(null,n,t,e),l=void 0,!io||n!=="touchstart"&&n!=="touchmove"&&n!=="wheel"||(l=!0),r?l!==void 0?e.addEventListener(n,t,{capture:!0,passive:l}):e.addEventListener(n,t,!0):l!==void 0?e.addEventListener(n,t,{passive:l}):e.addEventListener(n,t,!1)}function Ml(e,n,t,r,l){var o=r;if(!(n&1)&&!(n&2)&&r!==null)e:for(;;){if(r===null)return;var i=r.tag;if(i===3||i===4){var u=r.stateNode.containerInfo;if(u===l||u.nodeType===8&&u.parentNode===l)break;if(i===4)for(i=r.return;i!==null;){var s=i.tag;if((s===3||s===4)&&(s=i.stateNode.containerInfo,s===l||s.nodeType===8&&s.parentNode===l))return;i=i.return}for(;u!==null;){if(i=kn(u),i===null)return;if(s=i.tag,s===5||s===6){r=o=i;continue e}u=u.parentNode}}r=r.return}ws(function(){var c=o,h=Go(t),m=[];e:{var p=Xs.get(e);if(p!==void 0){var g=ni,w=e;switch(e){case"keypress":if(Er(t)===0)break e;case"keydown":case"keyup":g=gf;break;case"focusin":w="focus",g=Pl;break;case"focusout":w="blur",g=Pl;break;case"beforeblur":case"afterblur":g=Pl;break;case"click":if(t.button===2)break e;case"auxclick":case"dblclick":case"mousedown":case"mousemove":case"mouseup":case"mouseout":case"mouseover":case"contextmenu":g=Zi;break;case"drag":case"dragend":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"dragstart":case"drop":g=of;break;case"touchcancel":case"touchend":case"touchmove":case"touchstart":g=kf;break;case Ws:case Qs:case Ks:g=af;break;case Ys:g=xf;break;case"scroll":g=rf;break;case"wheel":g=_f;break;case"copy":case"cut":case"paste":g=ff;break;case"gotpointercapture":case"lostpointercapture":case"pointercancel":case"pointerdown":case"pointermove":case"pointerout":case"pointerover":case"pointerup":g=qi}var S=(n&4)!==0,F=!S&&e==="scroll",f=S?p!==null?p+"Capture":null:p;S=[];for(var a=c,d;a!==null;){d=a;var v=d.stateNode;if(d.tag===5&&v!==null&&(d=v,f!==null&&(v=jt(a,f),v!=null&&S.push(At(a,v,d)))),F)break;a=a.return}0<S.length&&(p=new g(p,w,null,t,h),m.push({event:p,listeners:S}))}}if(!(n&7)){e:{if(p=e==="mouseover"||e==="pointerover",g=e==="mouseout"||e==="pointerout",p&&t!==lo&&(w=t.relatedTarget||t.fromElement)&&(kn(w)||w[Ke]))break e;if((g||p)&&(p=h.window===h?h:(p=h.ownerDocument)?p.defaultView||p.parentWindow:window,g?(w=t.relatedTarget||t.toElement,g=c,w=w?kn(w):null,w!==null&&(F=On(w),w!==F||w.tag!==5&&w.tag!==6)&&(w=null)):(g=null,w=c),g!==w)){if(S=Zi,v="onMouseLeave",f="onMouseEnter",a="mouse",(e==="pointerout"||e==="pointerover")&&(S=qi,v="onPointerLeave",f="onPointerEnter",a="pointer"),F=g==null?p:$n(g),d=w==null?p:$n(w),p=new S(v,a+"leave",g,t,h),p.target=F,p.relatedTarget=d,v=null,kn(h)===c&&(S=new S(f,a+"enter",w,t,h),S.target=d,S.relatedTarget=F,v=S),F=v,g&&w)n:{for(S=g,f=w,a=0,d=S;d;d=jn(d))a++;for(d=0,v=f;v;v=jn(v))d++;for(;0<a-d;)S=jn(S),a--;for(;0<d-a;)f=jn(f),d--;for(;a--;){if(S===f||f!==null&&S===f.alternate)break n;S=jn(S),f=jn(f)}S=null}else S=null;g!==null&&au(m,p,g,S,!1),w!==null&&F!==null&&au(m,F,w,S,!0)}}e:{if(p=c?$n(c):window,g=p.nodeName&&p.nodeName.toLowerCase(),g==="select"||g==="input"&&p.type==="file")var E=Of;else if(nu(p))if($s)E=If;else{E=Mf;var C=jf}else(g=p.nodeName)&&g.toLowerCase()==="input"&&(p.type==="checkbox"||p.type==="radio")&&(E=Df);if(E&&(E=E(e,c))){Us(m,E,t,h);break e}C&&C(e,p,c),e==="focusout"&&(C=p._wrapperState)&&C.controlled&&p.type==="number"&&bl(p,"number",p.value)}switch(C=c?$n(c):window,e){case"focusin":(nu(C)||C.contentEditable==="true")&&(Fn=C,fo=c,Nt=null);break;case"focusout":Nt=fo=Fn=null;break;case"mousedown":po=!0;break;case"contextmenu":case"mouseup":case"dragend":po=!1,iu(m,t,h);break;case"selectionchange":if($f)break;case"keydown":case"keyup":iu(m,t,h)}var _;if(ri)e:{switch(e){case"compositionstart":var N="onCompositionStart";break e;case"compositionend":N="onCompositionEnd";break e;case"compositionupdate":N="onCompositionUpdate";break e}N=void 0}else In?Is(e,t)&&(N="onCompositionEnd"):e==="keydown"&&t.keyCode===229&&(N="onCompositionStart");N&&(Ds&&t.locale!=="ko"&&(In||N!=="onCompositionStart"?N==="onCompositionEnd"&&In&&(_=Ms()):(nn=h,ei="value"in nn?nn.value:nn.textContent,In=!0)),C=Fr(c,N),0<C.length&&(N=new Ji(N,e,null,t,h),m.push({event:N,listeners:C}),_?N.data=_:(_=Fs(t),_!==null&&(N.data=_)))),(_=Pf?zf(e,t):Lf(e,t))&&(c=Fr(c,"onBeforeInput"),0<c.length&&(h=new Ji("onBeforeInput","beforeinput",null,t,h),m.push({event:h,listeners:c}),h.data=_))}Gs(m,n)})}function At(e,n,t){return{instance:e,listener:n,currentTarget:t}}function Fr(e,n){for(var t=n+"Capture",r=[];e!==null;){var l=e,o=l.stateNode;l.tag===5&&o!==null&&(l=o,o=jt(e,t),o!=null&&r.unshift(At(e,o,l)),o=jt(e,n),o!=null&&r.push(At(e,o,l))),e=e.return}return r}function jn(e){if(e===null)return null;do e=e.return;while(e&&e.tag!==5);return e||null}function au(e,n,t,r,l){for(var o=n._reactName,i=[];t!==null&&t!==r;){var u=t,s=u.alternate,c=u.stateNode;if(s!==null&&s===r)break;u.tag===5&&c!==null&&(u=c,l?(s=jt(t,o),s!=null&&i.unshift(At(t,s,u))):l||(s=jt(t,o),s!=null&&i.push(At(t,s,u)))),t=t.return}i.length!==0&&e.push({event:n,listeners:i})}var Hf=/\r\n?/g,Wf=/\u0000|\uFFFD/g;function cu(e){return(typeof e=="string"?e:""+e).replace(Hf,`
 `).replace(Wf,"")}function fr(e,n,t){if(n=cu(n),cu(e)!==n&&t)throw Error(y(425))}function Ur(){}var mo=null,ho=null;function vo(e,n){return e==="textarea"||e==="noscript"||typeof n.children=="string"||typeof n.children=="number"||typeof n.dangerouslySetInnerHTML=="object"&&n.dangerouslySetInnerHTML!==null&&n.dangerouslySetInnerHTML.__html!=null}var yo=typeof setTimeout=="function"?setTimeout:void 0,Qf=typeof clearTimeout=="function"?clearTimeout:void 0,fu=typeof Promise=="function"?Promise:void 0,Kf=typeof queueMicrotask=="function"?queueMicrotask:typeof fu<"u"?function(e){return fu.resolve(null).then(e).catch(Yf)}:yo;function Yf(e){setTimeout(function(){throw e})}function Dl(e,n){var t=n,r=0;do{var l=t.nextSibling;if(e.removeChild(t),l&&l.nodeType===8)if(t=l.data,t==="/$"){if(r===0){e.removeChild(l),It(n);return}r--}else t!=="$"&&t!=="$?"&&t!=="$!"||r++;t=l}while(t);It(n)}function un(e){for(;e!=null;e=e.nextSibling){var n=e.nodeType;if(n===1||n===3)break;if(n===8){if(n=e.data,n==="$"||n==="$!"||n==="$?")break;if(n==="/$")return null}}return e}function du(e){e=e.previousSibling;for(var n=0;e;){if(e.nodeType===8){var t=e.data;if(t==="$"||t==="$!"||t==="$?"){if(n===0)return e;n--}else t==="/$"&&n++}e=e.previousSibling}return null}var ut=Math.random().toString(36).slice(2),Fe="__reactFiber$"+ut,Vt="__reactProps$"+ut,Ke="__reactContainer$"+ut,go="__reactEvents$"+ut,Xf="__reactListeners$"+ut,Gf="__reactHandles$"+ut;function kn(e){var n=e[Fe];if(n)return n;for(var t=e.parentNode;t;){if(n=t[Ke]||t[Fe]){if(t=n.alternate,n.child!==null||t!==null&&t.child!==null)for(e=du(e);e!==null;){if(t=e[Fe])return t;e=du(e)}return n}e=t,t=e.parentNode}return null}function Jt(e){return e=e[Fe]||e[Ke],!e||e.tag!==5&&e.tag!==6&&e.tag!==13&&e.tag!==3?null:e}function $n(e){if(e.tag===5||e.tag===6)return e.stateNode;throw Error(y(33))}function ol(e){return e[Vt]||null}var wo=[],An=-1;function hn(e){return{current:e}}function I(e){0>An||(e.current=wo[An],wo[An]=null,An--)}function M(e,n){An++,wo[An]=e.current,e.current=n}var pn={},le=hn(pn),fe=hn(!1),Nn=pn;function bn(e,n){var t=e.type.contextTypes;if(!t)return pn;var r=e.stateNode;if(r&&r.__reactInternalMemoizedUnmaskedChildContext===n)return r.__reactInternalMemoizedMaskedChildContext;var l={},o;for(o in t)l[o]=n[o];return r&&(e=e.stateNode,e.__reactInternalMemoizedUnmaskedChildContext=n,e.__reactInternalMemoizedMaskedChildContext=l),l}function de(e){return e=e.childContextTypes,e!=null}function $r(){I(fe),I(le)}function pu(e,n,t){if(le.current!==pn)throw Error(y(168));M(le,n),M(fe,t)}function Js(e,n,t){var r=e.stateNode;if(n=n.childContextTypes,typeof r.getChildContext!="function")return t;r=r.getChildContext();for(var l in r)if(!(l in n))throw Error(y(108,Oc(e)||"Unknown",l));return V({},t,r)}function Ar(e){return e=(e=e.stateNode)&&e.__reactInternalMemoizedMergedChildContext||pn,Nn=le.current,M(le,e),M(fe,fe.current),!0}function mu(e,n,t){var r=e.stateNode;if(!r)throw Error(y(169));t?(e=Js(e,n,Nn),r.__reactInternalMemoizedMergedChildContext=e,I(fe),I(le),M(le,e)):I(fe),M(fe,t)}var Ve=null,il=!1,Il=!1;function qs(e){Ve===null?Ve=[e]:Ve.push(e)}function Zf(e){il=!0,qs(e)}function vn(){if(!Il&&Ve!==null){Il=!0;var e=0,n=j;try{var t=Ve;for(j=1;e<t.length;e++){var r=t[e];do r=r(!0);while(r!==null)}Ve=null,il=!1}catch(l){throw Ve!==null&&(Ve=Ve.slice(e+1)),xs(Zo,vn),l}finally{j=n,Il=!1}}return null}var Vn=[],Bn=0,Vr=null,Br=0,Se=[],ke=0,Pn=null,Be=1,He="";function wn(e,n){Vn[Bn++]=Br,Vn[Bn++]=Vr,Vr=e,Br=n}function bs(e,n,t){Se[ke++]=Be,Se[ke++]=He,Se[ke++]=Pn,Pn=e;var r=Be;e=He;var l=32-Oe(r)-1;r&=~(1<<l),t+=1;var o=32-Oe(n)+l;if(30<o){var i=l-l%5;o=(r&(1<<i)-1).toString(32),r>>=i,l-=i,Be=1<<32-Oe(n)+l|t<<l|r,He=o+e}else Be=1<<o|t<<l|r,He=e}function oi(e){e.return!==null&&(wn(e,1),bs(e,1,0))}function ii(e){for(;e===Vr;)Vr=Vn[--Bn],Vn[Bn]=null,Br=Vn[--Bn],Vn[Bn]=null;for(;e===Pn;)Pn=Se[--ke],Se[ke]=null,He=Se[--ke],Se[ke]=null,Be=Se[--ke],Se[ke]=null}var ve=null,he=null,U=!1,Re=null;function ea(e,n){var t=Ee(5,null,null,0);t.elementType="DELETED",t.stateNode=n,t.return=e,n=e.deletions,n===null?(e.deletions=[t],e.flags|=16):n.push(t)}function hu(e,n){switch(e.tag){case 5:var t=e.type;return n=n.nodeType!==1||t.toLowerCase()!==n.nodeName.toLowerCase()?null:n,n!==null?(e.stateNode=n,ve=e,he=un(n.firstChild),!0):!1;case 6:return n=e.pendingProps===""||n.nodeType!==3?null:n,n!==null?(e.stateNode=n,ve=e,he=null,!0):!1;case 13:return n=n.nodeType!==8?null:n,n!==null?(t=Pn!==null?{id:Be,overflow:He}:null,e.memoizedState={dehydrated:n,treeContext:t,retryLane:1073741824},t=Ee(18,null,null,0),t.stateNode=n,t.return=e,e.child=t,ve=e,he=null,!0):!1;default:return!1}}function So(e){return(e.mode&1)!==0&&(e.flags&128)===0}function ko(e){if(U){var n=he;if(n){var t=n;if(!hu(e,n)){if(So(e))throw Error(y(418));n=un(t.nextSibling);var r=ve;n&&hu(e,n)?ea(r,t):(e.flags=e.flags&-4097|2,U=!1,ve=e)}}else{if(So(e))throw Error(y(418));e.flags=e.flags&-4097|2,U=!1,ve=e}}}function vu(e){for(e=e.return;e!==null&&e.tag!==5&&e.tag!==3&&e.tag!==13;)e=e.return;ve=e}function dr(e){if(e!==ve)return!1;if(!U)return vu(e),U=!0,!1;var n;if((n=e.tag!==3)&&!(n=e.tag!==5)&&(n=e.type,n=n!=="head"&&n!=="body"&&!vo(e.type,e.memoizedProps)),n&&(n=he)){if(So(e))throw na(),Error(y(418));for(;n;)ea(e,n),n=un(n.nextSibling)}if(vu(e),e.tag===13){if(e=e.memoizedState,e=e!==null?e.dehydrated:null,!e)throw Error(y(317));e:{for(e=e.nextSibling,n=0;e;){if(e.nodeType===8){var t=e.data;if(t==="/$"){if(n===0){he=un(e.nextSibling);break e}n--}else t!=="$"&&t!=="$!"&&t!=="$?"||n++}e=e.nextSibling}he=null}}else he=ve?un(e.stateNode.nextSibling):null;return!0}function na(){for(var e=he;e;)e=un(e.nextSibling)}function et(){he=ve=null,U=!1}function ui(e){Re===null?Re=[e]:Re.push(e)}var Jf=Ge.ReactCurrentBatchConfig;function ze(e,n){if(e&&e.defaultProps){n=V({},n),e=e.defaultProps;for(var t in e)n[t]===void 0&&(n[t]=e[t]);return n}return n}var Hr=hn(null),Wr=null,Hn=null,si=null;function ai(){si=Hn=Wr=null}function ci(e){var n=Hr.current;I(Hr),e._currentValue=n}function Eo(e,n,t){for(;e!==null;){var r=e.alternate;if((e.childLanes&n)!==n?(e.childLanes|=n,r!==null&&(r.childLanes|=n)):r!==null&&(r.childLanes&n)!==n&&(r.childLanes|=n),e===t)break;e=e.return}}function Zn(e,n){Wr=e,si=Hn=null,e=e.dependencies,e!==null&&e.firstContext!==null&&(e.lanes&n&&(ce=!0),e.firstContext=null)}function Ce(e){var n=e._currentValue;if(si!==e)if(e={context:e,memoizedValue:n,next:null},Hn===null){if(Wr===null)throw Error(y(308));Hn=e,Wr.dependencies={lanes:0,firstContext:e}}else Hn=Hn.next=e;return n}var En=null;function fi(e){En===null?En=[e]:En.push(e)}function ta(e,n,t,r){var l=n.interleaved;return l===null?(t.next=t,fi(n)):(t.next=l.next,l.next=t),n.interleaved=t,Ye(e,r)}function Ye(e,n){e.lanes|=n;var t=e.alternate;for(t!==null&&(t.lanes|=n),t=e,e=e.return;e!==null;)e.childLanes|=n,t=e.alternate,t!==null&&(t.childLanes|=n),t=e,e=e.return;return t.tag===3?t.stateNode:null}var qe=!1;function di(e){e.updateQueue={baseState:e.memoizedState,firstBaseUpdate:null,lastBaseUpdate:null,shared:{pending:null,interleaved:null,lanes:0},effects:null}}function ra(e,n){e=e.updateQueue,n.updateQueue===e&&(n.updateQueue={baseState:e.baseState,firstBaseUpdate:e.firstBaseUpdate,lastBaseUpdate:e.lastBaseUpdate,shared:e.shared,effects:e.effects})}function We(e,n){return{eventTime:e,lane:n,tag:0,payload:null,callback:null,next:null}}function sn(e,n,t){var r=e.updateQueue;if(r===null)return null;if(r=r.shared,R&2){var l=r.pending;return l===null?n.next=n:(n.next=l.next,l.next=n),r.pending=n,Ye(e,t)}return l=r.interleaved,l===null?(n.next=n,fi(r)):(n.next=l.next,l.next=n),r.interleaved=n,Ye(e,t)}function xr(e,n,t){if(n=n.updateQueue,n!==null&&(n=n.shared,(t&4194240)!==0)){var r=n.lanes;r&=e.pendingLanes,t|=r,n.lanes=t,Jo(e,t)}}function yu(e,n){var t=e.updateQueue,r=e.alternate;if(r!==null&&(r=r.updateQueue,t===r)){var l=null,o=null;if(t=t.firstBaseUpdate,t!==null){do{var i={eventTime:t.eventTime,lane:t.lane,tag:t.tag,payload:t.payload,callback:t.callback,next:null};o===null?l=o=i:o=o.next=i,t=t.next}while(t!==null);o===null?l=o=n:o=o.next=n}else l=o=n;t={baseState:r.baseState,firstBaseUpdate:l,lastBaseUpdate:o,shared:r.shared,effects:r.effects},e.updateQueue=t;return}e=t.lastBaseUpdate,e===null?t.firstBaseUpdate=n:e.next=n,t.lastBaseUpdate=n}function Qr(e,n,t,r){var l=e.updateQueue;qe=!1;var o=l.firstBaseUpdate,i=l.lastBaseUpdate,u=l.shared.pending;if(u!==null){l.shared.pending=null;var s=u,c=s.next;s.next=null,i===null?o=c:i.next=c,i=s;var h=e.alternate;h!==null&&(h=h.updateQueue,u=h.lastBaseUpdate,u!==i&&(u===null?h.firstBaseUpdate=c:u.next=c,h.lastBaseUpdate=s))}if(o!==null){var m=l.baseState;i=0,h=c=s=null,u=o;do{var p=u.lane,g=u.eventTime;if((r&p)===p){h!==null&&(h=h.next={eventTime:g,lane:0,tag:u.tag,payload:u.payload,callback:u.callback,next:null});e:{var w=e,S=u;switch(p=n,g=t,S.tag){case 1:if(w=S.payload,typeof w=="function"){m=w.call(g,m,p);break e}m=w;break e;case 3:w.flags=w.flags&-65537|128;case 0:if(w=S.payload,p=typeof w=="function"?w.call(g,m,p):w,p==null)break e;m=V({},m,p);break e;case 2:qe=!0}}u.callback!==null&&u.lane!==0&&(e.flags|=64,p=l.effects,p===null?l.effects=[u]:p.push(u))}else g={eventTime:g,lane:p,tag:u.tag,payload:u.payload,callback:u.callback,next:null},h===null?(c=h=g,s=m):h=h.next=g,i|=p;if(u=u.next,u===null){if(u=l.shared.pending,u===null)break;p=u,u=p.next,p.next=null,l.lastBaseUpdate=p,l.shared.pending=null}}while(1);if(h===null&&(s=m),l.baseState=s,l.firstBaseUpdate=c,l.lastBaseUpdate=h,n=l.shared.interleaved,n!==null){l=n;do i|=l.lane,l=l.next;while(l!==n)}else o===null&&(l.shared.lanes=0);Ln|=i,e.lanes=i,e.memoizedState=m}}function gu(e,n,t){if(e=n.effects,n.effects=null,e!==null)for(n=0;n<e.length;n++){var r=e[n],l=r.callback;if(l!==null){if(r.callback=null,r=t,typeof l!="function")throw Error(y(191,l));l.call(r)}}}var la=new ts.Component().refs;function xo(e,n,t,r){n=e.memoizedState,t=t(r,n),t=t==null?n:V({},n,t),e.memoizedState=t,e.lanes===0&&(e.updateQueue.baseState=t)}var ul={isMounted:function(e){return(e=e._reactInternals)?On(e)===e:!1},enqueueSetState:function(e,n,t){e=e._reactInternals;var r=ie(),l=cn(e),o=We(r,l);o.payload=n,t!=null&&(o.callback=t),n=sn(e,o,l),n!==null&&(je(n,e,l,r),xr(n,e,l))},enqueueReplaceState:function(e,n,t){e=e._reactInternals;var r=ie(),l=cn(e),o=We(r,l);o.tag=1,o.payload=n,t!=null&&(o.callback=t),n=sn(e,o,l),n!==null&&(je(n,e,l,r),xr(n,e,l))},enqueueForceUpdate:function(e,n){e=e._reactInternals;var t=ie(),r=cn(e),l=We(t,r);l.tag=2,n!=null&&(l.callback=n),n=sn(e,l,r),n!==null&&(je(n,e,r,t),xr(n,e,r))}};function wu(e,n,t,r,l,o,i){return e=e.stateNode,typeof e.shouldComponentUpdate=="function"?e.shouldComponentUpdate(r,o,i):n.prototype&&n.prototype.isPureReactComponent?!Ut(t,r)||!Ut(l,o):!0}function oa(e,n,t){var r=!1,l=pn,o=n.contextType;return typeof o=="object"&&o!==null?o=Ce(o):(l=de(n)?Nn:le.current,r=n.contextTypes,o=(r=r!=null)?bn(e,l):pn),n=new n(t,o),e.memoizedState=n.state!==null&&n.state!==void 0?n.state:null,n.updater=ul,e.stateNode=n,n._reactInternals=e,r&&(e=e.stateNode,e.__reactInternalMemoizedUnmaskedChildContext=l,e.__reactInternalMemoizedMaskedChildContext=o),n}function Su(e,n,t,r){e=n.state,typeof n.componentWillReceiveProps=="function"&&n.componentWillReceiveProps(t,r),typeof n.UNSAFE_componentWillReceiveProps=="function"&&n.UNSAFE_componentWillReceiveProps(t,r),n.state!==e&&ul.enqueueReplaceState(n,n.state,null)}function Co(e,n,t,r){var l=e.stateNode;l.props=t,l.state=e.memoizedState,l.refs=la,di(e);var o=n.contextType;typeof o=="object"&&o!==null?l.context=Ce(o):(o=de(n)?Nn:le.current,l.context=bn(e,o)),l.state=e.memoizedState,o=n.getDerivedStateFromProps,typeof o=="function"&&(xo(e,n,o,t),l.state=e.memoizedState),typeof n.getDerivedStateFromProps=="function"||typeof l.getSnapshotBeforeUpdate=="function"||typeof l.UNSAFE_componentWillMount!="function"&&typeof l.componentWillMount!="function"||(n=l.state,typeof l.componentWillMount=="function"&&l.componentWillMount(),typeof l.UNSAFE_componentWillMount=="function"&&l.UNSAFE_componentWillMount(),n!==l.state&&ul.enqueueReplaceState(l,l.state,null),Qr(e,t,l,r),l.state=e.memoizedState),typeof l.componentDidMount=="function"&&(e.flags|=4194308)}function ht(e,n,t){if(e=t.ref,e!==null&&typeof e!="function"&&typeof e!="object"){if(t._owner){if(t=t._owner,t){if(t.tag!==1)throw Error(y(309));var r=t.stateNode}if(!r)throw Error(y(147,e));var l=r,o=""+e;return n!==null&&n.ref!==null&&typeof n.ref=="function"&&n.ref._stringRef===o?n.ref:(n=function(i){var u=l.refs;u===la&&(u=l.refs={}),i===null?delete u[o]:u[o]=i},n._stringRef=o,n)}if(typeof e!="string")throw Error(y(284));if(!t._owner)throw Error(y(290,e))}return e}function pr(e,n){throw e=Object.prototype.toString.call(n),Error(y(31,e==="[object Object]"?"object with keys {"+Object.keys(n).join(", ")+"}":e))}function ku(e){var n=e._init;return n(e._payload)}function ia(e){function n(f,a){if(e){var d=f.deletions;d===null?(f.deletions=[a],f.flags|=16):d.push(a)}}function t(f,a){if(!e)return null;for(;a!==null;)n(f,a),a=a.sibling;return null}function r(f,a){for(f=new Map;a!==null;)a.key!==null?f.set(a.key,a):f.set(a.index,a),a=a.sibling;return f}function l(f,a){return f=fn(f,a),f.index=0,f.sibling=null,f}function o(f,a,d){return f.index=d,e?(d=f.alternate,d!==null?(d=d.index,d<a?(f.flags|=2,a):d):(f.flags|=2,a)):(f.flags|=1048576,a)}function i(f){return e&&f.alternate===null&&(f.flags|=2),f}function u(f,a,d,v){return a===null||a.tag!==6?(a=Hl(d,f.mode,v),a.return=f,a):(a=l(a,d),a.return=f,a)}function s(f,a,d,v){var E=d.type;return E===Dn?h(f,a,d.props.children,v,d.key):a!==null&&(a.elementType===E||typeof E=="object"&&E!==null&&E.$$typeof===Je&&ku(E)===a.type)?(v=l(a,d.props),v.ref=ht(f,a,d),v.return=f,v):(v=Lr(d.type,d.key,d.props,null,f.mode,v),v.ref=ht(f,a,d),v.return=f,v)}function c(f,a,d,v){return a===null||a.tag!==4||a.stateNode.containerInfo!==d.containerInfo||a.stateNode.implementation!==d.implementation?(a=Wl(d,f.mode,v),a.return=f,a):(a=l(a,d.children||[]),a.return=f,a)}function h(f,a,d,v,E){return a===null||a.tag!==7?(a=_n(d,f.mode,v,E),a.return=f,a):(a=l(a,d),a.return=f,a)}function m(f,a,d){if(typeof a=="string"&&a!==""||typeof a=="number")return a=Hl(""+a,f.mode,d),a.return=f,a;if(typeof a=="object"&&a!==null){switch(a.$$typeof){case tr:return d=Lr(a.type,a.key,a.props,null,f.mode,d),d.ref=ht(f,null,a),d.return=f,d;case Mn:return a=Wl(a,f.mode,d),a.return=f,a;case Je:var v=a._init;return m(f,v(a._payload),d)}if(wt(a)||ct(a))return a=_n(a,f.mode,d,null),a.return=f,a;pr(f,a)}return null}function p(f,a,d,v){var E=a!==null?a.key:null;if(typeof d=="string"&&d!==""||typeof d=="number")return E!==null?null:u(f,a,""+d,v);if(typeof d=="object"&&d!==null){switch(d.$$typeof){case tr:return d.key===E?s(f,a,d,v):null;case Mn:return d.key===E?c(f,a,d,v):null;case Je:return E=d._init,p(f,a,E(d._payload),v)}if(wt(d)||ct(d))return E!==null?null:h(f,a,d,v,null);pr(f,d)}return null}function g(f,a,d,v,E){if(typeof v=="string"&&v!==""||typeof v=="number")return f=f.get(d)||null,u(a,f,""+v,E);if(typeof v=="object"&&v!==null){switch(v.$$typeof){case tr:return f=f.get(v.key===null?d:v.key)||null,s(a,f,v,E);case Mn:return f=f.get(v.key===null?d:v.key)||null,c(a,f,v,E);case Je:var C=v._init;return g(f,a,d,C(v._payload),E)}if(wt(v)||ct(v))return f=f.get(d)||null,h(a,f,v,E,null);pr(a,v)}return null}function w(f,a,d,v){for(var E=null,C=null,_=a,N=a=0,H=null;_!==null&&N<d.length;N++){_.index>N?(H=_,_=null):H=_.sibling;var T=p(f,_,d[N],v);if(T===null){_===null&&(_=H);break}e&&_&&T.alternate===null&&n(f,_),a=o(T,a,N),C===null?E=T:C.sibling=T,C=T,_=H}if(N===d.length)return t(f,_),U&&wn(f,N),E;if(_===null){for(;N<d.length;N++)_=m(f,d[N],v),_!==null&&(a=o(_,a,N),C===null?E=_:C.sibling=_,C=_);return U&&wn(f,N),E}for(_=r(f,_);N<d.length;N++)H=g(_,f,N,d[N],v),H!==null&&(e&&H.alternate!==null&&_.delete(H.key===null?N:H.key),a=o(H,a,N),C===null?E=H:C.sibling=H,C=H);return e&&_.forEach(function(Ne){return n(f,Ne)}),U&&wn(f,N),E}function S(f,a,d,v){var E=ct(d);if(typeof E!="function")throw Error(y(150));if(d=E.call(d),d==null)throw Error(y(151));for(var C=E=null,_=a,N=a=0,H=null,T=d.next();_!==null&&!T.done;N++,T=d.next()){_.index>N?(H=_,_=null):H=_.sibling;var Ne=p(f,_,T.value,v);if(Ne===null){_===null&&(_=H);break}e&&_&&Ne.alternate===null&&n(f,_),a=o(Ne,a,N),C===null?E=Ne:C.sibling=Ne,C=Ne,_=H}if(T.done)return t(f,_),U&&wn(f,N),E;if(_===null){for(;!T.done;N++,T=d.next())T=m(f,T.value,v),T!==null&&(a=o(T,a,N),C===null?E=T:C.sibling=T,C=T);return U&&wn(f,N),E}for(_=r(f,_);!T.done;N++,T=d.next())T=g(_,f,N,T.value,v),T!==null&&(e&&T.alternate!==null&&_.delete(T.key===null?N:T.key),a=o(T,a,N),C===null?E=T:C.sibling=T,C=T);return e&&_.forEach(function(st){return n(f,st)}),U&&wn(f,N),E}function F(f,a,d,v){if(typeof d=="object"&&d!==null&&d.type===Dn&&d.key===null&&(d=d.props.children),typeof d=="object"&&d!==null){switch(d.$$typeof){case tr:e:{for(var E=d.key,C=a;C!==null;){if(C.key===E){if(E=d.type,E===Dn){if(C.tag===7){t(f,C.sibling),a=l(C,d.props.children),a.return=f,f=a;break e}}else if(C.elementType===E||typeof E=="object"&&E!==null&&E.$$typeof===Je&&ku(E)===C.type){t(f,C.sibling),a=l(C,d.props),a.ref=ht(f,C,d),a.return=f,f=a;break e}t(f,C);break}else n(f,C);C=C.sibling}d.type===Dn?(a=_n(d.props.children,f.mode,v,d.key),a.return=f,f=a):(v=Lr(d.type,d.key,d.props,null,f.mode,v),v.ref=ht(f,a,d),v.return=f,f=v)}return i(f);case Mn:e:{for(C=d.key;a!==null;){if(a.key===C)if(a.tag===4&&a.stateNode.containerInfo===d.containerInfo&&a.stateNode.implementation===d.implementation){t(f,a.sibling),a=l(a,d.children||[]),a.return=f,f=a;break e}else{t(f,a);break}else n(f,a);a=a.sibling}a=Wl(d,f.mode,v),a.return=f,f=a}return i(f);case Je:return C=d._init,F(f,a,C(d._payload),v)}if(wt(d))return w(f,a,d,v);if(ct(d))return S(f,a,d,v);pr(f,d)}return typeof d=="string"&&d!==""||typeof d=="number"?(d=""+d,a!==null&&a.tag===6?(t(f,a.sibling),a=l(a,d),a.return=f,f=a):(t(f,a),a=Hl(d,f.mode,v),a.return=f,f=a),i(f)):t(f,a)}return F}var nt=ia(!0),ua=ia(!1),qt={},$e=hn(qt),Bt=hn(qt),Ht=hn(qt);function xn(e){if(e===qt)throw Error(y(174));return e}function pi(e,n){switch(M(Ht,n),M(Bt,e),M($e,qt),e=n.nodeType,e){case 9:case 11:n=(n=n.documentElement)?n.namespaceURI:no(null,"");break;default:e=e===8?n.parentNode:n,n=e.namespaceURI||null,e=e.tagName,n=no(n,e)}I($e),M($e,n)}function tt(){I($e),I(Bt),I(Ht)}function sa(e){xn(Ht.current);var n=xn($e.current),t=no(n,e.type);n!==t&&(M(Bt,e),M($e,t))}function mi(e){Bt.current===e&&(I($e),I(Bt))}var $=hn(0);function Kr(e){for(var n=e;n!==null;){if(n.tag===13){var t=n.memoizedState;if(t!==null&&(t=t.dehydrated,t===null||t.data==="$?"||t.data==="$!"))return n}else if(n.tag===19&&n.memoizedProps.revealOrder!==void 0){if(n.flags&128)return n}else if(n.child!==null){n.child.return=n,n=n.child;continue}if(n===e)break;for(;n.sibling===null;){if(n.return===null||n.return===e)return null;n=n.return}n.sibling.return=n.return,n=n.sibling}return null}var Fl=[];function hi(){for(var e=0;e<Fl.length;e++)Fl[e]._workInProgressVersionPrimary=null;Fl.length=0}var Cr=Ge.ReactCurrentDispatcher,Ul=Ge.ReactCurrentBatchConfig,zn=0,A=null,Y=null,Z=null,Yr=!1,Pt=!1,Wt=0,qf=0;function ne(){throw Error(y(321))}function vi(e,n){if(n===null)return!1;for(var t=0;t<n.length&&t<e.length;t++)if(!Me(e[t],n[t]))return!1;return!0}function yi(e,n,t,r,l,o){if(zn=o,A=n,n.memoizedState=null,n.updateQueue=null,n.lanes=0,Cr.current=e===null||e.memoizedState===null?td:rd,e=t(r,l),Pt){o=0;do{if(Pt=!1,Wt=0,25<=o)throw Error(y(301));o+=1,Z=Y=null,n.updateQueue=null,Cr.current=ld,e=t(r,l)}while(Pt)}if(Cr.current=Xr,n=Y!==null&&Y.next!==null,zn=0,Z=Y=A=null,Yr=!1,n)throw Error(y(300));return e}function gi(){var e=Wt!==0;return Wt=0,e}function Ie(){var e={memoizedState:null,baseState:null,baseQueue:null,queue:null,next:null};return Z===null?A.memoizedState=Z=e:Z=Z.next=e,Z}function _e(){if(Y===null){var e=A.alternate;e=e!==null?e.memoizedState:null}else e=Y.next;var n=Z===null?A.memoizedState:Z.next;if(n!==null)Z=n,Y=e;else{if(e===null)throw Error(y(310));Y=e,e={memoizedState:Y.memoizedState,baseState:Y.baseState,baseQueue:Y.baseQueue,queue:Y.queue,next:null},Z===null?A.memoizedState=Z=e:Z=Z.next=e}return Z}function Qt(e,n){return typeof n=="function"?n(e):n}function $l(e){var n=_e(),t=n.queue;if(t===null)throw Error(y(311));t.lastRenderedReducer=e;var r=Y,l=r.baseQueue,o=t.pending;if(o!==null){if(l!==null){var i=l.next;l.next=o.next,o.next=i}r.baseQueue=l=o,t.pending=null}if(l!==null){o=l.next,r=r.baseState;var u=i=null,s=null,c=o;do{var h=c.lane;if((zn&h)===h)s!==null&&(s=s.next={lane:0,action:c.action,hasEagerState:c.hasEagerState,eagerState:c.eagerState,next:null}),r=c.hasEagerState?c.eagerState:e(r,c.action);else{var m={lane:h,action:c.action,hasEagerState:c.hasEagerState,eagerState:c.eagerState,next:null};s===null?(u=s=m,i=r):s=s.next=m,A.lanes|=h,Ln|=h}c=c.next}while(c!==null&&c!==o);s===null?i=r:s.next=u,Me(r,n.memoizedState)||(ce=!0),n.memoizedState=r,n.baseState=i,n.baseQueue=s,t.lastRenderedState=r}if(e=t.interleaved,e!==null){l=e;do o=l.lane,A.lanes|=o,Ln|=o,l=l.next;while(l!==e)}else l===null&&(t.lanes=0);return[n.memoizedState,t.dispatch]}function Al(e){var n=_e(),t=n.queue;if(t===null)throw Error(y(311));t.lastRenderedReducer=e;var r=t.dispatch,l=t.pending,o=n.memoizedState;if(l!==null){t.pending=null;var i=l=l.next;do o=e(o,i.action),i=i.next;while(i!==l);Me(o,n.memoizedState)||(ce=!0),n.memoizedState=o,n.baseQueue===null&&(n.baseState=o),t.lastRenderedState=o}return[o,r]}function aa(){}function ca(e,n){var t=A,r=_e(),l=n(),o=!Me(r.memoizedState,l);if(o&&(r.memoizedState=l,ce=!0),r=r.queue,wi(pa.bind(null,t,r,e),[e]),r.getSnapshot!==n||o||Z!==null&&Z.memoizedState.tag&1){if(t.flags|=2048,Kt(9,da.bind(null,t,r,l,n),void 0,null),J===null)throw Error(y(349));zn&30||fa(t,n,l)}return l}function fa(e,n,t){e.flags|=16384,e={getSnapshot:n,value:t},n=A.updateQueue,n===null?(n={lastEffect:null,stores:null},A.updateQueue=n,n.stores=[e]):(t=n.stores,t===null?n.stores=[e]:t.push(e))}function da(e,n,t,r){n.value=t,n.getSnapshot=r,ma(n)&&ha(e)}function pa(e,n,t){return t(function(){ma(n)&&ha(e)})}function ma(e){var n=e.getSnapshot;e=e.value;try{var t=n();return!Me(e,t)}catch{return!0}}function ha(e){var n=Ye(e,1);n!==null&&je(n,e,1,-1)}function Eu(e){var n=Ie();return typeof e=="function"&&(e=e()),n.memoizedState=n.baseState=e,e={pending:null,interleaved:null,lanes:0,dispatch:null,lastRenderedReducer:Qt,lastRenderedState:e},n.queue=e,e=e.dispatch=nd.bind(null,A,e),[n.memoizedState,e]}function Kt(e,n,t,r){return e={tag:e,create:n,destroy:t,deps:r,next:null},n=A.updateQueue,n===null?(n={lastEffect:null,stores:null},A.updateQueue=n,n.lastEffect=e.next=e):(t=n.lastEffect,t===null?n.lastEffect=e.next=e:(r=t.next,t.next=e,e.next=r,n.lastEffect=e)),e}function va(){return _e().memoizedState}function _r(e,n,t,r){var l=Ie();A.flags|=e,l.memoizedState=Kt(1|n,t,void 0,r===void 0?null:r)}function sl(e,n,t,r){var l=_e();r=r===void 0?null:r;var o=void 0;if(Y!==null){var i=Y.memoizedState;if(o=i.destroy,r!==null&&vi(r,i.deps)){l.memoizedState=Kt(n,t,o,r);return}}A.flags|=e,l.memoizedState=Kt(1|n,t,o,r)}function xu(e,n){return _r(8390656,8,e,n)}function wi(e,n){return sl(2048,8,e,n)}function ya(e,n){return sl(4,2,e,n)}function ga(e,n){return sl(4,4,e,n)}function wa(e,n){if(typeof n=="function")return e=e(),n(e),function(){n(null)};if(n!=null)return e=e(),n.current=e,function(){n.current=null}}function Sa(e,n,t){return t=t!=null?t.concat([e]):null,sl(4,4,wa.bind(null,n,e),t)}function Si(){}function ka(e,n){var t=_e();n=n===void 0?null:n;var r=t.memoizedState;return r!==null&&n!==null&&vi(n,r[1])?r[0]:(t.memoizedState=[e,n],e)}function Ea(e,n){var t=_e();n=n===void 0?null:n;var r=t.memoizedState;return r!==null&&n!==null&&vi(n,r[1])?r[0]:(e=e(),t.memoizedState=[e,n],e)}function xa(e,n,t){return zn&21?(Me(t,n)||(t=Ns(),A.lanes|=t,Ln|=t,e.baseState=!0),n):(e.baseState&&(e.baseState=!1,ce=!0),e.memoizedState=t)}function bf(e,n){var t=j;j=t!==0&&4>t?t:4,e(!0);var r=Ul.transition;Ul.transition={};try{e(!1),n()}finally{j=t,Ul.transition=r}}function Ca(){return _e().memoizedState}function ed(e,n,t){var r=cn(e);if(t={lane:r,action:t,hasEagerState:!1,eagerState:null,next:null},_a(e))Na(n,t);else if(t=ta(e,n,t,r),t!==null){var l=ie();je(t,e,r,l),Pa(t,n,r)}}function nd(e,n,t){var r=cn(e),l={lane:r,action:t,hasEagerState:!1,eagerState:null,next:null};if(_a(e))Na(n,l);else{var o=e.alternate;if(e.lanes===0&&(o===null||o.lanes===0)&&(o=n.lastRenderedReducer,o!==null))try{var i=n.lastRenderedState,u=o(i,t);if(l.hasEagerState=!0,l.eagerState=u,Me(u,i)){var s=n.interleaved;s===null?(l.next=l,fi(n)):(l.next=s.next,s.next=l),n.interleaved=l;return}}catch{}finally{}t=ta(e,n,l,r),t!==null&&(l=ie(),je(t,e,r,l),Pa(t,n,r))}}function _a(e){var n=e.alternate;return e===A||n!==null&&n===A}function Na(e,n){Pt=Yr=!0;var t=e.pending;t===null?n.next=n:(n.next=t.next,t.next=n),e.pending=n}function Pa(e,n,t){if(t&4194240){var r=n.lanes;r&=e.pendingLanes,t|=r,n.lanes=t,Jo(e,t)}}var Xr={readContext:Ce,useCallback:ne,useContext:ne,useEffect:ne,useImperativeHandle:ne,useInsertionEffect:ne,useLayoutEffect:ne,useMemo:ne,useReducer:ne,useRef:ne,useState:ne,useDebugValue:ne,useDeferredValue:ne,useTransition:ne,useMutableSource:ne,useSyncExternalStore:ne,useId:ne,unstable_isNewReconciler:!1},td={readContext:Ce,useCallback:function(e,n){return Ie().memoizedState=[e,n===void 0?null:n],e},useContext:Ce,useEffect:xu,useImperativeHandle:function(e,n,t){return t=t!=null?t.concat([e]):null,_r(4194308,4,wa.bind(null,n,e),t)},useLayoutEffect:function(e,n){return _r(4194308,4,e,n)},useInsertionEffect:function(e,n){return _r(4,2,e,n)},useMemo:function(e,n){var t=Ie();return n=n===void 0?null:n,e=e(),t.memoizedState=[e,n],e},useReducer:function(e,n,t){var r=Ie();return n=t!==void 0?t(n):n,r.memoizedState=r.baseState=n,e={pending:null,interleaved:null,lanes:0,dispatch:null,lastRenderedReducer:e,lastRenderedState:n},r.queue=e,e=e.dispatch=ed.bind(null,A,e),[r.memoizedState,e]},useRef:function(e){var n=Ie();return e={current:e},n.memoizedState=e},useState:Eu,useDebugValue:Si,useDeferredValue:function(e){return Ie().memoizedState=e},useTransition:function(){var e=Eu(!1),n=e[0];return e=bf.bind(null,e[1]),Ie().memoizedState=e,[n,e]},useMutableSource:function(){},useSyncExternalStore:function(e,n,t){var r=A,l=Ie();if(U){if(t===void 0)throw Error(y(407));t=t()}else{if(t=n(),J===null)throw Error(y(349));zn&30||fa(r,n,t)}l.memoizedState=t;var o={value:t,getSnapshot:n};return l.queue=o,xu(pa.bind(null,r,o,e),[e]),r.flags|=2048,Kt(9,da.bind(null,r,o,t,n),void 0,null),t},useId:function(){var e=Ie(),n=J.identifierPrefix;if(U){var t=He,r=Be;t=(r&~(1<<32-Oe(r)-1)).toString(32)+t,n=":"+n+"R"+t,t=Wt++,0<t&&(n+="H"+t.toString(32)),n+=":"}else t=qf++,n=":"+n+"r"+t.toString(32)+":";return e.memoizedState=n},unstable_isNewReconciler:!1},rd={readContext:Ce,useCallback:ka,useContext:Ce,useEffect:wi,useImperativeHandle:Sa,useInsertionEffect:ya,useLayoutEffect:ga,useMemo:Ea,useReducer:$l,useRef:va,useState:function(){return $l(Qt)},useDebugValue:Si,useDeferredValue:function(e){var n=_e();return xa(n,Y.memoizedState,e)},useTransition:function(){var e=$l(Qt)[0],n=_e().memoizedState;return[e,n]},useMutableSource:aa,useSyncExternalStore:ca,useId:Ca,unstable_isNewReconciler:!1},ld={readContext:Ce,useCallback:ka,useContext:Ce,useEffect:wi,useImperativeHandle:Sa,useInsertionEffect:ya,useLayoutEffect:ga,useMemo:Ea,useReducer:Al,useRef:va,useState:function(){return Al(Qt)},useDebugValue:Si,useDeferredValue:function(e){var n=_e();return Y===null?n.memoizedState=e:xa(n,Y.memoizedState,e)},useTransition:function(){var e=Al(Qt)[0],n=_e().memoizedState;return[e,n]},useMutableSource:aa,useSyncExternalStore:ca,useId:Ca,unstable_isNewReconciler:!1};function rt(e,n){try{var t="",r=n;do t+=Rc(r),r=r.return;while(r);var l=t}catch(o){l=`
 Error generating stack: `+o.message+`
-`+o.stack}return{value:e,source:n,stack:l,digest:null}}function Vl(e,n,t){return{value:e,source:null,stack:t??null,digest:n??null}}function _o(e,n){try{console.error(n.value)}catch(t){setTimeout(function(){throw t})}}var od=typeof WeakMap=="function"?WeakMap:Map;function za(e,n,t){t=We(-1,t),t.tag=3,t.payload={element:null};var r=n.value;return t.callback=function(){Zr||(Zr=!0,Do=r),_o(e,n)},t}function La(e,n,t){t=We(-1,t),t.tag=3;var r=e.type.getDerivedStateFromError;if(typeof r=="function"){var l=n.value;t.payload=function(){return r(l)},t.callback=function(){_o(e,n)}}var o=e.stateNode;return o!==null&&typeof o.componentDidCatch=="function"&&(t.callback=function(){_o(e,n),typeof r!="function"&&(an===null?an=new Set([this]):an.add(this));var i=n.stack;this.componentDidCatch(n.value,{componentStack:i!==null?i:""})}),t}function Cu(e,n,t){var r=e.pingCache;if(r===null){r=e.pingCache=new od;var l=new Set;r.set(n,l)}else l=r.get(n),l===void 0&&(l=new Set,r.set(n,l));l.has(t)||(l.add(t),e=wd.bind(null,e,n,t),n.then(e,e))}function _u(e){do{var n;if((n=e.tag===13)&&(n=e.memoizedState,n=n!==null?n.dehydrated!==null:!0),n)return e;e=e.return}while(e!==null);return null}function Nu(e,n,t,r,l){return e.mode&1?(e.flags|=65536,e.lanes=l,e):(e===n?e.flags|=65536:(e.flags|=128,t.flags|=131072,t.flags&=-52805,t.tag===1&&(t.alternate===null?t.tag=17:(n=We(-1,1),n.tag=2,sn(t,n,1))),t.lanes|=1),e)}var id=Ge.ReactCurrentOwner,ce=!1;function oe(e,n,t,r){n.child=e===null?ua(n,null,t,r):nt(n,e.child,t,r)}function Pu(e,n,t,r,l){t=t.render;var o=n.ref;return Zn(n,l),r=yi(e,n,t,r,o,l),t=gi(),e!==null&&!ce?(n.updateQueue=e.updateQueue,n.flags&=-2053,e.lanes&=~l,Xe(e,n,l)):(U&&t&&oi(n),n.flags|=1,oe(e,n,r,l),n.child)}function zu(e,n,t,r,l){if(e===null){var o=t.type;return typeof o=="function"&&!zi(o)&&o.defaultProps===void 0&&t.compare===null&&t.defaultProps===void 0?(n.tag=15,n.type=o,Ta(e,n,o,r,l)):(e=Lr(t.type,null,r,n,n.mode,l),e.ref=n.ref,e.return=n,n.child=e)}if(o=e.child,!(e.lanes&l)){var i=o.memoizedProps;if(t=t.compare,t=t!==null?t:Ut,t(i,r)&&e.ref===n.ref)return Xe(e,n,l)}return n.flags|=1,e=fn(o,r),e.ref=n.ref,e.return=n,n.child=e}function Ta(e,n,t,r,l){if(e!==null){var o=e.memoizedProps;if(Ut(o,r)&&e.ref===n.ref)if(ce=!1,n.pendingProps=r=o,(e.lanes&l)!==0)e.flags&131072&&(ce=!0);else return n.lanes=e.lanes,Xe(e,n,l)}return No(e,n,t,r,l)}function Ra(e,n,t){var r=n.pendingProps,l=r.children,o=e!==null?e.memoizedState:null;if(r.mode==="hidden")if(!(n.mode&1))n.memoizedState={baseLanes:0,cachePool:null,transitions:null},M(Qn,me),me|=t;else{if(!(t&1073741824))return e=o!==null?o.baseLanes|t:t,n.lanes=n.childLanes=1073741824,n.memoizedState={baseLanes:e,cachePool:null,transitions:null},n.updateQueue=null,M(Qn,me),me|=e,null;n.memoizedState={baseLanes:0,cachePool:null,transitions:null},r=o!==null?o.baseLanes:t,M(Qn,me),me|=r}else o!==null?(r=o.baseLanes|t,n.memoizedState=null):r=t,M(Qn,me),me|=r;return oe(e,n,l,t),n.child}function Oa(e,n){var t=n.ref;(e===null&&t!==null||e!==null&&e.ref!==t)&&(n.flags|=512,n.flags|=2097152)}function No(e,n,t,r,l){var o=de(t)?Nn:le.current;return o=bn(n,o),Zn(n,l),t=yi(e,n,t,r,o,l),r=gi(),e!==null&&!ce?(n.updateQueue=e.updateQueue,n.flags&=-2053,e.lanes&=~l,Xe(e,n,l)):(U&&r&&oi(n),n.flags|=1,oe(e,n,t,l),n.child)}function Lu(e,n,t,r,l){if(de(t)){var o=!0;Ar(n)}else o=!1;if(Zn(n,l),n.stateNode===null)Nr(e,n),oa(n,t,r),Co(n,t,r,l),r=!0;else if(e===null){var i=n.stateNode,u=n.memoizedProps;i.props=u;var s=i.context,c=t.contextType;typeof c=="object"&&c!==null?c=Ce(c):(c=de(t)?Nn:le.current,c=bn(n,c));var h=t.getDerivedStateFromProps,m=typeof h=="function"||typeof i.getSnapshotBeforeUpdate=="function";m||typeof i.UNSAFE_componentWillReceiveProps!="function"&&typeof i.componentWillReceiveProps!="function"||(u!==r||s!==c)&&Su(n,i,r,c),qe=!1;var p=n.memoizedState;i.state=p,Qr(n,r,i,l),s=n.memoizedState,u!==r||p!==s||fe.current||qe?(typeof h=="function"&&(xo(n,t,h,r),s=n.memoizedState),(u=qe||wu(n,t,u,r,p,s,c))?(m||typeof i.UNSAFE_componentWillMount!="function"&&typeof i.componentWillMount!="function"||(typeof i.componentWillMount=="function"&&i.componentWillMount(),typeof i.UNSAFE_componentWillMount=="function"&&i.UNSAFE_componentWillMount()),typeof i.componentDidMount=="function"&&(n.flags|=4194308)):(typeof i.componentDidMount=="function"&&(n.flags|=4194308),n.memoizedProps=r,n.memoizedState=s),i.props=r,i.state=s,i.context=c,r=u):(typeof i.componentDidMount=="function"&&(n.flags|=4194308),r=!1)}else{i=n.stateNode,ra(e,n),u=n.memoizedProps,c=n.type===n.elementType?u:ze(n.type,u),i.props=c,m=n.pendingProps,p=i.context,s=t.contextType,typeof s=="object"&&s!==null?s=Ce(s):(s=de(t)?Nn:le.current,s=bn(n,s));var g=t.getDerivedStateFromProps;(h=typeof g=="function"||typeof i.getSnapshotBeforeUpdate=="function")||typeof i.UNSAFE_componentWillReceiveProps!="function"&&typeof i.componentWillReceiveProps!="function"||(u!==m||p!==s)&&Su(n,i,r,s),qe=!1,p=n.memoizedState,i.state=p,Qr(n,r,i,l);var w=n.memoizedState;u!==m||p!==w||fe.current||qe?(typeof g=="function"&&(xo(n,t,g,r),w=n.memoizedState),(c=qe||wu(n,t,c,r,p,w,s)||!1)?(h||typeof i.UNSAFE_componentWillUpdate!="function"&&typeof i.componentWillUpdate!="function"||(typeof i.componentWillUpdate=="function"&&i.componentWillUpdate(r,w,s),typeof i.UNSAFE_componentWillUpdate=="function"&&i.UNSAFE_componentWillUpdate(r,w,s)),typeof i.componentDidUpdate=="function"&&(n.flags|=4),typeof i.getSnapshotBeforeUpdate=="function"&&(n.flags|=1024)):(typeof i.componentDidUpdate!="function"||u===e.memoizedProps&&p===e.memoizedState||(n.flags|=4),typeof i.getSnapshotBeforeUpdate!="function"||u===e.memoizedProps&&p===e.memoizedState||(n.flags|=1024),n.memoizedProps=r,n.memoizedState=w),i.props=r,i.state=w,i.context=s,r=c):(typeof i.componentDidUpdate!="function"||u===e.memoizedProps&&p===e.memoizedState||(n.flags|=4),typeof i.getSnapshotBeforeUpdate!="function"||u===e.memoizedProps&&p===e.memoizedState||(n.flags|=1024),r=!1)}return Po(e,n,t,r,o,l)}function Po(e,n,t,r,l,o){Oa(e,n);var i=(n.flags&128)!==0;if(!r&&!i)return l&&mu(n,t,!1),Xe(e,n,o);r=n.stateNode,id.current=n;var u=i&&typeof t.getDerivedStateFromError!="function"?null:r.render();return n.flags|=1,e!==null&&i?(n.child=nt(n,e.child,null,o),n.child=nt(n,null,u,o)):oe(e,n,u,o),n.memoizedState=r.state,l&&mu(n,t,!0),n.child}function ja(e){var n=e.stateNode;n.pendingContext?pu(e,n.pendingContext,n.pendingContext!==n.context):n.context&&pu(e,n.context,!1),pi(e,n.containerInfo)}function Tu(e,n,t,r,l){return et(),ui(l),n.flags|=256,oe(e,n,t,r),n.child}var zo={dehydrated:null,treeContext:null,retryLane:0};function Lo(e){return{baseLanes:e,cachePool:null,transitions:null}}function Ma(e,n,t){var r=n.pendingProps,l=$.current,o=!1,i=(n.flags&128)!==0,u;if((u=i)||(u=e!==null&&e.memoizedState===null?!1:(l&2)!==0),u?(o=!0,n.flags&=-129):(e===null||e.memoizedState!==null)&&(l|=1),M($,l&1),e===null)return ko(n),e=n.memoizedState,e!==null&&(e=e.dehydrated,e!==null)?(n.mode&1?e.data==="$!"?n.lanes=8:n.lanes=1073741824:n.lanes=1,null):(i=r.children,e=r.fallback,o?(r=n.mode,o=n.child,i={mode:"hidden",children:i},!(r&1)&&o!==null?(o.childLanes=0,o.pendingProps=i):o=fl(i,r,0,null),e=_n(e,r,t,null),o.return=n,e.return=n,o.sibling=e,n.child=o,n.child.memoizedState=Lo(t),n.memoizedState=zo,e):ki(n,i));if(l=e.memoizedState,l!==null&&(u=l.dehydrated,u!==null))return ud(e,n,i,r,u,l,t);if(o){o=r.fallback,i=n.mode,l=e.child,u=l.sibling;var s={mode:"hidden",children:r.children};return!(i&1)&&n.child!==l?(r=n.child,r.childLanes=0,r.pendingProps=s,n.deletions=null):(r=fn(l,s),r.subtreeFlags=l.subtreeFlags&14680064),u!==null?o=fn(u,o):(o=_n(o,i,t,null),o.flags|=2),o.return=n,r.return=n,r.sibling=o,n.child=r,r=o,o=n.child,i=e.child.memoizedState,i=i===null?Lo(t):{baseLanes:i.baseLanes|t,cachePool:null,transitions:i.transitions},o.memoizedState=i,o.childLanes=e.childLanes&~t,n.memoizedState=zo,r}return o=e.child,e=o.sibling,r=fn(o,{mode:"visible",children:r.children}),!(n.mode&1)&&(r.lanes=t),r.return=n,r.sibling=null,e!==null&&(t=n.deletions,t===null?(n.deletions=[e],n.flags|=16):t.push(e)),n.child=r,n.memoizedState=null,r}function ki(e,n){return n=fl({mode:"visible",children:n},e.mode,0,null),n.return=e,e.child=n}function mr(e,n,t,r){return r!==null&&ui(r),nt(n,e.child,null,t),e=ki(n,n.pendingProps.children),e.flags|=2,n.memoizedState=null,e}function ud(e,n,t,r,l,o,i){if(t)return n.flags&256?(n.flags&=-257,r=Vl(Error(y(422))),mr(e,n,i,r)):n.memoizedState!==null?(n.child=e.child,n.flags|=128,null):(o=r.fallback,l=n.mode,r=fl({mode:"visible",children:r.children},l,0,null),o=_n(o,l,i,null),o.flags|=2,r.return=n,o.return=n,r.sibling=o,n.child=r,n.mode&1&&nt(n,e.child,null,i),n.child.memoizedState=Lo(i),n.memoizedState=zo,o);if(!(n.mode&1))return mr(e,n,i,null);if(l.data==="$!"){if(r=l.nextSibling&&l.nextSibling.dataset,r)var u=r.dgst;return r=u,o=Error(y(419)),r=Vl(o,r,void 0),mr(e,n,i,r)}if(u=(i&e.childLanes)!==0,ce||u){if(r=J,r!==null){switch(i&-i){case 4:l=2;break;case 16:l=8;break;case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:l=32;break;case 536870912:l=268435456;break;default:l=0}l=l&(r.suspendedLanes|i)?0:l,l!==0&&l!==o.retryLane&&(o.retryLane=l,Ye(e,l),je(r,e,l,-1))}return Pi(),r=Vl(Error(y(421))),mr(e,n,i,r)}return l.data==="$?"?(n.flags|=128,n.child=e.child,n=Sd.bind(null,e),l._reactRetry=n,null):(e=o.treeContext,he=un(l.nextSibling),ve=n,U=!0,Re=null,e!==null&&(Se[ke++]=Be,Se[ke++]=He,Se[ke++]=Pn,Be=e.id,He=e.overflow,Pn=n),n=ki(n,r.children),n.flags|=4096,n)}function Ru(e,n,t){e.lanes|=n;var r=e.alternate;r!==null&&(r.lanes|=n),Eo(e.return,n,t)}function Bl(e,n,t,r,l){var o=e.memoizedState;o===null?e.memoizedState={isBackwards:n,rendering:null,renderingStartTime:0,last:r,tail:t,tailMode:l}:(o.isBackwards=n,o.rendering=null,o.renderingStartTime=0,o.last=r,o.tail=t,o.tailMode=l)}function Da(e,n,t){var r=n.pendingProps,l=r.revealOrder,o=r.tail;if(oe(e,n,r.children,t),r=$.current,r&2)r=r&1|2,n.flags|=128;else{if(e!==null&&e.flags&128)e:for(e=n.child;e!==null;){if(e.tag===13)e.memoizedState!==null&&Ru(e,t,n);else if(e.tag===19)Ru(e,t,n);else if(e.child!==null){e.child.return=e,e=e.child;continue}if(e===n)break e;for(;e.sibling===null;){if(e.return===null||e.return===n)break e;e=e.return}e.sibling.return=e.return,e=e.sibling}r&=1}if(M($,r),!(n.mode&1))n.memoizedState=null;else switch(l){case"forwards":for(t=n.child,l=null;t!==null;)e=t.alternate,e!==null&&Kr(e)===null&&(l=t),t=t.sibling;t=l,t===null?(l=n.child,n.child=null):(l=t.sibling,t.sibling=null),Bl(n,!1,l,t,o);break;case"backwards":for(t=null,l=n.child,n.child=null;l!==null;){if(e=l.alternate,e!==null&&Kr(e)===null){n.child=l;break}e=l.sibling,l.sibling=t,t=l,l=e}Bl(n,!0,t,null,o);break;case"together":Bl(n,!1,null,null,void 0);break;default:n.memoizedState=null}return n.child}function Nr(e,n){!(n.mode&1)&&e!==null&&(e.alternate=null,n.alternate=null,n.flags|=2)}function Xe(e,n,t){if(e!==null&&(n.dependencies=e.dependencies),Ln|=n.lanes,!(t&n.childLanes))return null;if(e!==null&&n.child!==e.child)throw Error(y(153));if(n.child!==null){for(e=n.child,t=fn(e,e.pendingProps),n.child=t,t.return=n;e.sibling!==null;)e=e.sibling,t=t.sibling=fn(e,e.pendingProps),t.return=n;t.sibling=null}return n.child}function sd(e,n,t){switch(n.tag){case 3:ja(n),et();break;case 5:sa(n);break;case 1:de(n.type)&&Ar(n);break;case 4:pi(n,n.stateNode.containerInfo);break;case 10:var r=n.type._context,l=n.memoizedProps.value;M(Hr,r._currentValue),r._currentValue=l;break;case 13:if(r=n.memoizedState,r!==null)return r.dehydrated!==null?(M($,$.current&1),n.flags|=128,null):t&n.child.childLanes?Ma(e,n,t):(M($,$.current&1),e=Xe(e,n,t),e!==null?e.sibling:null);M($,$.current&1);break;case 19:if(r=(t&n.childLanes)!==0,e.flags&128){if(r)return Da(e,n,t);n.flags|=128}if(l=n.memoizedState,l!==null&&(l.rendering=null,l.tail=null,l.lastEffect=null),M($,$.current),r)break;return null;case 22:case 23:return n.lanes=0,Ra(e,n,t)}return Xe(e,n,t)}var Ia,To,Fa,Ua;Ia=function(e,n){for(var t=n.child;t!==null;){if(t.tag===5||t.tag===6)e.appendChild(t.stateNode);else if(t.tag!==4&&t.child!==null){t.child.return=t,t=t.child;continue}if(t===n)break;for(;t.sibling===null;){if(t.return===null||t.return===n)return;t=t.return}t.sibling.return=t.return,t=t.sibling}};To=function(){};Fa=function(e,n,t,r){var l=e.memoizedProps;if(l!==r){e=n.stateNode,xn($e.current);var o=null;switch(t){case"input":l=Jl(e,l),r=Jl(e,r),o=[];break;case"select":l=V({},l,{value:void 0}),r=V({},r,{value:void 0}),o=[];break;case"textarea":l=eo(e,l),r=eo(e,r),o=[];break;default:typeof l.onClick!="function"&&typeof r.onClick=="function"&&(e.onclick=Ur)}to(t,r);var i;t=null;for(c in l)if(!r.hasOwnProperty(c)&&l.hasOwnProperty(c)&&l[c]!=null)if(c==="style"){var u=l[c];for(i in u)u.hasOwnProperty(i)&&(t||(t={}),t[i]="")}else c!=="dangerouslySetInnerHTML"&&c!=="children"&&c!=="suppressContentEditableWarning"&&c!=="suppressHydrationWarning"&&c!=="autoFocus"&&(Rt.hasOwnProperty(c)?o||(o=[]):(o=o||[]).push(c,null));for(c in r){var s=r[c];if(u=l!=null?l[c]:void 0,r.hasOwnProperty(c)&&s!==u&&(s!=null||u!=null))if(c==="style")if(u){for(i in u)!u.hasOwnProperty(i)||s&&s.hasOwnProperty(i)||(t||(t={}),t[i]="");for(i in s)s.hasOwnProperty(i)&&u[i]!==s[i]&&(t||(t={}),t[i]=s[i])}else t||(o||(o=[]),o.push(c,t)),t=s;else c==="dangerouslySetInnerHTML"?(s=s?s.__html:void 0,u=u?u.__html:void 0,s!=null&&u!==s&&(o=o||[]).push(c,s)):c==="children"?typeof s!="string"&&typeof s!="number"||(o=o||[]).push(c,""+s):c!=="suppressContentEditableWarning"&&c!=="suppressHydrationWarning"&&(Rt.hasOwnProperty(c)?(s!=null&&c==="onScroll"&&D("scroll",e),o||u===s||(o=[])):(o=o||[]).push(c,s))}t&&(o=o||[]).push("style",t);var c=o;(n.updateQueue=c)&&(n.flags|=4)}};Ua=function(e,n,t,r){t!==r&&(n.flags|=4)};function vt(e,n){if(!U)switch(e.tailMode){case"hidden":n=e.tail;for(var t=null;n!==null;)n.alternate!==null&&(t=n),n=n.sibling;t===null?e.tail=null:t.sibling=null;break;case"collapsed":t=e.tail;for(var r=null;t!==null;)t.alternate!==null&&(r=t),t=t.sibling;r===null?n||e.tail===null?e.tail=null:e.tail.sibling=null:r.sibling=null}}function te(e){var n=e.alternate!==null&&e.alternate.child===e.child,t=0,r=0;if(n)for(var l=e.child;l!==null;)t|=l.lanes|l.childLanes,r|=l.subtreeFlags&14680064,r|=l.flags&14680064,l.return=e,l=l.sibling;else for(l=e.child;l!==null;)t|=l.lanes|l.childLanes,r|=l.subtreeFlags,r|=l.flags,l.return=e,l=l.sibling;return e.subtreeFlags|=r,e.childLanes=t,n}function ad(e,n,t){var r=n.pendingProps;switch(ii(n),n.tag){case 2:case 16:case 15:case 0:case 11:case 7:case 8:case 12:case 9:case 14:return te(n),null;case 1:return de(n.type)&&$r(),te(n),null;case 3:return r=n.stateNode,tt(),I(fe),I(le),hi(),r.pendingContext&&(r.context=r.pendingContext,r.pendingContext=null),(e===null||e.child===null)&&(dr(n)?n.flags|=4:e===null||e.memoizedState.isDehydrated&&!(n.flags&256)||(n.flags|=1024,Re!==null&&(Uo(Re),Re=null))),To(e,n),te(n),null;case 5:mi(n);var l=xn(Ht.current);if(t=n.type,e!==null&&n.stateNode!=null)Fa(e,n,t,r,l),e.ref!==n.ref&&(n.flags|=512,n.flags|=2097152);else{if(!r){if(n.stateNode===null)throw Error(y(166));return te(n),null}if(e=xn($e.current),dr(n)){r=n.stateNode,t=n.type;var o=n.memoizedProps;switch(r[Fe]=n,r[Vt]=o,e=(n.mode&1)!==0,t){case"dialog":D("cancel",r),D("close",r);break;case"iframe":case"object":case"embed":D("load",r);break;case"video":case"audio":for(l=0;l<kt.length;l++)D(kt[l],r);break;case"source":D("error",r);break;case"img":case"image":case"link":D("error",r),D("load",r);break;case"details":D("toggle",r);break;case"input":Ai(r,o),D("invalid",r);break;case"select":r._wrapperState={wasMultiple:!!o.multiple},D("invalid",r);break;case"textarea":Bi(r,o),D("invalid",r)}to(t,o),l=null;for(var i in o)if(o.hasOwnProperty(i)){var u=o[i];i==="children"?typeof u=="string"?r.textContent!==u&&(o.suppressHydrationWarning!==!0&&fr(r.textContent,u,e),l=["children",u]):typeof u=="number"&&r.textContent!==""+u&&(o.suppressHydrationWarning!==!0&&fr(r.textContent,u,e),l=["children",""+u]):Rt.hasOwnProperty(i)&&u!=null&&i==="onScroll"&&D("scroll",r)}switch(t){case"input":rr(r),Vi(r,o,!0);break;case"textarea":rr(r),Hi(r);break;case"select":case"option":break;default:typeof o.onClick=="function"&&(r.onclick=Ur)}r=l,n.updateQueue=r,r!==null&&(n.flags|=4)}else{i=l.nodeType===9?l:l.ownerDocument,e==="http://www.w3.org/1999/xhtml"&&(e=fs(t)),e==="http://www.w3.org/1999/xhtml"?t==="script"?(e=i.createElement("div"),e.innerHTML="<script><\/script>",e=e.removeChild(e.firstChild)):typeof r.is=="string"?e=i.createElement(t,{is:r.is}):(e=i.createElement(t),t==="select"&&(i=e,r.multiple?i.multiple=!0:r.size&&(i.size=r.size))):e=i.createElementNS(e,t),e[Fe]=n,e[Vt]=r,Ia(e,n,!1,!1),n.stateNode=e;e:{switch(i=ro(t,r),t){case"dialog":D("cancel",e),D("close",e),l=r;break;case"iframe":case"object":case"embed":D("load",e),l=r;break;case"video":case"audio":for(l=0;l<kt.length;l++)D(kt[l],e);l=r;break;case"source":D("error",e),l=r;break;case"img":case"image":case"link":D("error",e),D("load",e),l=r;break;case"details":D("toggle",e),l=r;break;case"input":Ai(e,r),l=Jl(e,r),D("invalid",e);break;case"option":l=r;break;case"select":e._wrapperState={wasMultiple:!!r.multiple},l=V({},r,{value:void 0}),D("invalid",e);break;case"textarea":Bi(e,r),l=eo(e,r),D("invalid",e);break;default:l=r}to(t,l),u=l;for(o in u)if(u.hasOwnProperty(o)){var s=u[o];o==="style"?ms(e,s):o==="dangerouslySetInnerHTML"?(s=s?s.__html:void 0,s!=null&&ds(e,s)):o==="children"?typeof s=="string"?(t!=="textarea"||s!=="")&&Ot(e,s):typeof s=="number"&&Ot(e,""+s):o!=="suppressContentEditableWarning"&&o!=="suppressHydrationWarning"&&o!=="autoFocus"&&(Rt.hasOwnProperty(o)?s!=null&&o==="onScroll"&&D("scroll",e):s!=null&&Qo(e,o,s,i))}switch(t){case"input":rr(e),Vi(e,r,!1);break;case"textarea":rr(e),Hi(e);break;case"option":r.value!=null&&e.setAttribute("value",""+dn(r.value));break;case"select":e.multiple=!!r.multiple,o=r.value,o!=null?Kn(e,!!r.multiple,o,!1):r.defaultValue!=null&&Kn(e,!!r.multiple,r.defaultValue,!0);break;default:typeof l.onClick=="function"&&(e.onclick=Ur)}switch(t){case"button":case"input":case"select":case"textarea":r=!!r.autoFocus;break e;case"img":r=!0;break e;default:r=!1}}r&&(n.flags|=4)}n.ref!==null&&(n.flags|=512,n.flags|=2097152)}return te(n),null;case 6:if(e&&n.stateNode!=null)Ua(e,n,e.memoizedProps,r);else{if(typeof r!="string"&&n.stateNode===null)throw Error(y(166));if(t=xn(Ht.current),xn($e.current),dr(n)){if(r=n.stateNode,t=n.memoizedProps,r[Fe]=n,(o=r.nodeValue!==t)&&(e=ve,e!==null))switch(e.tag){case 3:fr(r.nodeValue,t,(e.mode&1)!==0);break;case 5:e.memoizedProps.suppressHydrationWarning!==!0&&fr(r.nodeValue,t,(e.mode&1)!==0)}o&&(n.flags|=4)}else r=(t.nodeType===9?t:t.ownerDocument).createTextNode(r),r[Fe]=n,n.stateNode=r}return te(n),null;case 13:if(I($),r=n.memoizedState,e===null||e.memoizedState!==null&&e.memoizedState.dehydrated!==null){if(U&&he!==null&&n.mode&1&&!(n.flags&128))na(),et(),n.flags|=98560,o=!1;else if(o=dr(n),r!==null&&r.dehydrated!==null){if(e===null){if(!o)throw Error(y(318));if(o=n.memoizedState,o=o!==null?o.dehydrated:null,!o)throw Error(y(317));o[Fe]=n}else et(),!(n.flags&128)&&(n.memoizedState=null),n.flags|=4;te(n),o=!1}else Re!==null&&(Uo(Re),Re=null),o=!0;if(!o)return n.flags&65536?n:null}return n.flags&128?(n.lanes=t,n):(r=r!==null,r!==(e!==null&&e.memoizedState!==null)&&r&&(n.child.flags|=8192,n.mode&1&&(e===null||$.current&1?X===0&&(X=3):Pi())),n.updateQueue!==null&&(n.flags|=4),te(n),null);case 4:return tt(),To(e,n),e===null&&$t(n.stateNode.containerInfo),te(n),null;case 10:return ci(n.type._context),te(n),null;case 17:return de(n.type)&&$r(),te(n),null;case 19:if(I($),o=n.memoizedState,o===null)return te(n),null;if(r=(n.flags&128)!==0,i=o.rendering,i===null)if(r)vt(o,!1);else{if(X!==0||e!==null&&e.flags&128)for(e=n.child;e!==null;){if(i=Kr(e),i!==null){for(n.flags|=128,vt(o,!1),r=i.updateQueue,r!==null&&(n.updateQueue=r,n.flags|=4),n.subtreeFlags=0,r=t,t=n.child;t!==null;)o=t,e=r,o.flags&=14680066,i=o.alternate,i===null?(o.childLanes=0,o.lanes=e,o.child=null,o.subtreeFlags=0,o.memoizedProps=null,o.memoizedState=null,o.updateQueue=null,o.dependencies=null,o.stateNode=null):(o.childLanes=i.childLanes,o.lanes=i.lanes,o.child=i.child,o.subtreeFlags=0,o.deletions=null,o.memoizedProps=i.memoizedProps,o.memoizedState=i.memoizedState,o.updateQueue=i.updateQueue,o.type=i.type,e=i.dependencies,o.dependencies=e===null?null:{lanes:e.lanes,firstContext:e.firstContext}),t=t.sibling;return M($,$.current&1|2),n.child}e=e.sibling}o.tail!==null&&Q()>lt&&(n.flags|=128,r=!0,vt(o,!1),n.lanes=4194304)}else{if(!r)if(e=Kr(i),e!==null){if(n.flags|=128,r=!0,t=e.updateQueue,t!==null&&(n.updateQueue=t,n.flags|=4),vt(o,!0),o.tail===null&&o.tailMode==="hidden"&&!i.alternate&&!U)return te(n),null}else 2*Q()-o.renderingStartTime>lt&&t!==1073741824&&(n.flags|=128,r=!0,vt(o,!1),n.lanes=4194304);o.isBackwards?(i.sibling=n.child,n.child=i):(t=o.last,t!==null?t.sibling=i:n.child=i,o.last=i)}return o.tail!==null?(n=o.tail,o.rendering=n,o.tail=n.sibling,o.renderingStartTime=Q(),n.sibling=null,t=$.current,M($,r?t&1|2:t&1),n):(te(n),null);case 22:case 23:return Ni(),r=n.memoizedState!==null,e!==null&&e.memoizedState!==null!==r&&(n.flags|=8192),r&&n.mode&1?me&1073741824&&(te(n),n.subtreeFlags&6&&(n.flags|=8192)):te(n),null;case 24:return null;case 25:return null}throw Error(y(156,n.tag))}function cd(e,n){switch(ii(n),n.tag){case 1:return de(n.type)&&$r(),e=n.flags,e&65536?(n.flags=e&-65537|128,n):null;case 3:return tt(),I(fe),I(le),hi(),e=n.flags,e&65536&&!(e&128)?(n.flags=e&-65537|128,n):null;case 5:return mi(n),null;case 13:if(I($),e=n.memoizedState,e!==null&&e.dehydrated!==null){if(n.alternate===null)throw Error(y(340));et()}return e=n.flags,e&65536?(n.flags=e&-65537|128,n):null;case 19:return I($),null;case 4:return tt(),null;case 10:return ci(n.type._context),null;case 22:case 23:return Ni(),null;case 24:return null;default:return null}}var hr=!1,re=!1,fd=typeof WeakSet=="function"?WeakSet:Set,k=null;function Wn(e,n){var t=e.ref;if(t!==null)if(typeof t=="function")try{t(null)}catch(r){B(e,n,r)}else t.current=null}function Ro(e,n,t){try{t()}catch(r){B(e,n,r)}}var Ou=!1;function dd(e,n){if(mo=Dr,e=Bs(),li(e)){if("selectionStart"in e)var t={start:e.selectionStart,end:e.selectionEnd};else e:{t=(t=e.ownerDocument)&&t.defaultView||window;var r=t.getSelection&&t.getSelection();if(r&&r.rangeCount!==0){t=r.anchorNode;var l=r.anchorOffset,o=r.focusNode;r=r.focusOffset;try{t.nodeType,o.nodeType}catch{t=null;break e}var i=0,u=-1,s=-1,c=0,h=0,m=e,p=null;n:for(;;){for(var g;m!==t||l!==0&&m.nodeType!==3||(u=i+l),m!==o||r!==0&&m.nodeType!==3||(s=i+r),m.nodeType===3&&(i+=m.nodeValue.length),(g=m.firstChild)!==null;)p=m,m=g;for(;;){if(m===e)break n;if(p===t&&++c===l&&(u=i),p===o&&++h===r&&(s=i),(g=m.nextSibling)!==null)break;m=p,p=m.parentNode}m=g}t=u===-1||s===-1?null:{start:u,end:s}}else t=null}t=t||{start:0,end:0}}else t=null;for(ho={focusedElem:e,selectionRange:t},Dr=!1,k=n;k!==null;)if(n=k,e=n.child,(n.subtreeFlags&1028)!==0&&e!==null)e.return=n,k=e;else for(;k!==null;){n=k;try{var w=n.alternate;if(n.flags&1024)switch(n.tag){case 0:case 11:case 15:break;case 1:if(w!==null){var S=w.memoizedProps,F=w.memoizedState,f=n.stateNode,a=f.getSnapshotBeforeUpdate(n.elementType===n.type?S:ze(n.type,S),F);f.__reactInternalSnapshotBeforeUpdate=a}break;case 3:var d=n.stateNode.containerInfo;d.nodeType===1?d.textContent="":d.nodeType===9&&d.documentElement&&d.removeChild(d.documentElement);break;case 5:case 6:case 4:case 17:break;default:throw Error(y(163))}}catch(v){B(n,n.return,v)}if(e=n.sibling,e!==null){e.return=n.return,k=e;break}k=n.return}return w=Ou,Ou=!1,w}function zt(e,n,t){var r=n.updateQueue;if(r=r!==null?r.lastEffect:null,r!==null){var l=r=r.next;do{if((l.tag&e)===e){var o=l.destroy;l.destroy=void 0,o!==void 0&&Ro(n,t,o)}l=l.next}while(l!==r)}}function al(e,n){if(n=n.updateQueue,n=n!==null?n.lastEffect:null,n!==null){var t=n=n.next;do{if((t.tag&e)===e){var r=t.create;t.destroy=r()}t=t.next}while(t!==n)}}function Oo(e){var n=e.ref;if(n!==null){var t=e.stateNode;switch(e.tag){case 5:e=t;break;default:e=t}typeof n=="function"?n(e):n.current=e}}function $a(e){var n=e.alternate;n!==null&&(e.alternate=null,$a(n)),e.child=null,e.deletions=null,e.sibling=null,e.tag===5&&(n=e.stateNode,n!==null&&(delete n[Fe],delete n[Vt],delete n[go],delete n[Xf],delete n[Gf])),e.stateNode=null,e.return=null,e.dependencies=null,e.memoizedProps=null,e.memoizedState=null,e.pendingProps=null,e.stateNode=null,e.updateQueue=null}function Aa(e){return e.tag===5||e.tag===3||e.tag===4}function ju(e){e:for(;;){for(;e.sibling===null;){if(e.return===null||Aa(e.return))return null;e=e.return}for(e.sibling.return=e.return,e=e.sibling;e.tag!==5&&e.tag!==6&&e.tag!==18;){if(e.flags&2||e.child===null||e.tag===4)continue e;e.child.return=e,e=e.child}if(!(e.flags&2))return e.stateNode}}function jo(e,n,t){var r=e.tag;if(r===5||r===6)e=e.stateNode,n?t.nodeType===8?t.parentNode.insertBefore(e,n):t.insertBefore(e,n):(t.nodeType===8?(n=t.parentNode,n.insertBefore(e,t)):(n=t,n.appendChild(e)),t=t._reactRootContainer,t!=null||n.onclick!==null||(n.onclick=Ur));else if(r!==4&&(e=e.child,e!==null))for(jo(e,n,t),e=e.sibling;e!==null;)jo(e,n,t),e=e.sibling}function Mo(e,n,t){var r=e.tag;if(r===5||r===6)e=e.stateNode,n?t.insertBefore(e,n):t.appendChild(e);else if(r!==4&&(e=e.child,e!==null))for(Mo(e,n,t),e=e.sibling;e!==null;)Mo(e,n,t),e=e.sibling}var q=null,Te=!1;function Ze(e,n,t){for(t=t.child;t!==null;)Va(e,n,t),t=t.sibling}function Va(e,n,t){if(Ue&&typeof Ue.onCommitFiberUnmount=="function")try{Ue.onCommitFiberUnmount(nl,t)}catch{}switch(t.tag){case 5:re||Wn(t,n);case 6:var r=q,l=Te;q=null,Ze(e,n,t),q=r,Te=l,q!==null&&(Te?(e=q,t=t.stateNode,e.nodeType===8?e.parentNode.removeChild(t):e.removeChild(t)):q.removeChild(t.stateNode));break;case 18:q!==null&&(Te?(e=q,t=t.stateNode,e.nodeType===8?Dl(e.parentNode,t):e.nodeType===1&&Dl(e,t),It(e)):Dl(q,t.stateNode));break;case 4:r=q,l=Te,q=t.stateNode.containerInfo,Te=!0,Ze(e,n,t),q=r,Te=l;break;case 0:case 11:case 14:case 15:if(!re&&(r=t.updateQueue,r!==null&&(r=r.lastEffect,r!==null))){l=r=r.next;do{var o=l,i=o.destroy;o=o.tag,i!==void 0&&(o&2||o&4)&&Ro(t,n,i),l=l.next}while(l!==r)}Ze(e,n,t);break;case 1:if(!re&&(Wn(t,n),r=t.stateNode,typeof r.componentWillUnmount=="function"))try{r.props=t.memoizedProps,r.state=t.memoizedState,r.componentWillUnmount()}catch(u){B(t,n,u)}Ze(e,n,t);break;case 21:Ze(e,n,t);break;case 22:t.mode&1?(re=(r=re)||t.memoizedState!==null,Ze(e,n,t),re=r):Ze(e,n,t);break;default:Ze(e,n,t)}}function Mu(e){var n=e.updateQueue;if(n!==null){e.updateQueue=null;var t=e.stateNode;t===null&&(t=e.stateNode=new fd),n.forEach(function(r){var l=kd.bind(null,e,r);t.has(r)||(t.add(r),r.then(l,l))})}}function Pe(e,n){var t=n.deletions;if(t!==null)for(var r=0;r<t.length;r++){var l=t[r];try{var o=e,i=n,u=i;e:for(;u!==null;){switch(u.tag){case 5:q=u.stateNode,Te=!1;break e;case 3:q=u.stateNode.containerInfo,Te=!0;break e;case 4:q=u.stateNode.containerInfo,Te=!0;break e}u=u.return}if(q===null)throw Error(y(160));Va(o,i,l),q=null,Te=!1;var s=l.alternate;s!==null&&(s.return=null),l.return=null}catch(c){B(l,n,c)}}if(n.subtreeFlags&12854)for(n=n.child;n!==null;)Ba(n,e),n=n.sibling}function Ba(e,n){var t=e.alternate,r=e.flags;switch(e.tag){case 0:case 11:case 14:case 15:if(Pe(n,e),De(e),r&4){try{zt(3,e,e.return),al(3,e)}catch(S){B(e,e.return,S)}try{zt(5,e,e.return)}catch(S){B(e,e.return,S)}}break;case 1:Pe(n,e),De(e),r&512&&t!==null&&Wn(t,t.return);break;case 5:if(Pe(n,e),De(e),r&512&&t!==null&&Wn(t,t.return),e.flags&32){var l=e.stateNode;try{Ot(l,"")}catch(S){B(e,e.return,S)}}if(r&4&&(l=e.stateNode,l!=null)){var o=e.memoizedProps,i=t!==null?t.memoizedProps:o,u=e.type,s=e.updateQueue;if(e.updateQueue=null,s!==null)try{u==="input"&&o.type==="radio"&&o.name!=null&&as(l,o),ro(u,i);var c=ro(u,o);for(i=0;i<s.length;i+=2){var h=s[i],m=s[i+1];h==="style"?ms(l,m):h==="dangerouslySetInnerHTML"?ds(l,m):h==="children"?Ot(l,m):Qo(l,h,m,c)}switch(u){case"input":ql(l,o);break;case"textarea":cs(l,o);break;case"select":var p=l._wrapperState.wasMultiple;l._wrapperState.wasMultiple=!!o.multiple;var g=o.value;g!=null?Kn(l,!!o.multiple,g,!1):p!==!!o.multiple&&(o.defaultValue!=null?Kn(l,!!o.multiple,o.defaultValue,!0):Kn(l,!!o.multiple,o.multiple?[]:"",!1))}l[Vt]=o}catch(S){B(e,e.return,S)}}break;case 6:if(Pe(n,e),De(e),r&4){if(e.stateNode===null)throw Error(y(162));l=e.stateNode,o=e.memoizedProps;try{l.nodeValue=o}catch(S){B(e,e.return,S)}}break;case 3:if(Pe(n,e),De(e),r&4&&t!==null&&t.memoizedState.isDehydrated)try{It(n.containerInfo)}catch(S){B(e,e.return,S)}break;case 4:Pe(n,e),De(e);break;case 13:Pe(n,e),De(e),l=e.child,l.flags&8192&&(o=l.memoizedState!==null,l.stateNode.isHidden=o,!o||l.alternate!==null&&l.alternate.memoizedState!==null||(Ci=Q())),r&4&&Mu(e);break;case 22:if(h=t!==null&&t.memoizedState!==null,e.mode&1?(re=(c=re)||h,Pe(n,e),re=c):Pe(n,e),De(e),r&8192){if(c=e.memoizedState!==null,(e.stateNode.isHidden=c)&&!h&&e.mode&1)for(k=e,h=e.child;h!==null;){for(m=k=h;k!==null;){switch(p=k,g=p.child,p.tag){case 0:case 11:case 14:case 15:zt(4,p,p.return);break;case 1:Wn(p,p.return);var w=p.stateNode;if(typeof w.componentWillUnmount=="function"){r=p,t=p.return;try{n=r,w.props=n.memoizedProps,w.state=n.memoizedState,w.componentWillUnmount()}catch(S){B(r,t,S)}}break;case 5:Wn(p,p.return);break;case 22:if(p.memoizedState!==null){Iu(m);continue}}g!==null?(g.return=p,k=g):Iu(m)}h=h.sibling}e:for(h=null,m=e;;){if(m.tag===5){if(h===null){h=m;try{l=m.stateNode,c?(o=l.style,typeof o.setProperty=="function"?o.setProperty("display","none","important"):o.display="none"):(u=m.stateNode,s=m.memoizedProps.style,i=s!=null&&s.hasOwnProperty("display")?s.display:null,u.style.display=ps("display",i))}catch(S){B(e,e.return,S)}}}else if(m.tag===6){if(h===null)try{m.stateNode.nodeValue=c?"":m.memoizedProps}catch(S){B(e,e.return,S)}}else if((m.tag!==22&&m.tag!==23||m.memoizedState===null||m===e)&&m.child!==null){m.child.return=m,m=m.child;continue}if(m===e)break e;for(;m.sibling===null;){if(m.return===null||m.return===e)break e;h===m&&(h=null),m=m.return}h===m&&(h=null),m.sibling.return=m.return,m=m.sibling}}break;case 19:Pe(n,e),De(e),r&4&&Mu(e);break;case 21:break;default:Pe(n,e),De(e)}}function De(e){var n=e.flags;if(n&2){try{e:{for(var t=e.return;t!==null;){if(Aa(t)){var r=t;break e}t=t.return}throw Error(y(160))}switch(r.tag){case 5:var l=r.stateNode;r.flags&32&&(Ot(l,""),r.flags&=-33);var o=ju(e);Mo(e,o,l);break;case 3:case 4:var i=r.stateNode.containerInfo,u=ju(e);jo(e,u,i);break;default:throw Error(y(161))}}catch(s){B(e,e.return,s)}e.flags&=-3}n&4096&&(e.flags&=-4097)}function pd(e,n,t){k=e,Ha(e)}function Ha(e,n,t){for(var r=(e.mode&1)!==0;k!==null;){var l=k,o=l.child;if(l.tag===22&&r){var i=l.memoizedState!==null||hr;if(!i){var u=l.alternate,s=u!==null&&u.memoizedState!==null||re;u=hr;var c=re;if(hr=i,(re=s)&&!c)for(k=l;k!==null;)i=k,s=i.child,i.tag===22&&i.memoizedState!==null?Fu(l):s!==null?(s.return=i,k=s):Fu(l);for(;o!==null;)k=o,Ha(o),o=o.sibling;k=l,hr=u,re=c}Du(e)}else l.subtreeFlags&8772&&o!==null?(o.return=l,k=o):Du(e)}}function Du(e){for(;k!==null;){var n=k;if(n.flags&8772){var t=n.alternate;try{if(n.flags&8772)switch(n.tag){case 0:case 11:case 15:re||al(5,n);break;case 1:var r=n.stateNode;if(n.flags&4&&!re)if(t===null)r.componentDidMount();else{var l=n.elementType===n.type?t.memoizedProps:ze(n.type,t.memoizedProps);r.componentDidUpdate(l,t.memoizedState,r.__reactInternalSnapshotBeforeUpdate)}var o=n.updateQueue;o!==null&&gu(n,o,r);break;case 3:var i=n.updateQueue;if(i!==null){if(t=null,n.child!==null)switch(n.child.tag){case 5:t=n.child.stateNode;break;case 1:t=n.child.stateNode}gu(n,i,t)}break;case 5:var u=n.stateNode;if(t===null&&n.flags&4){t=u;var s=n.memoizedProps;switch(n.type){case"button":case"input":case"select":case"textarea":s.autoFocus&&t.focus();break;case"img":s.src&&(t.src=s.src)}}break;case 6:break;case 4:break;case 12:break;case 13:if(n.memoizedState===null){var c=n.alternate;if(c!==null){var h=c.memoizedState;if(h!==null){var m=h.dehydrated;m!==null&&It(m)}}}break;case 19:case 17:case 21:case 22:case 23:case 25:break;default:throw Error(y(163))}re||n.flags&512&&Oo(n)}catch(p){B(n,n.return,p)}}if(n===e){k=null;break}if(t=n.sibling,t!==null){t.return=n.return,k=t;break}k=n.return}}function Iu(e){for(;k!==null;){var n=k;if(n===e){k=null;break}var t=n.sibling;if(t!==null){t.return=n.return,k=t;break}k=n.return}}function Fu(e){for(;k!==null;){var n=k;try{switch(n.tag){case 0:case 11:case 15:var t=n.return;try{al(4,n)}catch(s){B(n,t,s)}break;case 1:var r=n.stateNode;if(typeof r.componentDidMount=="function"){var l=n.return;try{r.componentDidMount()}catch(s){B(n,l,s)}}var o=n.return;try{Oo(n)}catch(s){B(n,o,s)}break;case 5:var i=n.return;try{Oo(n)}catch(s){B(n,i,s)}}}catch(s){B(n,n.return,s)}if(n===e){k=null;break}var u=n.sibling;if(u!==null){u.return=n.return,k=u;break}k=n.return}}var md=Math.ceil,Gr=Ge.ReactCurrentDispatcher,Ei=Ge.ReactCurrentOwner,xe=Ge.ReactCurrentBatchConfig,R=0,J=null,K=null,b=0,me=0,Qn=hn(0),X=0,Yt=null,Ln=0,cl=0,xi=0,Lt=null,ae=null,Ci=0,lt=1/0,Ae=null,Zr=!1,Do=null,an=null,vr=!1,tn=null,Jr=0,Tt=0,Io=null,Pr=-1,zr=0;function ie(){return R&6?Q():Pr!==-1?Pr:Pr=Q()}function cn(e){return e.mode&1?R&2&&b!==0?b&-b:Jf.transition!==null?(zr===0&&(zr=Ns()),zr):(e=j,e!==0||(e=window.event,e=e===void 0?16:js(e.type)),e):1}function je(e,n,t,r){if(50<Tt)throw Tt=0,Io=null,Error(y(185));Gt(e,t,r),(!(R&2)||e!==J)&&(e===J&&(!(R&2)&&(cl|=t),X===4&&en(e,b)),pe(e,r),t===1&&R===0&&!(n.mode&1)&&(lt=Q()+500,il&&vn()))}function pe(e,n){var t=e.callbackNode;Zc(e,n);var r=Mr(e,e===J?b:0);if(r===0)t!==null&&Ki(t),e.callbackNode=null,e.callbackPriority=0;else if(n=r&-r,e.callbackPriority!==n){if(t!=null&&Ki(t),n===1)e.tag===0?Zf(Uu.bind(null,e)):qs(Uu.bind(null,e)),Kf(function(){!(R&6)&&vn()}),t=null;else{switch(Ps(r)){case 1:t=Zo;break;case 4:t=Cs;break;case 16:t=jr;break;case 536870912:t=_s;break;default:t=jr}t=Ja(t,Wa.bind(null,e))}e.callbackPriority=n,e.callbackNode=t}}function Wa(e,n){if(Pr=-1,zr=0,R&6)throw Error(y(327));var t=e.callbackNode;if(Jn()&&e.callbackNode!==t)return null;var r=Mr(e,e===J?b:0);if(r===0)return null;if(r&30||r&e.expiredLanes||n)n=qr(e,r);else{n=r;var l=R;R|=2;var o=Ka();(J!==e||b!==n)&&(Ae=null,lt=Q()+500,Cn(e,n));do try{yd();break}catch(u){Qa(e,u)}while(1);ai(),Gr.current=o,R=l,K!==null?n=0:(J=null,b=0,n=X)}if(n!==0){if(n===2&&(l=so(e),l!==0&&(r=l,n=Fo(e,l))),n===1)throw t=Yt,Cn(e,0),en(e,r),pe(e,Q()),t;if(n===6)en(e,r);else{if(l=e.current.alternate,!(r&30)&&!hd(l)&&(n=qr(e,r),n===2&&(o=so(e),o!==0&&(r=o,n=Fo(e,o))),n===1))throw t=Yt,Cn(e,0),en(e,r),pe(e,Q()),t;switch(e.finishedWork=l,e.finishedLanes=r,n){case 0:case 1:throw Error(y(345));case 2:Sn(e,ae,Ae);break;case 3:if(en(e,r),(r&130023424)===r&&(n=Ci+500-Q(),10<n)){if(Mr(e,0)!==0)break;if(l=e.suspendedLanes,(l&r)!==r){ie(),e.pingedLanes|=e.suspendedLanes&l;break}e.timeoutHandle=yo(Sn.bind(null,e,ae,Ae),n);break}Sn(e,ae,Ae);break;case 4:if(en(e,r),(r&4194240)===r)break;for(n=e.eventTimes,l=-1;0<r;){var i=31-Oe(r);o=1<<i,i=n[i],i>l&&(l=i),r&=~o}if(r=l,r=Q()-r,r=(120>r?120:480>r?480:1080>r?1080:1920>r?1920:3e3>r?3e3:4320>r?4320:1960*md(r/1960))-r,10<r){e.timeoutHandle=yo(Sn.bind(null,e,ae,Ae),r);break}Sn(e,ae,Ae);break;case 5:Sn(e,ae,Ae);break;default:throw Error(y(329))}}}return pe(e,Q()),e.callbackNode===t?Wa.bind(null,e):null}function Fo(e,n){var t=Lt;return e.current.memoizedState.isDehydrated&&(Cn(e,n).flags|=256),e=qr(e,n),e!==2&&(n=ae,ae=t,n!==null&&Uo(n)),e}function Uo(e){ae===null?ae=e:ae.push.apply(ae,e)}function hd(e){for(var n=e;;){if(n.flags&16384){var t=n.updateQueue;if(t!==null&&(t=t.stores,t!==null))for(var r=0;r<t.length;r++){var l=t[r],o=l.getSnapshot;l=l.value;try{if(!Me(o(),l))return!1}catch{return!1}}}if(t=n.child,n.subtreeFlags&16384&&t!==null)t.return=n,n=t;else{if(n===e)break;for(;n.sibling===null;){if(n.return===null||n.return===e)return!0;n=n.return}n.sibling.return=n.return,n=n.sibling}}return!0}function en(e,n){for(n&=~xi,n&=~cl,e.suspendedLanes|=n,e.pingedLanes&=~n,e=e.expirationTimes;0<n;){var t=31-Oe(n),r=1<<t;e[t]=-1,n&=~r}}function Uu(e){if(R&6)throw Error(y(327));Jn();var n=Mr(e,0);if(!(n&1))return pe(e,Q()),null;var t=qr(e,n);if(e.tag!==0&&t===2){var r=so(e);r!==0&&(n=r,t=Fo(e,r))}if(t===1)throw t=Yt,Cn(e,0),en(e,n),pe(e,Q()),t;if(t===6)throw Error(y(345));return e.finishedWork=e.current.alternate,e.finishedLanes=n,Sn(e,ae,Ae),pe(e,Q()),null}function _i(e,n){var t=R;R|=1;try{return e(n)}finally{R=t,R===0&&(lt=Q()+500,il&&vn())}}function Tn(e){tn!==null&&tn.tag===0&&!(R&6)&&Jn();var n=R;R|=1;var t=xe.transition,r=j;try{if(xe.transition=null,j=1,e)return e()}finally{j=r,xe.transition=t,R=n,!(R&6)&&vn()}}function Ni(){me=Qn.current,I(Qn)}function Cn(e,n){e.finishedWork=null,e.finishedLanes=0;var t=e.timeoutHandle;if(t!==-1&&(e.timeoutHandle=-1,Qf(t)),K!==null)for(t=K.return;t!==null;){var r=t;switch(ii(r),r.tag){case 1:r=r.type.childContextTypes,r!=null&&$r();break;case 3:tt(),I(fe),I(le),hi();break;case 5:mi(r);break;case 4:tt();break;case 13:I($);break;case 19:I($);break;case 10:ci(r.type._context);break;case 22:case 23:Ni()}t=t.return}if(J=e,K=e=fn(e.current,null),b=me=n,X=0,Yt=null,xi=cl=Ln=0,ae=Lt=null,En!==null){for(n=0;n<En.length;n++)if(t=En[n],r=t.interleaved,r!==null){t.interleaved=null;var l=r.next,o=t.pending;if(o!==null){var i=o.next;o.next=l,r.next=i}t.pending=r}En=null}return e}function Qa(e,n){do{var t=K;try{if(ai(),Cr.current=Xr,Yr){for(var r=A.memoizedState;r!==null;){var l=r.queue;l!==null&&(l.pending=null),r=r.next}Yr=!1}if(zn=0,Z=Y=A=null,Pt=!1,Wt=0,Ei.current=null,t===null||t.return===null){X=1,Yt=n,K=null;break}e:{var o=e,i=t.return,u=t,s=n;if(n=b,u.flags|=32768,s!==null&&typeof s=="object"&&typeof s.then=="function"){var c=s,h=u,m=h.tag;if(!(h.mode&1)&&(m===0||m===11||m===15)){var p=h.alternate;p?(h.updateQueue=p.updateQueue,h.memoizedState=p.memoizedState,h.lanes=p.lanes):(h.updateQueue=null,h.memoizedState=null)}var g=_u(i);if(g!==null){g.flags&=-257,Nu(g,i,u,o,n),g.mode&1&&Cu(o,c,n),n=g,s=c;var w=n.updateQueue;if(w===null){var S=new Set;S.add(s),n.updateQueue=S}else w.add(s);break e}else{if(!(n&1)){Cu(o,c,n),Pi();break e}s=Error(y(426))}}else if(U&&u.mode&1){var F=_u(i);if(F!==null){!(F.flags&65536)&&(F.flags|=256),Nu(F,i,u,o,n),ui(rt(s,u));break e}}o=s=rt(s,u),X!==4&&(X=2),Lt===null?Lt=[o]:Lt.push(o),o=i;do{switch(o.tag){case 3:o.flags|=65536,n&=-n,o.lanes|=n;var f=za(o,s,n);yu(o,f);break e;case 1:u=s;var a=o.type,d=o.stateNode;if(!(o.flags&128)&&(typeof a.getDerivedStateFromError=="function"||d!==null&&typeof d.componentDidCatch=="function"&&(an===null||!an.has(d)))){o.flags|=65536,n&=-n,o.lanes|=n;var v=La(o,u,n);yu(o,v);break e}}o=o.return}while(o!==null)}Xa(t)}catch(E){n=E,K===t&&t!==null&&(K=t=t.return);continue}break}while(1)}function Ka(){var e=Gr.current;return Gr.current=Xr,e===null?Xr:e}function Pi(){(X===0||X===3||X===2)&&(X=4),J===null||!(Ln&268435455)&&!(cl&268435455)||en(J,b)}function qr(e,n){var t=R;R|=2;var r=Ka();(J!==e||b!==n)&&(Ae=null,Cn(e,n));do try{vd();break}catch(l){Qa(e,l)}while(1);if(ai(),R=t,Gr.current=r,K!==null)throw Error(y(261));return J=null,b=0,X}function vd(){for(;K!==null;)Ya(K)}function yd(){for(;K!==null&&!Vc();)Ya(K)}function Ya(e){var n=Za(e.alternate,e,me);e.memoizedProps=e.pendingProps,n===null?Xa(e):K=n,Ei.current=null}function Xa(e){var n=e;do{var t=n.alternate;if(e=n.return,n.flags&32768){if(t=cd(t,n),t!==null){t.flags&=32767,K=t;return}if(e!==null)e.flags|=32768,e.subtreeFlags=0,e.deletions=null;else{X=6,K=null;return}}else if(t=ad(t,n,me),t!==null){K=t;return}if(n=n.sibling,n!==null){K=n;return}K=n=e}while(n!==null);X===0&&(X=5)}function Sn(e,n,t){var r=j,l=xe.transition;try{xe.transition=null,j=1,gd(e,n,t,r)}finally{xe.transition=l,j=r}return null}function gd(e,n,t,r){do Jn();while(tn!==null);if(R&6)throw Error(y(327));t=e.finishedWork;var l=e.finishedLanes;if(t===null)return null;if(e.finishedWork=null,e.finishedLanes=0,t===e.current)throw Error(y(177));e.callbackNode=null,e.callbackPriority=0;var o=t.lanes|t.childLanes;if(Jc(e,o),e===J&&(K=J=null,b=0),!(t.subtreeFlags&2064)&&!(t.flags&2064)||vr||(vr=!0,Ja(jr,function(){return Jn(),null})),o=(t.flags&15990)!==0,t.subtreeFlags&15990||o){o=xe.transition,xe.transition=null;var i=j;j=1;var u=R;R|=4,Ei.current=null,dd(e,t),Ba(t,e),Uf(ho),Dr=!!mo,ho=mo=null,e.current=t,pd(t),Bc(),R=u,j=i,xe.transition=o}else e.current=t;if(vr&&(vr=!1,tn=e,Jr=l),o=e.pendingLanes,o===0&&(an=null),Qc(t.stateNode),pe(e,Q()),n!==null)for(r=e.onRecoverableError,t=0;t<n.length;t++)l=n[t],r(l.value,{componentStack:l.stack,digest:l.digest});if(Zr)throw Zr=!1,e=Do,Do=null,e;return Jr&1&&e.tag!==0&&Jn(),o=e.pendingLanes,o&1?e===Io?Tt++:(Tt=0,Io=e):Tt=0,vn(),null}function Jn(){if(tn!==null){var e=Ps(Jr),n=xe.transition,t=j;try{if(xe.transition=null,j=16>e?16:e,tn===null)var r=!1;else{if(e=tn,tn=null,Jr=0,R&6)throw Error(y(331));var l=R;for(R|=4,k=e.current;k!==null;){var o=k,i=o.child;if(k.flags&16){var u=o.deletions;if(u!==null){for(var s=0;s<u.length;s++){var c=u[s];for(k=c;k!==null;){var h=k;switch(h.tag){case 0:case 11:case 15:zt(8,h,o)}var m=h.child;if(m!==null)m.return=h,k=m;else for(;k!==null;){h=k;var p=h.sibling,g=h.return;if($a(h),h===c){k=null;break}if(p!==null){p.return=g,k=p;break}k=g}}}var w=o.alternate;if(w!==null){var S=w.child;if(S!==null){w.child=null;do{var F=S.sibling;S.sibling=null,S=F}while(S!==null)}}k=o}}if(o.subtreeFlags&2064&&i!==null)i.return=o,k=i;else e:for(;k!==null;){if(o=k,o.flags&2048)switch(o.tag){case 0:case 11:case 15:zt(9,o,o.return)}var f=o.sibling;if(f!==null){f.return=o.return,k=f;break e}k=o.return}}var a=e.current;for(k=a;k!==null;){i=k;var d=i.child;if(i.subtreeFlags&2064&&d!==null)d.return=i,k=d;else e:for(i=a;k!==null;){if(u=k,u.flags&2048)try{switch(u.tag){case 0:case 11:case 15:al(9,u)}}catch(E){B(u,u.return,E)}if(u===i){k=null;break e}var v=u.sibling;if(v!==null){v.return=u.return,k=v;break e}k=u.return}}if(R=l,vn(),Ue&&typeof Ue.onPostCommitFiberRoot=="function")try{Ue.onPostCommitFiberRoot(nl,e)}catch{}r=!0}return r}finally{j=t,xe.transition=n}}return!1}function $u(e,n,t){n=rt(t,n),n=za(e,n,1),e=sn(e,n,1),n=ie(),e!==null&&(Gt(e,1,n),pe(e,n))}function B(e,n,t){if(e.tag===3)$u(e,e,t);else for(;n!==null;){if(n.tag===3){$u(n,e,t);break}else if(n.tag===1){var r=n.stateNode;if(typeof n.type.getDerivedStateFromError=="function"||typeof r.componentDidCatch=="function"&&(an===null||!an.has(r))){e=rt(t,e),e=La(n,e,1),n=sn(n,e,1),e=ie(),n!==null&&(Gt(n,1,e),pe(n,e));break}}n=n.return}}function wd(e,n,t){var r=e.pingCache;r!==null&&r.delete(n),n=ie(),e.pingedLanes|=e.suspendedLanes&t,J===e&&(b&t)===t&&(X===4||X===3&&(b&130023424)===b&&500>Q()-Ci?Cn(e,0):xi|=t),pe(e,n)}function Ga(e,n){n===0&&(e.mode&1?(n=ir,ir<<=1,!(ir&130023424)&&(ir=4194304)):n=1);var t=ie();e=Ye(e,n),e!==null&&(Gt(e,n,t),pe(e,t))}function Sd(e){var n=e.memoizedState,t=0;n!==null&&(t=n.retryLane),Ga(e,t)}function kd(e,n){var t=0;switch(e.tag){case 13:var r=e.stateNode,l=e.memoizedState;l!==null&&(t=l.retryLane);break;case 19:r=e.stateNode;break;default:throw Error(y(314))}r!==null&&r.delete(n),Ga(e,t)}var Za;Za=function(e,n,t){if(e!==null)if(e.memoizedProps!==n.pendingProps||fe.current)ce=!0;else{if(!(e.lanes&t)&&!(n.flags&128))return ce=!1,sd(e,n,t);ce=!!(e.flags&131072)}else ce=!1,U&&n.flags&1048576&&bs(n,Br,n.index);switch(n.lanes=0,n.tag){case 2:var r=n.type;Nr(e,n),e=n.pendingProps;var l=bn(n,le.current);Zn(n,t),l=yi(null,n,r,e,l,t);var o=gi();return n.flags|=1,typeof l=="object"&&l!==null&&typeof l.render=="function"&&l.$$typeof===void 0?(n.tag=1,n.memoizedState=null,n.updateQueue=null,de(r)?(o=!0,Ar(n)):o=!1,n.memoizedState=l.state!==null&&l.state!==void 0?l.state:null,di(n),l.updater=ul,n.stateNode=l,l._reactInternals=n,Co(n,r,e,t),n=Po(null,n,r,!0,o,t)):(n.tag=0,U&&o&&oi(n),oe(null,n,l,t),n=n.child),n;case 16:r=n.elementType;e:{switch(Nr(e,n),e=n.pendingProps,l=r._init,r=l(r._payload),n.type=r,l=n.tag=xd(r),e=ze(r,e),l){case 0:n=No(null,n,r,e,t);break e;case 1:n=Lu(null,n,r,e,t);break e;case 11:n=Pu(null,n,r,e,t);break e;case 14:n=zu(null,n,r,ze(r.type,e),t);break e}throw Error(y(306,r,""))}return n;case 0:return r=n.type,l=n.pendingProps,l=n.elementType===r?l:ze(r,l),No(e,n,r,l,t);case 1:return r=n.type,l=n.pendingProps,l=n.elementType===r?l:ze(r,l),Lu(e,n,r,l,t);case 3:e:{if(ja(n),e===null)throw Error(y(387));r=n.pendingProps,o=n.memoizedState,l=o.element,ra(e,n),Qr(n,r,null,t);var i=n.memoizedState;if(r=i.element,o.isDehydrated)if(o={element:r,isDehydrated:!1,cache:i.cache,pendingSuspenseBoundaries:i.pendingSuspenseBoundaries,transitions:i.transitions},n.updateQueue.baseState=o,n.memoizedState=o,n.flags&256){l=rt(Error(y(423)),n),n=Tu(e,n,r,t,l);break e}else if(r!==l){l=rt(Error(y(424)),n),n=Tu(e,n,r,t,l);break e}else for(he=un(n.stateNode.containerInfo.firstChild),ve=n,U=!0,Re=null,t=ua(n,null,r,t),n.child=t;t;)t.flags=t.flags&-3|4096,t=t.sibling;else{if(et(),r===l){n=Xe(e,n,t);break e}oe(e,n,r,t)}n=n.child}return n;case 5:return sa(n),e===null&&ko(n),r=n.type,l=n.pendingProps,o=e!==null?e.memoizedProps:null,i=l.children,vo(r,l)?i=null:o!==null&&vo(r,o)&&(n.flags|=32),Oa(e,n),oe(e,n,i,t),n.child;case 6:return e===null&&ko(n),null;case 13:return Ma(e,n,t);case 4:return pi(n,n.stateNode.containerInfo),r=n.pendingProps,e===null?n.child=nt(n,null,r,t):oe(e,n,r,t),n.child;case 11:return r=n.type,l=n.pendingProps,l=n.elementType===r?l:ze(r,l),Pu(e,n,r,l,t);case 7:return oe(e,n,n.pendingProps,t),n.child;case 8:return oe(e,n,n.pendingProps.children,t),n.child;case 12:return oe(e,n,n.pendingProps.children,t),n.child;case 10:e:{if(r=n.type._context,l=n.pendingProps,o=n.memoizedProps,i=l.value,M(Hr,r._currentValue),r._currentValue=i,o!==null)if(Me(o.value,i)){if(o.children===l.children&&!fe.current){n=Xe(e,n,t);break e}}else for(o=n.child,o!==null&&(o.return=n);o!==null;){var u=o.dependencies;if(u!==null){i=o.child;for(var s=u.firstContext;s!==null;){if(s.context===r){if(o.tag===1){s=We(-1,t&-t),s.tag=2;var c=o.updateQueue;if(c!==null){c=c.shared;var h=c.pending;h===null?s.next=s:(s.next=h.next,h.next=s),c.pending=s}}o.lanes|=t,s=o.alternate,s!==null&&(s.lanes|=t),Eo(o.return,t,n),u.lanes|=t;break}s=s.next}}else if(o.tag===10)i=o.type===n.type?null:o.child;else if(o.tag===18){if(i=o.return,i===null)throw Error(y(341));i.lanes|=t,u=i.alternate,u!==null&&(u.lanes|=t),Eo(i,t,n),i=o.sibling}else i=o.child;if(i!==null)i.return=o;else for(i=o;i!==null;){if(i===n){i=null;break}if(o=i.sibling,o!==null){o.return=i.return,i=o;break}i=i.return}o=i}oe(e,n,l.children,t),n=n.child}return n;case 9:return l=n.type,r=n.pendingProps.children,Zn(n,t),l=Ce(l),r=r(l),n.flags|=1,oe(e,n,r,t),n.child;case 14:return r=n.type,l=ze(r,n.pendingProps),l=ze(r.type,l),zu(e,n,r,l,t);case 15:return Ta(e,n,n.type,n.pendingProps,t);case 17:return r=n.type,l=n.pendingProps,l=n.elementType===r?l:ze(r,l),Nr(e,n),n.tag=1,de(r)?(e=!0,Ar(n)):e=!1,Zn(n,t),oa(n,r,l),Co(n,r,l,t),Po(null,n,r,!0,e,t);case 19:return Da(e,n,t);case 22:return Ra(e,n,t)}throw Error(y(156,n.tag))};function Ja(e,n){return xs(e,n)}function Ed(e,n,t,r){this.tag=e,this.key=t,this.sibling=this.child=this.return=this.stateNode=this.type=this.elementType=null,this.index=0,this.ref=null,this.pendingProps=n,this.dependencies=this.memoizedState=this.updateQueue=this.memoizedProps=null,this.mode=r,this.subtreeFlags=this.flags=0,this.deletions=null,this.childLanes=this.lanes=0,this.alternate=null}function Ee(e,n,t,r){return new Ed(e,n,t,r)}function zi(e){return e=e.prototype,!(!e||!e.isReactComponent)}function xd(e){if(typeof e=="function")return zi(e)?1:0;if(e!=null){if(e=e.$$typeof,e===Yo)return 11;if(e===Xo)return 14}return 2}function fn(e,n){var t=e.alternate;return t===null?(t=Ee(e.tag,n,e.key,e.mode),t.elementType=e.elementType,t.type=e.type,t.stateNode=e.stateNode,t.alternate=e,e.alternate=t):(t.pendingProps=n,t.type=e.type,t.flags=0,t.subtreeFlags=0,t.deletions=null),t.flags=e.flags&14680064,t.childLanes=e.childLanes,t.lanes=e.lanes,t.child=e.child,t.memoizedProps=e.memoizedProps,t.memoizedState=e.memoizedState,t.updateQueue=e.updateQueue,n=e.dependencies,t.dependencies=n===null?null:{lanes:n.lanes,firstContext:n.firstContext},t.sibling=e.sibling,t.index=e.index,t.ref=e.ref,t}function Lr(e,n,t,r,l,o){var i=2;if(r=e,typeof e=="function")zi(e)&&(i=1);else if(typeof e=="string")i=5;else e:switch(e){case Dn:return _n(t.children,l,o,n);case Ko:i=8,l|=8;break;case Yl:return e=Ee(12,t,n,l|2),e.elementType=Yl,e.lanes=o,e;case Xl:return e=Ee(13,t,n,l),e.elementType=Xl,e.lanes=o,e;case Gl:return e=Ee(19,t,n,l),e.elementType=Gl,e.lanes=o,e;case is:return fl(t,l,o,n);default:if(typeof e=="object"&&e!==null)switch(e.$$typeof){case ls:i=10;break e;case os:i=9;break e;case Yo:i=11;break e;case Xo:i=14;break e;case Je:i=16,r=null;break e}throw Error(y(130,e==null?e:typeof e,""))}return n=Ee(i,t,n,l),n.elementType=e,n.type=r,n.lanes=o,n}function _n(e,n,t,r){return e=Ee(7,e,r,n),e.lanes=t,e}function fl(e,n,t,r){return e=Ee(22,e,r,n),e.elementType=is,e.lanes=t,e.stateNode={isHidden:!1},e}function Hl(e,n,t){return e=Ee(6,e,null,n),e.lanes=t,e}function Wl(e,n,t){return n=Ee(4,e.children!==null?e.children:[],e.key,n),n.lanes=t,n.stateNode={containerInfo:e.containerInfo,pendingChildren:null,implementation:e.implementation},n}function Cd(e,n,t,r,l){this.tag=n,this.containerInfo=e,this.finishedWork=this.pingCache=this.current=this.pendingChildren=null,this.timeoutHandle=-1,this.callbackNode=this.pendingContext=this.context=null,this.callbackPriority=0,this.eventTimes=Cl(0),this.expirationTimes=Cl(-1),this.entangledLanes=this.finishedLanes=this.mutableReadLanes=this.expiredLanes=this.pingedLanes=this.suspendedLanes=this.pendingLanes=0,this.entanglements=Cl(0),this.identifierPrefix=r,this.onRecoverableError=l,this.mutableSourceEagerHydrationData=null}function Li(e,n,t,r,l,o,i,u,s){return e=new Cd(e,n,t,u,s),n===1?(n=1,o===!0&&(n|=8)):n=0,o=Ee(3,null,null,n),e.current=o,o.stateNode=e,o.memoizedState={element:r,isDehydrated:t,cache:null,transitions:null,pendingSuspenseBoundaries:null},di(o),e}function _d(e,n,t){var r=3<arguments.length&&arguments[3]!==void 0?arguments[3]:null;return{$$typeof:Mn,key:r==null?null:""+r,children:e,containerInfo:n,implementation:t}}function qa(e){if(!e)return pn;e=e._reactInternals;e:{if(On(e)!==e||e.tag!==1)throw Error(y(170));var n=e;do{switch(n.tag){case 3:n=n.stateNode.context;break e;case 1:if(de(n.type)){n=n.stateNode.__reactInternalMemoizedMergedChildContext;break e}}n=n.return}while(n!==null);throw Error(y(171))}if(e.tag===1){var t=e.type;if(de(t))return Js(e,t,n)}return n}function ba(e,n,t,r,l,o,i,u,s){return e=Li(t,r,!0,e,l,o,i,u,s),e.context=qa(null),t=e.current,r=ie(),l=cn(t),o=We(r,l),o.callback=n??null,sn(t,o,l),e.current.lanes=l,Gt(e,l,r),pe(e,r),e}function dl(e,n,t,r){var l=n.current,o=ie(),i=cn(l);return t=qa(t),n.context===null?n.context=t:n.pendingContext=t,n=We(o,i),n.payload={element:e},r=r===void 0?null:r,r!==null&&(n.callback=r),e=sn(l,n,i),e!==null&&(je(e,l,i,o),xr(e,l,i)),i}function br(e){if(e=e.current,!e.child)return null;switch(e.child.tag){case 5:return e.child.stateNode;default:return e.child.stateNode}}function Au(e,n){if(e=e.memoizedState,e!==null&&e.dehydrated!==null){var t=e.retryLane;e.retryLane=t!==0&&t<n?t:n}}function Ti(e,n){Au(e,n),(e=e.alternate)&&Au(e,n)}function Nd(){return null}var ec=typeof reportError=="function"?reportError:function(e){console.error(e)};function Ri(e){this._internalRoot=e}pl.prototype.render=Ri.prototype.render=function(e){var n=this._internalRoot;if(n===null)throw Error(y(409));dl(e,n,null,null)};pl.prototype.unmount=Ri.prototype.unmount=function(){var e=this._internalRoot;if(e!==null){this._internalRoot=null;var n=e.containerInfo;Tn(function(){dl(null,e,null,null)}),n[Ke]=null}};function pl(e){this._internalRoot=e}pl.prototype.unstable_scheduleHydration=function(e){if(e){var n=Ts();e={blockedOn:null,target:e,priority:n};for(var t=0;t<be.length&&n!==0&&n<be[t].priority;t++);be.splice(t,0,e),t===0&&Os(e)}};function Oi(e){return!(!e||e.nodeType!==1&&e.nodeType!==9&&e.nodeType!==11)}function ml(e){return!(!e||e.nodeType!==1&&e.nodeType!==9&&e.nodeType!==11&&(e.nodeType!==8||e.nodeValue!==" react-mount-point-unstable "))}function Vu(){}function Pd(e,n,t,r,l){if(l){if(typeof r=="function"){var o=r;r=function(){var c=br(i);o.call(c)}}var i=ba(n,r,e,0,null,!1,!1,"",Vu);return e._reactRootContainer=i,e[Ke]=i.current,$t(e.nodeType===8?e.parentNode:e),Tn(),i}for(;l=e.lastChild;)e.removeChild(l);if(typeof r=="function"){var u=r;r=function(){var c=br(s);u.call(c)}}var s=Li(e,0,!1,null,null,!1,!1,"",Vu);return e._reactRootContainer=s,e[Ke]=s.current,$t(e.nodeType===8?e.parentNode:e),Tn(function(){dl(n,s,t,r)}),s}function hl(e,n,t,r,l){var o=t._reactRootContainer;if(o){var i=o;if(typeof l=="function"){var u=l;l=function(){var s=br(i);u.call(s)}}dl(n,i,e,l)}else i=Pd(t,n,e,l,r);return br(i)}zs=function(e){switch(e.tag){case 3:var n=e.stateNode;if(n.current.memoizedState.isDehydrated){var t=St(n.pendingLanes);t!==0&&(Jo(n,t|1),pe(n,Q()),!(R&6)&&(lt=Q()+500,vn()))}break;case 13:Tn(function(){var r=Ye(e,1);if(r!==null){var l=ie();je(r,e,1,l)}}),Ti(e,1)}};qo=function(e){if(e.tag===13){var n=Ye(e,134217728);if(n!==null){var t=ie();je(n,e,134217728,t)}Ti(e,134217728)}};Ls=function(e){if(e.tag===13){var n=cn(e),t=Ye(e,n);if(t!==null){var r=ie();je(t,e,n,r)}Ti(e,n)}};Ts=function(){return j};Rs=function(e,n){var t=j;try{return j=e,n()}finally{j=t}};oo=function(e,n,t){switch(n){case"input":if(ql(e,t),n=t.name,t.type==="radio"&&n!=null){for(t=e;t.parentNode;)t=t.parentNode;for(t=t.querySelectorAll("input[name="+JSON.stringify(""+n)+'][type="radio"]'),n=0;n<t.length;n++){var r=t[n];if(r!==e&&r.form===e.form){var l=ol(r);if(!l)throw Error(y(90));ss(r),ql(r,l)}}}break;case"textarea":cs(e,t);break;case"select":n=t.value,n!=null&&Kn(e,!!t.multiple,n,!1)}};ys=_i;gs=Tn;var zd={usingClientEntryPoint:!1,Events:[Jt,$n,ol,hs,vs,_i]},yt={findFiberByHostInstance:kn,bundleType:0,version:"18.2.0",rendererPackageName:"react-dom"},Ld={bundleType:yt.bundleType,version:yt.version,rendererPackageName:yt.rendererPackageName,rendererConfig:yt.rendererConfig,overrideHookState:null,overrideHookStateDeletePath:null,overrideHookStateRenamePath:null,overrideProps:null,overridePropsDeletePath:null,overridePropsRenamePath:null,setErrorHandler:null,setSuspenseHandler:null,scheduleUpdate:null,currentDispatcherRef:Ge.ReactCurrentDispatcher,findHostInstanceByFiber:function(e){return e=ks(e),e===null?null:e.stateNode},findFiberByHostInstance:yt.findFiberByHostInstance||Nd,findHostInstancesForRefresh:null,scheduleRefresh:null,scheduleRoot:null,setRefreshHandler:null,getCurrentFiber:null,reconcilerVersion:"18.2.0-next-9e3b772b8-20220608"};if(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__<"u"){var yr=__REACT_DEVTOOLS_GLOBAL_HOOK__;if(!yr.isDisabled&&yr.supportsFiber)try{nl=yr.inject(Ld),Ue=yr}catch{}}ge.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED=zd;ge.createPortal=function(e,n){var t=2<arguments.length&&arguments[2]!==void 0?arguments[2]:null;if(!Oi(n))throw Error(y(200));return _d(e,n,null,t)};ge.createRoot=function(e,n){if(!Oi(e))throw Error(y(299));var t=!1,r="",l=ec;return n!=null&&(n.unstable_strictMode===!0&&(t=!0),n.identifierPrefix!==void 0&&(r=n.identifierPrefix),n.onRecoverableError!==void 0&&(l=n.onRecoverableError)),n=Li(e,1,!1,null,null,t,!1,r,l),e[Ke]=n.current,$t(e.nodeType===8?e.parentNode:e),new Ri(n)};ge.findDOMNode=function(e){if(e==null)return null;if(e.nodeType===1)return e;var n=e._reactInternals;if(n===void 0)throw typeof e.render=="function"?Error(y(188)):(e=Object.keys(e).join(","),Error(y(268,e)));return e=ks(n),e=e===null?null:e.stateNode,e};ge.flushSync=function(e){return Tn(e)};ge.hydrate=function(e,n,t){if(!ml(n))throw Error(y(200));return hl(null,e,n,!0,t)};ge.hydrateRoot=function(e,n,t){if(!Oi(e))throw Error(y(405));var r=t!=null&&t.hydratedSources||null,l=!1,o="",i=ec;if(t!=null&&(t.unstable_strictMode===!0&&(l=!0),t.identifierPrefix!==void 0&&(o=t.identifierPrefix),t.onRecoverableError!==void 0&&(i=t.onRecoverableError)),n=ba(n,null,e,1,t??null,l,!1,o,i),e[Ke]=n.current,$t(e),r)for(e=0;e<r.length;e++)t=r[e],l=t._getVersion,l=l(t._source),n.mutableSourceEagerHydrationData==null?n.mutableSourceEagerHydrationData=[t,l]:n.mutableSourceEagerHydrationData.push(t,l);return new pl(n)};ge.render=function(e,n,t){if(!ml(n))throw Error(y(200));return hl(null,e,n,!1,t)};ge.unmountComponentAtNode=function(e){if(!ml(e))throw Error(y(40));return e._reactRootContainer?(Tn(function(){hl(null,null,e,!1,function(){e._reactRootContainer=null,e[Ke]=null})}),!0):!1};ge.unstable_batchedUpdates=_i;ge.unstable_renderSubtreeIntoContainer=function(e,n,t,r){if(!ml(t))throw Error(y(200));if(e==null||e._reactInternals===void 0)throw Error(y(38));return hl(e,n,t,!1,r)};ge.version="18.2.0-next-9e3b772b8-20220608";function nc(){if(!(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__>"u"||typeof __REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE!="function"))try{__REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE(nc)}catch(e){console.error(e)}}nc(),bu.exports=ge;var Td=bu.exports,Bu=Td;Ql.createRoot=Bu.createRoot,Ql.hydrateRoot=Bu.hydrateRoot;function Rd({text:e,percentage:n}){return n=n??0,O.jsx("div",{className:"progress-container",children:O.jsxs("div",{className:"progress-bar",style:{width:`${n}%`},children:[e," (",`${n.toFixed(2)}%`,")"]})})}function Od(){return O.jsxs("div",{id:"loader",children:[O.jsx("div",{}),O.jsx("div",{}),O.jsx("div",{}),O.jsx("div",{})]})}function jd(){const[e,n]=Le.useState(null),[t,r]=Le.useState(!1),[l,o]=Le.useState([]),[i,u]=Le.useState(""),[s,c]=Le.useState([]),h=Le.useRef(null);Le.useEffect(()=>{h.current||(h.current=new Worker(`${window.location.origin}/librarian/worker.js`,{type:"module"}));const p=g=>{switch(g.data.status){case"initiate":n(!1),o(w=>[...w,g.data]);break;case"progress":o(w=>w.map(S=>S.file===g.data.file?{...S,progress:g.data.progress}:S));break;case"done":o(w=>w.filter(S=>S.file!==g.data.file));break;case"ready":n(!0);break;case"complete":fetch("/librarian/vector",{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({namespace:"default",includeValues:!1,includeMetadata:!0,topK:10,vector:Object.values(g.data.output)})}).then(w=>w.json()).then(w=>{console.log("res",w.matches),c(w.matches),r(!1)});break}};return h.current.addEventListener("message",p),()=>h.current.removeEventListener("message",p)});const m=()=>{r(!0),h.current.postMessage({text:i})};return O.jsxs(O.Fragment,{children:[O.jsx("h1",{children:"Uqbar Librarian"}),O.jsxs("h2",{children:["Searching ",O.jsx("code",{children:"drew.uq"}),"'s ",O.jsx("code",{children:"news"})," database"]}),O.jsx("div",{className:"container",style:{minWidth:"700px"},children:O.jsxs("div",{className:"textbox-container",style:{marginBottom:"0"},children:[O.jsx("input",{value:i,onChange:p=>u(p.target.value),placeholder:"query for articles here"}),O.jsx("button",{disabled:t,onClick:m,children:"Search"})]})}),t&&O.jsx(Od,{}),O.jsx("div",{className:"container",children:s&&s.map((p,g)=>O.jsx(Md,{article:p},g))}),O.jsxs("div",{className:"progress-bars-container",children:[e===!1&&O.jsx("label",{children:"Loading models... (only run once)"}),l.map(p=>O.jsx("div",{children:O.jsx(Rd,{text:p.file,percentage:p.progress})},p.file))]})]})}function Md(e){const{key:n,article:t}=e,[r,l]=Le.useState(!1);return t.metadata.truncated=`${t.metadata.article.slice(0,200)}...`,O.jsxs("div",{className:"article",children:[O.jsx("a",{href:t.metadata.url,children:O.jsx("h1",{className:"article-title",children:t.metadata.title})}),O.jsxs("h3",{className:"article-author",children:[t.metadata.author," | ",t.metadata.publication]}),O.jsx("p",{className:"article-content",children:r?t.metadata.article:t.metadata.truncated}),O.jsx("span",{className:"read-more-link",onClick:()=>l(!r),children:r?"Collapse":"Read More"})]},n)}Ql.createRoot(document.getElementById("root")).render(O.jsx(wc.StrictMode,{children:O.jsx(jd,{})}));
+`+o.stack}return{value:e,source:n,stack:l,digest:null}}function Vl(e,n,t){return{value:e,source:null,stack:t??null,digest:n??null}}function _o(e,n){try{console.error(n.value)}catch(t){setTimeout(function(){throw t})}}var od=typeof WeakMap=="function"?WeakMap:Map;function za(e,n,t){t=We(-1,t),t.tag=3,t.payload={element:null};var r=n.value;return t.callback=function(){Zr||(Zr=!0,Do=r),_o(e,n)},t}function La(e,n,t){t=We(-1,t),t.tag=3;var r=e.type.getDerivedStateFromError;if(typeof r=="function"){var l=n.value;t.payload=function(){return r(l)},t.callback=function(){_o(e,n)}}var o=e.stateNode;return o!==null&&typeof o.componentDidCatch=="function"&&(t.callback=function(){_o(e,n),typeof r!="function"&&(an===null?an=new Set([this]):an.add(this));var i=n.stack;this.componentDidCatch(n.value,{componentStack:i!==null?i:""})}),t}function Cu(e,n,t){var r=e.pingCache;if(r===null){r=e.pingCache=new od;var l=new Set;r.set(n,l)}else l=r.get(n),l===void 0&&(l=new Set,r.set(n,l));l.has(t)||(l.add(t),e=wd.bind(null,e,n,t),n.then(e,e))}function _u(e){do{var n;if((n=e.tag===13)&&(n=e.memoizedState,n=n!==null?n.dehydrated!==null:!0),n)return e;e=e.return}while(e!==null);return null}function Nu(e,n,t,r,l){return e.mode&1?(e.flags|=65536,e.lanes=l,e):(e===n?e.flags|=65536:(e.flags|=128,t.flags|=131072,t.flags&=-52805,t.tag===1&&(t.alternate===null?t.tag=17:(n=We(-1,1),n.tag=2,sn(t,n,1))),t.lanes|=1),e)}var id=Ge.ReactCurrentOwner,ce=!1;function oe(e,n,t,r){n.child=e===null?ua(n,null,t,r):nt(n,e.child,t,r)}function Pu(e,n,t,r,l){t=t.render;var o=n.ref;return Zn(n,l),r=yi(e,n,t,r,o,l),t=gi(),e!==null&&!ce?(n.updateQueue=e.updateQueue,n.flags&=-2053,e.lanes&=~l,Xe(e,n,l)):(U&&t&&oi(n),n.flags|=1,oe(e,n,r,l),n.child)}function zu(e,n,t,r,l){if(e===null){var o=t.type;return typeof o=="function"&&!zi(o)&&o.defaultProps===void 0&&t.compare===null&&t.defaultProps===void 0?(n.tag=15,n.type=o,Ta(e,n,o,r,l)):(e=Lr(t.type,null,r,n,n.mode,l),e.ref=n.ref,e.return=n,n.child=e)}if(o=e.child,!(e.lanes&l)){var i=o.memoizedProps;if(t=t.compare,t=t!==null?t:Ut,t(i,r)&&e.ref===n.ref)return Xe(e,n,l)}return n.flags|=1,e=fn(o,r),e.ref=n.ref,e.return=n,n.child=e}function Ta(e,n,t,r,l){if(e!==null){var o=e.memoizedProps;if(Ut(o,r)&&e.ref===n.ref)if(ce=!1,n.pendingProps=r=o,(e.lanes&l)!==0)e.flags&131072&&(ce=!0);else return n.lanes=e.lanes,Xe(e,n,l)}return No(e,n,t,r,l)}function Ra(e,n,t){var r=n.pendingProps,l=r.children,o=e!==null?e.memoizedState:null;if(r.mode==="hidden")if(!(n.mode&1))n.memoizedState={baseLanes:0,cachePool:null,transitions:null},M(Qn,me),me|=t;else{if(!(t&1073741824))return e=o!==null?o.baseLanes|t:t,n.lanes=n.childLanes=1073741824,n.memoizedState={baseLanes:e,cachePool:null,transitions:null},n.updateQueue=null,M(Qn,me),me|=e,null;n.memoizedState={baseLanes:0,cachePool:null,transitions:null},r=o!==null?o.baseLanes:t,M(Qn,me),me|=r}else o!==null?(r=o.baseLanes|t,n.memoizedState=null):r=t,M(Qn,me),me|=r;return oe(e,n,l,t),n.child}function Oa(e,n){var t=n.ref;(e===null&&t!==null||e!==null&&e.ref!==t)&&(n.flags|=512,n.flags|=2097152)}function No(e,n,t,r,l){var o=de(t)?Nn:le.current;return o=bn(n,o),Zn(n,l),t=yi(e,n,t,r,o,l),r=gi(),e!==null&&!ce?(n.updateQueue=e.updateQueue,n.flags&=-2053,e.lanes&=~l,Xe(e,n,l)):(U&&r&&oi(n),n.flags|=1,oe(e,n,t,l),n.child)}function Lu(e,n,t,r,l){if(de(t)){var o=!0;Ar(n)}else o=!1;if(Zn(n,l),n.stateNode===null)Nr(e,n),oa(n,t,r),Co(n,t,r,l),r=!0;else if(e===null){var i=n.stateNode,u=n.memoizedProps;i.props=u;var s=i.context,c=t.contextType;typeof c=="object"&&c!==null?c=Ce(c):(c=de(t)?Nn:le.current,c=bn(n,c));var h=t.getDerivedStateFromProps,m=typeof h=="function"||typeof i.getSnapshotBeforeUpdate=="function";m||typeof i.UNSAFE_componentWillReceiveProps!="function"&&typeof i.componentWillReceiveProps!="function"||(u!==r||s!==c)&&Su(n,i,r,c),qe=!1;var p=n.memoizedState;i.state=p,Qr(n,r,i,l),s=n.memoizedState,u!==r||p!==s||fe.current||qe?(typeof h=="function"&&(xo(n,t,h,r),s=n.memoizedState),(u=qe||wu(n,t,u,r,p,s,c))?(m||typeof i.UNSAFE_componentWillMount!="function"&&typeof i.componentWillMount!="function"||(typeof i.componentWillMount=="function"&&i.componentWillMount(),typeof i.UNSAFE_componentWillMount=="function"&&i.UNSAFE_componentWillMount()),typeof i.componentDidMount=="function"&&(n.flags|=4194308)):(typeof i.componentDidMount=="function"&&(n.flags|=4194308),n.memoizedProps=r,n.memoizedState=s),i.props=r,i.state=s,i.context=c,r=u):(typeof i.componentDidMount=="function"&&(n.flags|=4194308),r=!1)}else{i=n.stateNode,ra(e,n),u=n.memoizedProps,c=n.type===n.elementType?u:ze(n.type,u),i.props=c,m=n.pendingProps,p=i.context,s=t.contextType,typeof s=="object"&&s!==null?s=Ce(s):(s=de(t)?Nn:le.current,s=bn(n,s));var g=t.getDerivedStateFromProps;(h=typeof g=="function"||typeof i.getSnapshotBeforeUpdate=="function")||typeof i.UNSAFE_componentWillReceiveProps!="function"&&typeof i.componentWillReceiveProps!="function"||(u!==m||p!==s)&&Su(n,i,r,s),qe=!1,p=n.memoizedState,i.state=p,Qr(n,r,i,l);var w=n.memoizedState;u!==m||p!==w||fe.current||qe?(typeof g=="function"&&(xo(n,t,g,r),w=n.memoizedState),(c=qe||wu(n,t,c,r,p,w,s)||!1)?(h||typeof i.UNSAFE_componentWillUpdate!="function"&&typeof i.componentWillUpdate!="function"||(typeof i.componentWillUpdate=="function"&&i.componentWillUpdate(r,w,s),typeof i.UNSAFE_componentWillUpdate=="function"&&i.UNSAFE_componentWillUpdate(r,w,s)),typeof i.componentDidUpdate=="function"&&(n.flags|=4),typeof i.getSnapshotBeforeUpdate=="function"&&(n.flags|=1024)):(typeof i.componentDidUpdate!="function"||u===e.memoizedProps&&p===e.memoizedState||(n.flags|=4),typeof i.getSnapshotBeforeUpdate!="function"||u===e.memoizedProps&&p===e.memoizedState||(n.flags|=1024),n.memoizedProps=r,n.memoizedState=w),i.props=r,i.state=w,i.context=s,r=c):(typeof i.componentDidUpdate!="function"||u===e.memoizedProps&&p===e.memoizedState||(n.flags|=4),typeof i.getSnapshotBeforeUpdate!="function"||u===e.memoizedProps&&p===e.memoizedState||(n.flags|=1024),r=!1)}return Po(e,n,t,r,o,l)}function Po(e,n,t,r,l,o){Oa(e,n);var i=(n.flags&128)!==0;if(!r&&!i)return l&&mu(n,t,!1),Xe(e,n,o);r=n.stateNode,id.current=n;var u=i&&typeof t.getDerivedStateFromError!="function"?null:r.render();return n.flags|=1,e!==null&&i?(n.child=nt(n,e.child,null,o),n.child=nt(n,null,u,o)):oe(e,n,u,o),n.memoizedState=r.state,l&&mu(n,t,!0),n.child}function ja(e){var n=e.stateNode;n.pendingContext?pu(e,n.pendingContext,n.pendingContext!==n.context):n.context&&pu(e,n.context,!1),pi(e,n.containerInfo)}function Tu(e,n,t,r,l){return et(),ui(l),n.flags|=256,oe(e,n,t,r),n.child}var zo={dehydrated:null,treeContext:null,retryLane:0};function Lo(e){return{baseLanes:e,cachePool:null,transitions:null}}function Ma(e,n,t){var r=n.pendingProps,l=$.current,o=!1,i=(n.flags&128)!==0,u;if((u=i)||(u=e!==null&&e.memoizedState===null?!1:(l&2)!==0),u?(o=!0,n.flags&=-129):(e===null||e.memoizedState!==null)&&(l|=1),M($,l&1),e===null)return ko(n),e=n.memoizedState,e!==null&&(e=e.dehydrated,e!==null)?(n.mode&1?e.data==="$!"?n.lanes=8:n.lanes=1073741824:n.lanes=1,null):(i=r.children,e=r.fallback,o?(r=n.mode,o=n.child,i={mode:"hidden",children:i},!(r&1)&&o!==null?(o.childLanes=0,o.pendingProps=i):o=fl(i,r,0,null),e=_n(e,r,t,null),o.return=n,e.return=n,o.sibling=e,n.child=o,n.child.memoizedState=Lo(t),n.memoizedState=zo,e):ki(n,i));if(l=e.memoizedState,l!==null&&(u=l.dehydrated,u!==null))return ud(e,n,i,r,u,l,t);if(o){o=r.fallback,i=n.mode,l=e.child,u=l.sibling;var s={mode:"hidden",children:r.children};return!(i&1)&&n.child!==l?(r=n.child,r.childLanes=0,r.pendingProps=s,n.deletions=null):(r=fn(l,s),r.subtreeFlags=l.subtreeFlags&14680064),u!==null?o=fn(u,o):(o=_n(o,i,t,null),o.flags|=2),o.return=n,r.return=n,r.sibling=o,n.child=r,r=o,o=n.child,i=e.child.memoizedState,i=i===null?Lo(t):{baseLanes:i.baseLanes|t,cachePool:null,transitions:i.transitions},o.memoizedState=i,o.childLanes=e.childLanes&~t,n.memoizedState=zo,r}return o=e.child,e=o.sibling,r=fn(o,{mode:"visible",children:r.children}),!(n.mode&1)&&(r.lanes=t),r.return=n,r.sibling=null,e!==null&&(t=n.deletions,t===null?(n.deletions=[e],n.flags|=16):t.push(e)),n.child=r,n.memoizedState=null,r}function ki(e,n){return n=fl({mode:"visible",children:n},e.mode,0,null),n.return=e,e.child=n}function mr(e,n,t,r){return r!==null&&ui(r),nt(n,e.child,null,t),e=ki(n,n.pendingProps.children),e.flags|=2,n.memoizedState=null,e}function ud(e,n,t,r,l,o,i){if(t)return n.flags&256?(n.flags&=-257,r=Vl(Error(y(422))),mr(e,n,i,r)):n.memoizedState!==null?(n.child=e.child,n.flags|=128,null):(o=r.fallback,l=n.mode,r=fl({mode:"visible",children:r.children},l,0,null),o=_n(o,l,i,null),o.flags|=2,r.return=n,o.return=n,r.sibling=o,n.child=r,n.mode&1&&nt(n,e.child,null,i),n.child.memoizedState=Lo(i),n.memoizedState=zo,o);if(!(n.mode&1))return mr(e,n,i,null);if(l.data==="$!"){if(r=l.nextSibling&&l.nextSibling.dataset,r)var u=r.dgst;return r=u,o=Error(y(419)),r=Vl(o,r,void 0),mr(e,n,i,r)}if(u=(i&e.childLanes)!==0,ce||u){if(r=J,r!==null){switch(i&-i){case 4:l=2;break;case 16:l=8;break;case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:l=32;break;case 536870912:l=268435456;break;default:l=0}l=l&(r.suspendedLanes|i)?0:l,l!==0&&l!==o.retryLane&&(o.retryLane=l,Ye(e,l),je(r,e,l,-1))}return Pi(),r=Vl(Error(y(421))),mr(e,n,i,r)}return l.data==="$?"?(n.flags|=128,n.child=e.child,n=Sd.bind(null,e),l._reactRetry=n,null):(e=o.treeContext,he=un(l.nextSibling),ve=n,U=!0,Re=null,e!==null&&(Se[ke++]=Be,Se[ke++]=He,Se[ke++]=Pn,Be=e.id,He=e.overflow,Pn=n),n=ki(n,r.children),n.flags|=4096,n)}function Ru(e,n,t){e.lanes|=n;var r=e.alternate;r!==null&&(r.lanes|=n),Eo(e.return,n,t)}function Bl(e,n,t,r,l){var o=e.memoizedState;o===null?e.memoizedState={isBackwards:n,rendering:null,renderingStartTime:0,last:r,tail:t,tailMode:l}:(o.isBackwards=n,o.rendering=null,o.renderingStartTime=0,o.last=r,o.tail=t,o.tailMode=l)}function Da(e,n,t){var r=n.pendingProps,l=r.revealOrder,o=r.tail;if(oe(e,n,r.children,t),r=$.current,r&2)r=r&1|2,n.flags|=128;else{if(e!==null&&e.flags&128)e:for(e=n.child;e!==null;){if(e.tag===13)e.memoizedState!==null&&Ru(e,t,n);else if(e.tag===19)Ru(e,t,n);else if(e.child!==null){e.child.return=e,e=e.child;continue}if(e===n)break e;for(;e.sibling===null;){if(e.return===null||e.return===n)break e;e=e.return}e.sibling.return=e.return,e=e.sibling}r&=1}if(M($,r),!(n.mode&1))n.memoizedState=null;else switch(l){case"forwards":for(t=n.child,l=null;t!==null;)e=t.alternate,e!==null&&Kr(e)===null&&(l=t),t=t.sibling;t=l,t===null?(l=n.child,n.child=null):(l=t.sibling,t.sibling=null),Bl(n,!1,l,t,o);break;case"backwards":for(t=null,l=n.child,n.child=null;l!==null;){if(e=l.alternate,e!==null&&Kr(e)===null){n.child=l;break}e=l.sibling,l.sibling=t,t=l,l=e}Bl(n,!0,t,null,o);break;case"together":Bl(n,!1,null,null,void 0);break;default:n.memoizedState=null}return n.child}function Nr(e,n){!(n.mode&1)&&e!==null&&(e.alternate=null,n.alternate=null,n.flags|=2)}function Xe(e,n,t){if(e!==null&&(n.dependencies=e.dependencies),Ln|=n.lanes,!(t&n.childLanes))return null;if(e!==null&&n.child!==e.child)throw Error(y(153));if(n.child!==null){for(e=n.child,t=fn(e,e.pendingProps),n.child=t,t.return=n;e.sibling!==null;)e=e.sibling,t=t.sibling=fn(e,e.pendingProps),t.return=n;t.sibling=null}return n.child}function sd(e,n,t){switch(n.tag){case 3:ja(n),et();break;case 5:sa(n);break;case 1:de(n.type)&&Ar(n);break;case 4:pi(n,n.stateNode.containerInfo);break;case 10:var r=n.type._context,l=n.memoizedProps.value;M(Hr,r._currentValue),r._currentValue=l;break;case 13:if(r=n.memoizedState,r!==null)return r.dehydrated!==null?(M($,$.current&1),n.flags|=128,null):t&n.child.childLanes?Ma(e,n,t):(M($,$.current&1),e=Xe(e,n,t),e!==null?e.sibling:null);M($,$.current&1);break;case 19:if(r=(t&n.childLanes)!==0,e.flags&128){if(r)return Da(e,n,t);n.flags|=128}if(l=n.memoizedState,l!==null&&(l.rendering=null,l.tail=null,l.lastEffect=null),M($,$.current),r)break;return null;case 22:case 23:return n.lanes=0,Ra(e,n,t)}return Xe(e,n,t)}var Ia,To,Fa,Ua;Ia=function(e,n){for(var t=n.child;t!==null;){if(t.tag===5||t.tag===6)e.appendChild(t.stateNode);else if(t.tag!==4&&t.child!==null){t.child.return=t,t=t.child;continue}if(t===n)break;for(;t.sibling===null;){if(t.return===null||t.return===n)return;t=t.return}t.sibling.return=t.return,t=t.sibling}};To=function(){};Fa=function(e,n,t,r){var l=e.memoizedProps;if(l!==r){e=n.stateNode,xn($e.current);var o=null;switch(t){case"input":l=Jl(e,l),r=Jl(e,r),o=[];break;case"select":l=V({},l,{value:void 0}),r=V({},r,{value:void 0}),o=[];break;case"textarea":l=eo(e,l),r=eo(e,r),o=[];break;default:typeof l.onClick!="function"&&typeof r.onClick=="function"&&(e.onclick=Ur)}to(t,r);var i;t=null;for(c in l)if(!r.hasOwnProperty(c)&&l.hasOwnProperty(c)&&l[c]!=null)if(c==="style"){var u=l[c];for(i in u)u.hasOwnProperty(i)&&(t||(t={}),t[i]="")}else c!=="dangerouslySetInnerHTML"&&c!=="children"&&c!=="suppressContentEditableWarning"&&c!=="suppressHydrationWarning"&&c!=="autoFocus"&&(Rt.hasOwnProperty(c)?o||(o=[]):(o=o||[]).push(c,null));for(c in r){var s=r[c];if(u=l!=null?l[c]:void 0,r.hasOwnProperty(c)&&s!==u&&(s!=null||u!=null))if(c==="style")if(u){for(i in u)!u.hasOwnProperty(i)||s&&s.hasOwnProperty(i)||(t||(t={}),t[i]="");for(i in s)s.hasOwnProperty(i)&&u[i]!==s[i]&&(t||(t={}),t[i]=s[i])}else t||(o||(o=[]),o.push(c,t)),t=s;else c==="dangerouslySetInnerHTML"?(s=s?s.__html:void 0,u=u?u.__html:void 0,s!=null&&u!==s&&(o=o||[]).push(c,s)):c==="children"?typeof s!="string"&&typeof s!="number"||(o=o||[]).push(c,""+s):c!=="suppressContentEditableWarning"&&c!=="suppressHydrationWarning"&&(Rt.hasOwnProperty(c)?(s!=null&&c==="onScroll"&&D("scroll",e),o||u===s||(o=[])):(o=o||[]).push(c,s))}t&&(o=o||[]).push("style",t);var c=o;(n.updateQueue=c)&&(n.flags|=4)}};Ua=function(e,n,t,r){t!==r&&(n.flags|=4)};function vt(e,n){if(!U)switch(e.tailMode){case"hidden":n=e.tail;for(var t=null;n!==null;)n.alternate!==null&&(t=n),n=n.sibling;t===null?e.tail=null:t.sibling=null;break;case"collapsed":t=e.tail;for(var r=null;t!==null;)t.alternate!==null&&(r=t),t=t.sibling;r===null?n||e.tail===null?e.tail=null:e.tail.sibling=null:r.sibling=null}}function te(e){var n=e.alternate!==null&&e.alternate.child===e.child,t=0,r=0;if(n)for(var l=e.child;l!==null;)t|=l.lanes|l.childLanes,r|=l.subtreeFlags&14680064,r|=l.flags&14680064,l.return=e,l=l.sibling;else for(l=e.child;l!==null;)t|=l.lanes|l.childLanes,r|=l.subtreeFlags,r|=l.flags,l.return=e,l=l.sibling;return e.subtreeFlags|=r,e.childLanes=t,n}function ad(e,n,t){var r=n.pendingProps;switch(ii(n),n.tag){case 2:case 16:case 15:case 0:case 11:case 7:case 8:case 12:case 9:case 14:return te(n),null;case 1:return de(n.type)&&$r(),te(n),null;case 3:return r=n.stateNode,tt(),I(fe),I(le),hi(),r.pendingContext&&(r.context=r.pendingContext,r.pendingContext=null),(e===null||e.child===null)&&(dr(n)?n.flags|=4:e===null||e.memoizedState.isDehydrated&&!(n.flags&256)||(n.flags|=1024,Re!==null&&(Uo(Re),Re=null))),To(e,n),te(n),null;case 5:mi(n);var l=xn(Ht.current);if(t=n.type,e!==null&&n.stateNode!=null)Fa(e,n,t,r,l),e.ref!==n.ref&&(n.flags|=512,n.flags|=2097152);else{if(!r){if(n.stateNode===null)throw Error(y(166));return te(n),null}if(e=xn($e.current),dr(n)){r=n.stateNode,t=n.type;var o=n.memoizedProps;switch(r[Fe]=n,r[Vt]=o,e=(n.mode&1)!==0,t){case"dialog":D("cancel",r),D("close",r);break;case"iframe":case"object":case"embed":D("load",r);break;case"video":case"audio":for(l=0;l<kt.length;l++)D(kt[l],r);break;case"source":D("error",r);break;case"img":case"image":case"link":D("error",r),D("load",r);break;case"details":D("toggle",r);break;case"input":Ai(r,o),D("invalid",r);break;case"select":r._wrapperState={wasMultiple:!!o.multiple},D("invalid",r);break;case"textarea":Bi(r,o),D("invalid",r)}to(t,o),l=null;for(var i in o)if(o.hasOwnProperty(i)){var u=o[i];i==="children"?typeof u=="string"?r.textContent!==u&&(o.suppressHydrationWarning!==!0&&fr(r.textContent,u,e),l=["children",u]):typeof u=="number"&&r.textContent!==""+u&&(o.suppressHydrationWarning!==!0&&fr(r.textContent,u,e),l=["children",""+u]):Rt.hasOwnProperty(i)&&u!=null&&i==="onScroll"&&D("scroll",r)}switch(t){case"input":rr(r),Vi(r,o,!0);break;case"textarea":rr(r),Hi(r);break;case"select":case"option":break;default:typeof o.onClick=="function"&&(r.onclick=Ur)}r=l,n.updateQueue=r,r!==null&&(n.flags|=4)}else{i=l.nodeType===9?l:l.ownerDocument,e==="http://www.w3.org/1999/xhtml"&&(e=fs(t)),e==="http://www.w3.org/1999/xhtml"?t==="script"?(e=i.createElement("div"),e.innerHTML="<script><\/script>",e=e.removeChild(e.firstChild)):typeof r.is=="string"?e=i.createElement(t,{is:r.is}):(e=i.createElement(t),t==="select"&&(i=e,r.multiple?i.multiple=!0:r.size&&(i.size=r.size))):e=i.createElementNS(e,t),e[Fe]=n,e[Vt]=r,Ia(e,n,!1,!1),n.stateNode=e;e:{switch(i=ro(t,r),t){case"dialog":D("cancel",e),D("close",e),l=r;break;case"iframe":case"object":case"embed":D("load",e),l=r;break;case"video":case"audio":for(l=0;l<kt.length;l++)D(kt[l],e);l=r;break;case"source":D("error",e),l=r;break;case"img":case"image":case"link":D("error",e),D("load",e),l=r;break;case"details":D("toggle",e),l=r;break;case"input":Ai(e,r),l=Jl(e,r),D("invalid",e);break;case"option":l=r;break;case"select":e._wrapperState={wasMultiple:!!r.multiple},l=V({},r,{value:void 0}),D("invalid",e);break;case"textarea":Bi(e,r),l=eo(e,r),D("invalid",e);break;default:l=r}to(t,l),u=l;for(o in u)if(u.hasOwnProperty(o)){var s=u[o];o==="style"?ms(e,s):o==="dangerouslySetInnerHTML"?(s=s?s.__html:void 0,s!=null&&ds(e,s)):o==="children"?typeof s=="string"?(t!=="textarea"||s!=="")&&Ot(e,s):typeof s=="number"&&Ot(e,""+s):o!=="suppressContentEditableWarning"&&o!=="suppressHydrationWarning"&&o!=="autoFocus"&&(Rt.hasOwnProperty(o)?s!=null&&o==="onScroll"&&D("scroll",e):s!=null&&Qo(e,o,s,i))}switch(t){case"input":rr(e),Vi(e,r,!1);break;case"textarea":rr(e),Hi(e);break;case"option":r.value!=null&&e.setAttribute("value",""+dn(r.value));break;case"select":e.multiple=!!r.multiple,o=r.value,o!=null?Kn(e,!!r.multiple,o,!1):r.defaultValue!=null&&Kn(e,!!r.multiple,r.defaultValue,!0);break;default:typeof l.onClick=="function"&&(e.onclick=Ur)}switch(t){case"button":case"input":case"select":case"textarea":r=!!r.autoFocus;break e;case"img":r=!0;break e;default:r=!1}}r&&(n.flags|=4)}n.ref!==null&&(n.flags|=512,n.flags|=2097152)}return te(n),null;case 6:if(e&&n.stateNode!=null)Ua(e,n,e.memoizedProps,r);else{if(typeof r!="string"&&n.stateNode===null)throw Error(y(166));if(t=xn(Ht.current),xn($e.current),dr(n)){if(r=n.stateNode,t=n.memoizedProps,r[Fe]=n,(o=r.nodeValue!==t)&&(e=ve,e!==null))switch(e.tag){case 3:fr(r.nodeValue,t,(e.mode&1)!==0);break;case 5:e.memoizedProps.suppressHydrationWarning!==!0&&fr(r.nodeValue,t,(e.mode&1)!==0)}o&&(n.flags|=4)}else r=(t.nodeType===9?t:t.ownerDocument).createTextNode(r),r[Fe]=n,n.stateNode=r}return te(n),null;case 13:if(I($),r=n.memoizedState,e===null||e.memoizedState!==null&&e.memoizedState.dehydrated!==null){if(U&&he!==null&&n.mode&1&&!(n.flags&128))na(),et(),n.flags|=98560,o=!1;else if(o=dr(n),r!==null&&r.dehydrated!==null){if(e===null){if(!o)throw Error(y(318));if(o=n.memoizedState,o=o!==null?o.dehydrated:null,!o)throw Error(y(317));o[Fe]=n}else et(),!(n.flags&128)&&(n.memoizedState=null),n.flags|=4;te(n),o=!1}else Re!==null&&(Uo(Re),Re=null),o=!0;if(!o)return n.flags&65536?n:null}return n.flags&128?(n.lanes=t,n):(r=r!==null,r!==(e!==null&&e.memoizedState!==null)&&r&&(n.child.flags|=8192,n.mode&1&&(e===null||$.current&1?X===0&&(X=3):Pi())),n.updateQueue!==null&&(n.flags|=4),te(n),null);case 4:return tt(),To(e,n),e===null&&$t(n.stateNode.containerInfo),te(n),null;case 10:return ci(n.type._context),te(n),null;case 17:return de(n.type)&&$r(),te(n),null;case 19:if(I($),o=n.memoizedState,o===null)return te(n),null;if(r=(n.flags&128)!==0,i=o.rendering,i===null)if(r)vt(o,!1);else{if(X!==0||e!==null&&e.flags&128)for(e=n.child;e!==null;){if(i=Kr(e),i!==null){for(n.flags|=128,vt(o,!1),r=i.updateQueue,r!==null&&(n.updateQueue=r,n.flags|=4),n.subtreeFlags=0,r=t,t=n.child;t!==null;)o=t,e=r,o.flags&=14680066,i=o.alternate,i===null?(o.childLanes=0,o.lanes=e,o.child=null,o.subtreeFlags=0,o.memoizedProps=null,o.memoizedState=null,o.updateQueue=null,o.dependencies=null,o.stateNode=null):(o.childLanes=i.childLanes,o.lanes=i.lanes,o.child=i.child,o.subtreeFlags=0,o.deletions=null,o.memoizedProps=i.memoizedProps,o.memoizedState=i.memoizedState,o.updateQueue=i.updateQueue,o.type=i.type,e=i.dependencies,o.dependencies=e===null?null:{lanes:e.lanes,firstContext:e.firstContext}),t=t.sibling;return M($,$.current&1|2),n.child}e=e.sibling}o.tail!==null&&Q()>lt&&(n.flags|=128,r=!0,vt(o,!1),n.lanes=4194304)}else{if(!r)if(e=Kr(i),e!==null){if(n.flags|=128,r=!0,t=e.updateQueue,t!==null&&(n.updateQueue=t,n.flags|=4),vt(o,!0),o.tail===null&&o.tailMode==="hidden"&&!i.alternate&&!U)return te(n),null}else 2*Q()-o.renderingStartTime>lt&&t!==1073741824&&(n.flags|=128,r=!0,vt(o,!1),n.lanes=4194304);o.isBackwards?(i.sibling=n.child,n.child=i):(t=o.last,t!==null?t.sibling=i:n.child=i,o.last=i)}return o.tail!==null?(n=o.tail,o.rendering=n,o.tail=n.sibling,o.renderingStartTime=Q(),n.sibling=null,t=$.current,M($,r?t&1|2:t&1),n):(te(n),null);case 22:case 23:return Ni(),r=n.memoizedState!==null,e!==null&&e.memoizedState!==null!==r&&(n.flags|=8192),r&&n.mode&1?me&1073741824&&(te(n),n.subtreeFlags&6&&(n.flags|=8192)):te(n),null;case 24:return null;case 25:return null}throw Error(y(156,n.tag))}function cd(e,n){switch(ii(n),n.tag){case 1:return de(n.type)&&$r(),e=n.flags,e&65536?(n.flags=e&-65537|128,n):null;case 3:return tt(),I(fe),I(le),hi(),e=n.flags,e&65536&&!(e&128)?(n.flags=e&-65537|128,n):null;case 5:return mi(n),null;case 13:if(I($),e=n.memoizedState,e!==null&&e.dehydrated!==null){if(n.alternate===null)throw Error(y(340));et()}return e=n.flags,e&65536?(n.flags=e&-65537|128,n):null;case 19:return I($),null;case 4:return tt(),null;case 10:return ci(n.type._context),null;case 22:case 23:return Ni(),null;case 24:return null;default:return null}}var hr=!1,re=!1,fd=typeof WeakSet=="function"?WeakSet:Set,k=null;function Wn(e,n){var t=e.ref;if(t!==null)if(typeof t=="function")try{t(null)}catch(r){B(e,n,r)}else t.current=null}function Ro(e,n,t){try{t()}catch(r){B(e,n,r)}}var Ou=!1;function dd(e,n){if(mo=Dr,e=Bs(),li(e)){if("selectionStart"in e)var t={start:e.selectionStart,end:e.selectionEnd};else e:{t=(t=e.ownerDocument)&&t.defaultView||window;var r=t.getSelection&&t.getSelection();if(r&&r.rangeCount!==0){t=r.anchorNode;var l=r.anchorOffset,o=r.focusNode;r=r.focusOffset;try{t.nodeType,o.nodeType}catch{t=null;break e}var i=0,u=-1,s=-1,c=0,h=0,m=e,p=null;n:for(;;){for(var g;m!==t||l!==0&&m.nodeType!==3||(u=i+l),m!==o||r!==0&&m.nodeType!==3||(s=i+r),m.nodeType===3&&(i+=m.nodeValue.length),(g=m.firstChild)!==null;)p=m,m=g;for(;;){if(m===e)break n;if(p===t&&++c===l&&(u=i),p===o&&++h===r&&(s=i),(g=m.nextSibling)!==null)break;m=p,p=m.parentNode}m=g}t=u===-1||s===-1?null:{start:u,end:s}}else t=null}t=t||{start:0,end:0}}else t=null;for(ho={focusedElem:e,selectionRange:t},Dr=!1,k=n;k!==null;)if(n=k,e=n.child,(n.subtreeFlags&1028)!==0&&e!==null)e.return=n,k=e;else for(;k!==null;){n=k;try{var w=n.alternate;if(n.flags&1024)switch(n.tag){case 0:case 11:case 15:break;case 1:if(w!==null){var S=w.memoizedProps,F=w.memoizedState,f=n.stateNode,a=f.getSnapshotBeforeUpdate(n.elementType===n.type?S:ze(n.type,S),F);f.__reactInternalSnapshotBeforeUpdate=a}break;case 3:var d=n.stateNode.containerInfo;d.nodeType===1?d.textContent="":d.nodeType===9&&d.documentElement&&d.removeChild(d.documentElement);break;case 5:case 6:case 4:case 17:break;default:throw Error(y(163))}}catch(v){B(n,n.return,v)}if(e=n.sibling,e!==null){e.return=n.return,k=e;break}k=n.return}return w=Ou,Ou=!1,w}function zt(e,n,t){var r=n.updateQueue;if(r=r!==null?r.lastEffect:null,r!==null){var l=r=r.next;do{if((l.tag&e)===e){var o=l.destroy;l.destroy=void 0,o!==void 0&&Ro(n,t,o)}l=l.next}while(l!==r)}}function al(e,n){if(n=n.updateQueue,n=n!==null?n.lastEffect:null,n!==null){var t=n=n.next;do{if((t.tag&e)===e){var r=t.create;t.destroy=r()}t=t.next}while(t!==n)}}function Oo(e){var n=e.ref;if(n!==null){var t=e.stateNode;switch(e.tag){case 5:e=t;break;default:e=t}typeof n=="function"?n(e):n.current=e}}function $a(e){var n=e.alternate;n!==null&&(e.alternate=null,$a(n)),e.child=null,e.deletions=null,e.sibling=null,e.tag===5&&(n=e.stateNode,n!==null&&(delete n[Fe],delete n[Vt],delete n[go],delete n[Xf],delete n[Gf])),e.stateNode=null,e.return=null,e.dependencies=null,e.memoizedProps=null,e.memoizedState=null,e.pendingProps=null,e.stateNode=null,e.updateQueue=null}function Aa(e){return e.tag===5||e.tag===3||e.tag===4}function ju(e){e:for(;;){for(;e.sibling===null;){if(e.return===null||Aa(e.return))return null;e=e.return}for(e.sibling.return=e.return,e=e.sibling;e.tag!==5&&e.tag!==6&&e.tag!==18;){if(e.flags&2||e.child===null||e.tag===4)continue e;e.child.return=e,e=e.child}if(!(e.flags&2))return e.stateNode}}function jo(e,n,t){var r=e.tag;if(r===5||r===6)e=e.stateNode,n?t.nodeType===8?t.parentNode.insertBefore(e,n):t.insertBefore(e,n):(t.nodeType===8?(n=t.parentNode,n.insertBefore(e,t)):(n=t,n.appendChild(e)),t=t._reactRootContainer,t!=null||n.onclick!==null||(n.onclick=Ur));else if(r!==4&&(e=e.child,e!==null))for(jo(e,n,t),e=e.sibling;e!==null;)jo(e,n,t),e=e.sibling}function Mo(e,n,t){var r=e.tag;if(r===5||r===6)e=e.stateNode,n?t.insertBefore(e,n):t.appendChild(e);else if(r!==4&&(e=e.child,e!==null))for(Mo(e,n,t),e=e.sibling;e!==null;)Mo(e,n,t),e=e.sibling}var q=null,Te=!1;function Ze(e,n,t){for(t=t.child;t!==null;)Va(e,n,t),t=t.sibling}function Va(e,n,t){if(Ue&&typeof Ue.onCommitFiberUnmount=="function")try{Ue.onCommitFiberUnmount(nl,t)}catch{}switch(t.tag){case 5:re||Wn(t,n);case 6:var r=q,l=Te;q=null,Ze(e,n,t),q=r,Te=l,q!==null&&(Te?(e=q,t=t.stateNode,e.nodeType===8?e.parentNode.removeChild(t):e.removeChild(t)):q.removeChild(t.stateNode));break;case 18:q!==null&&(Te?(e=q,t=t.stateNode,e.nodeType===8?Dl(e.parentNode,t):e.nodeType===1&&Dl(e,t),It(e)):Dl(q,t.stateNode));break;case 4:r=q,l=Te,q=t.stateNode.containerInfo,Te=!0,Ze(e,n,t),q=r,Te=l;break;case 0:case 11:case 14:case 15:if(!re&&(r=t.updateQueue,r!==null&&(r=r.lastEffect,r!==null))){l=r=r.next;do{var o=l,i=o.destroy;o=o.tag,i!==void 0&&(o&2||o&4)&&Ro(t,n,i),l=l.next}while(l!==r)}Ze(e,n,t);break;case 1:if(!re&&(Wn(t,n),r=t.stateNode,typeof r.componentWillUnmount=="function"))try{r.props=t.memoizedProps,r.state=t.memoizedState,r.componentWillUnmount()}catch(u){B(t,n,u)}Ze(e,n,t);break;case 21:Ze(e,n,t);break;case 22:t.mode&1?(re=(r=re)||t.memoizedState!==null,Ze(e,n,t),re=r):Ze(e,n,t);break;default:Ze(e,n,t)}}function Mu(e){var n=e.updateQueue;if(n!==null){e.updateQueue=null;var t=e.stateNode;t===null&&(t=e.stateNode=new fd),n.forEach(function(r){var l=kd.bind(null,e,r);t.has(r)||(t.add(r),r.then(l,l))})}}function Pe(e,n){var t=n.deletions;if(t!==null)for(var r=0;r<t.length;r++){var l=t[r];try{var o=e,i=n,u=i;e:for(;u!==null;){switch(u.tag){case 5:q=u.stateNode,Te=!1;break e;case 3:q=u.stateNode.containerInfo,Te=!0;break e;case 4:q=u.stateNode.containerInfo,Te=!0;break e}u=u.return}if(q===null)throw Error(y(160));Va(o,i,l),q=null,Te=!1;var s=l.alternate;s!==null&&(s.return=null),l.return=null}catch(c){B(l,n,c)}}if(n.subtreeFlags&12854)for(n=n.child;n!==null;)Ba(n,e),n=n.sibling}function Ba(e,n){var t=e.alternate,r=e.flags;switch(e.tag){case 0:case 11:case 14:case 15:if(Pe(n,e),De(e),r&4){try{zt(3,e,e.return),al(3,e)}catch(S){B(e,e.return,S)}try{zt(5,e,e.return)}catch(S){B(e,e.return,S)}}break;case 1:Pe(n,e),De(e),r&512&&t!==null&&Wn(t,t.return);break;case 5:if(Pe(n,e),De(e),r&512&&t!==null&&Wn(t,t.return),e.flags&32){var l=e.stateNode;try{Ot(l,"")}catch(S){B(e,e.return,S)}}if(r&4&&(l=e.stateNode,l!=null)){var o=e.memoizedProps,i=t!==null?t.memoizedProps:o,u=e.type,s=e.updateQueue;if(e.updateQueue=null,s!==null)try{u==="input"&&o.type==="radio"&&o.name!=null&&as(l,o),ro(u,i);var c=ro(u,o);for(i=0;i<s.length;i+=2){var h=s[i],m=s[i+1];h==="style"?ms(l,m):h==="dangerouslySetInnerHTML"?ds(l,m):h==="children"?Ot(l,m):Qo(l,h,m,c)}switch(u){case"input":ql(l,o);break;case"textarea":cs(l,o);break;case"select":var p=l._wrapperState.wasMultiple;l._wrapperState.wasMultiple=!!o.multiple;var g=o.value;g!=null?Kn(l,!!o.multiple,g,!1):p!==!!o.multiple&&(o.defaultValue!=null?Kn(l,!!o.multiple,o.defaultValue,!0):Kn(l,!!o.multiple,o.multiple?[]:"",!1))}l[Vt]=o}catch(S){B(e,e.return,S)}}break;case 6:if(Pe(n,e),De(e),r&4){if(e.stateNode===null)throw Error(y(162));l=e.stateNode,o=e.memoizedProps;try{l.nodeValue=o}catch(S){B(e,e.return,S)}}break;case 3:if(Pe(n,e),De(e),r&4&&t!==null&&t.memoizedState.isDehydrated)try{It(n.containerInfo)}catch(S){B(e,e.return,S)}break;case 4:Pe(n,e),De(e);break;case 13:Pe(n,e),De(e),l=e.child,l.flags&8192&&(o=l.memoizedState!==null,l.stateNode.isHidden=o,!o||l.alternate!==null&&l.alternate.memoizedState!==null||(Ci=Q())),r&4&&Mu(e);break;case 22:if(h=t!==null&&t.memoizedState!==null,e.mode&1?(re=(c=re)||h,Pe(n,e),re=c):Pe(n,e),De(e),r&8192){if(c=e.memoizedState!==null,(e.stateNode.isHidden=c)&&!h&&e.mode&1)for(k=e,h=e.child;h!==null;){for(m=k=h;k!==null;){switch(p=k,g=p.child,p.tag){case 0:case 11:case 14:case 15:zt(4,p,p.return);break;case 1:Wn(p,p.return);var w=p.stateNode;if(typeof w.componentWillUnmount=="function"){r=p,t=p.return;try{n=r,w.props=n.memoizedProps,w.state=n.memoizedState,w.componentWillUnmount()}catch(S){B(r,t,S)}}break;case 5:Wn(p,p.return);break;case 22:if(p.memoizedState!==null){Iu(m);continue}}g!==null?(g.return=p,k=g):Iu(m)}h=h.sibling}e:for(h=null,m=e;;){if(m.tag===5){if(h===null){h=m;try{l=m.stateNode,c?(o=l.style,typeof o.setProperty=="function"?o.setProperty("display","none","important"):o.display="none"):(u=m.stateNode,s=m.memoizedProps.style,i=s!=null&&s.hasOwnProperty("display")?s.display:null,u.style.display=ps("display",i))}catch(S){B(e,e.return,S)}}}else if(m.tag===6){if(h===null)try{m.stateNode.nodeValue=c?"":m.memoizedProps}catch(S){B(e,e.return,S)}}else if((m.tag!==22&&m.tag!==23||m.memoizedState===null||m===e)&&m.child!==null){m.child.return=m,m=m.child;continue}if(m===e)break e;for(;m.sibling===null;){if(m.return===null||m.return===e)break e;h===m&&(h=null),m=m.return}h===m&&(h=null),m.sibling.return=m.return,m=m.sibling}}break;case 19:Pe(n,e),De(e),r&4&&Mu(e);break;case 21:break;default:Pe(n,e),De(e)}}function De(e){var n=e.flags;if(n&2){try{e:{for(var t=e.return;t!==null;){if(Aa(t)){var r=t;break e}t=t.return}throw Error(y(160))}switch(r.tag){case 5:var l=r.stateNode;r.flags&32&&(Ot(l,""),r.flags&=-33);var o=ju(e);Mo(e,o,l);break;case 3:case 4:var i=r.stateNode.containerInfo,u=ju(e);jo(e,u,i);break;default:throw Error(y(161))}}catch(s){B(e,e.return,s)}e.flags&=-3}n&4096&&(e.flags&=-4097)}function pd(e,n,t){k=e,Ha(e)}function Ha(e,n,t){for(var r=(e.mode&1)!==0;k!==null;){var l=k,o=l.child;if(l.tag===22&&r){var i=l.memoizedState!==null||hr;if(!i){var u=l.alternate,s=u!==null&&u.memoizedState!==null||re;u=hr;var c=re;if(hr=i,(re=s)&&!c)for(k=l;k!==null;)i=k,s=i.child,i.tag===22&&i.memoizedState!==null?Fu(l):s!==null?(s.return=i,k=s):Fu(l);for(;o!==null;)k=o,Ha(o),o=o.sibling;k=l,hr=u,re=c}Du(e)}else l.subtreeFlags&8772&&o!==null?(o.return=l,k=o):Du(e)}}function Du(e){for(;k!==null;){var n=k;if(n.flags&8772){var t=n.alternate;try{if(n.flags&8772)switch(n.tag){case 0:case 11:case 15:re||al(5,n);break;case 1:var r=n.stateNode;if(n.flags&4&&!re)if(t===null)r.componentDidMount();else{var l=n.elementType===n.type?t.memoizedProps:ze(n.type,t.memoizedProps);r.componentDidUpdate(l,t.memoizedState,r.__reactInternalSnapshotBeforeUpdate)}var o=n.updateQueue;o!==null&&gu(n,o,r);break;case 3:var i=n.updateQueue;if(i!==null){if(t=null,n.child!==null)switch(n.child.tag){case 5:t=n.child.stateNode;break;case 1:t=n.child.stateNode}gu(n,i,t)}break;case 5:var u=n.stateNode;if(t===null&&n.flags&4){t=u;var s=n.memoizedProps;switch(n.type){case"button":case"input":case"select":case"textarea":s.autoFocus&&t.focus();break;case"img":s.src&&(t.src=s.src)}}break;case 6:break;case 4:break;case 12:break;case 13:if(n.memoizedState===null){var c=n.alternate;if(c!==null){var h=c.memoizedState;if(h!==null){var m=h.dehydrated;m!==null&&It(m)}}}break;case 19:case 17:case 21:case 22:case 23:case 25:break;default:throw Error(y(163))}re||n.flags&512&&Oo(n)}catch(p){B(n,n.return,p)}}if(n===e){k=null;break}if(t=n.sibling,t!==null){t.return=n.return,k=t;break}k=n.return}}function Iu(e){for(;k!==null;){var n=k;if(n===e){k=null;break}var t=n.sibling;if(t!==null){t.return=n.return,k=t;break}k=n.return}}function Fu(e){for(;k!==null;){var n=k;try{switch(n.tag){case 0:case 11:case 15:var t=n.return;try{al(4,n)}catch(s){B(n,t,s)}break;case 1:var r=n.stateNode;if(typeof r.componentDidMount=="function"){var l=n.return;try{r.componentDidMount()}catch(s){B(n,l,s)}}var o=n.return;try{Oo(n)}catch(s){B(n,o,s)}break;case 5:var i=n.return;try{Oo(n)}catch(s){B(n,i,s)}}}catch(s){B(n,n.return,s)}if(n===e){k=null;break}var u=n.sibling;if(u!==null){u.return=n.return,k=u;break}k=n.return}}var md=Math.ceil,Gr=Ge.ReactCurrentDispatcher,Ei=Ge.ReactCurrentOwner,xe=Ge.ReactCurrentBatchConfig,R=0,J=null,K=null,b=0,me=0,Qn=hn(0),X=0,Yt=null,Ln=0,cl=0,xi=0,Lt=null,ae=null,Ci=0,lt=1/0,Ae=null,Zr=!1,Do=null,an=null,vr=!1,tn=null,Jr=0,Tt=0,Io=null,Pr=-1,zr=0;function ie(){return R&6?Q():Pr!==-1?Pr:Pr=Q()}function cn(e){return e.mode&1?R&2&&b!==0?b&-b:Jf.transition!==null?(zr===0&&(zr=Ns()),zr):(e=j,e!==0||(e=window.event,e=e===void 0?16:js(e.type)),e):1}function je(e,n,t,r){if(50<Tt)throw Tt=0,Io=null,Error(y(185));Gt(e,t,r),(!(R&2)||e!==J)&&(e===J&&(!(R&2)&&(cl|=t),X===4&&en(e,b)),pe(e,r),t===1&&R===0&&!(n.mode&1)&&(lt=Q()+500,il&&vn()))}function pe(e,n){var t=e.callbackNode;Zc(e,n);var r=Mr(e,e===J?b:0);if(r===0)t!==null&&Ki(t),e.callbackNode=null,e.callbackPriority=0;else if(n=r&-r,e.callbackPriority!==n){if(t!=null&&Ki(t),n===1)e.tag===0?Zf(Uu.bind(null,e)):qs(Uu.bind(null,e)),Kf(function(){!(R&6)&&vn()}),t=null;else{switch(Ps(r)){case 1:t=Zo;break;case 4:t=Cs;break;case 16:t=jr;break;case 536870912:t=_s;break;default:t=jr}t=Ja(t,Wa.bind(null,e))}e.callbackPriority=n,e.callbackNode=t}}function Wa(e,n){if(Pr=-1,zr=0,R&6)throw Error(y(327));var t=e.callbackNode;if(Jn()&&e.callbackNode!==t)return null;var r=Mr(e,e===J?b:0);if(r===0)return null;if(r&30||r&e.expiredLanes||n)n=qr(e,r);else{n=r;var l=R;R|=2;var o=Ka();(J!==e||b!==n)&&(Ae=null,lt=Q()+500,Cn(e,n));do try{yd();break}catch(u){Qa(e,u)}while(1);ai(),Gr.current=o,R=l,K!==null?n=0:(J=null,b=0,n=X)}if(n!==0){if(n===2&&(l=so(e),l!==0&&(r=l,n=Fo(e,l))),n===1)throw t=Yt,Cn(e,0),en(e,r),pe(e,Q()),t;if(n===6)en(e,r);else{if(l=e.current.alternate,!(r&30)&&!hd(l)&&(n=qr(e,r),n===2&&(o=so(e),o!==0&&(r=o,n=Fo(e,o))),n===1))throw t=Yt,Cn(e,0),en(e,r),pe(e,Q()),t;switch(e.finishedWork=l,e.finishedLanes=r,n){case 0:case 1:throw Error(y(345));case 2:Sn(e,ae,Ae);break;case 3:if(en(e,r),(r&130023424)===r&&(n=Ci+500-Q(),10<n)){if(Mr(e,0)!==0)break;if(l=e.suspendedLanes,(l&r)!==r){ie(),e.pingedLanes|=e.suspendedLanes&l;break}e.timeoutHandle=yo(Sn.bind(null,e,ae,Ae),n);break}Sn(e,ae,Ae);break;case 4:if(en(e,r),(r&4194240)===r)break;for(n=e.eventTimes,l=-1;0<r;){var i=31-Oe(r);o=1<<i,i=n[i],i>l&&(l=i),r&=~o}if(r=l,r=Q()-r,r=(120>r?120:480>r?480:1080>r?1080:1920>r?1920:3e3>r?3e3:4320>r?4320:1960*md(r/1960))-r,10<r){e.timeoutHandle=yo(Sn.bind(null,e,ae,Ae),r);break}Sn(e,ae,Ae);break;case 5:Sn(e,ae,Ae);break;default:throw Error(y(329))}}}return pe(e,Q()),e.callbackNode===t?Wa.bind(null,e):null}function Fo(e,n){var t=Lt;return e.current.memoizedState.isDehydrated&&(Cn(e,n).flags|=256),e=qr(e,n),e!==2&&(n=ae,ae=t,n!==null&&Uo(n)),e}function Uo(e){ae===null?ae=e:ae.push.apply(ae,e)}function hd(e){for(var n=e;;){if(n.flags&16384){var t=n.updateQueue;if(t!==null&&(t=t.stores,t!==null))for(var r=0;r<t.length;r++){var l=t[r],o=l.getSnapshot;l=l.value;try{if(!Me(o(),l))return!1}catch{return!1}}}if(t=n.child,n.subtreeFlags&16384&&t!==null)t.return=n,n=t;else{if(n===e)break;for(;n.sibling===null;){if(n.return===null||n.return===e)return!0;n=n.return}n.sibling.return=n.return,n=n.sibling}}return!0}function en(e,n){for(n&=~xi,n&=~cl,e.suspendedLanes|=n,e.pingedLanes&=~n,e=e.expirationTimes;0<n;){var t=31-Oe(n),r=1<<t;e[t]=-1,n&=~r}}function Uu(e){if(R&6)throw Error(y(327));Jn();var n=Mr(e,0);if(!(n&1))return pe(e,Q()),null;var t=qr(e,n);if(e.tag!==0&&t===2){var r=so(e);r!==0&&(n=r,t=Fo(e,r))}if(t===1)throw t=Yt,Cn(e,0),en(e,n),pe(e,Q()),t;if(t===6)throw Error(y(345));return e.finishedWork=e.current.alternate,e.finishedLanes=n,Sn(e,ae,Ae),pe(e,Q()),null}function _i(e,n){var t=R;R|=1;try{return e(n)}finally{R=t,R===0&&(lt=Q()+500,il&&vn())}}function Tn(e){tn!==null&&tn.tag===0&&!(R&6)&&Jn();var n=R;R|=1;var t=xe.transition,r=j;try{if(xe.transition=null,j=1,e)return e()}finally{j=r,xe.transition=t,R=n,!(R&6)&&vn()}}function Ni(){me=Qn.current,I(Qn)}function Cn(e,n){e.finishedWork=null,e.finishedLanes=0;var t=e.timeoutHandle;if(t!==-1&&(e.timeoutHandle=-1,Qf(t)),K!==null)for(t=K.return;t!==null;){var r=t;switch(ii(r),r.tag){case 1:r=r.type.childContextTypes,r!=null&&$r();break;case 3:tt(),I(fe),I(le),hi();break;case 5:mi(r);break;case 4:tt();break;case 13:I($);break;case 19:I($);break;case 10:ci(r.type._context);break;case 22:case 23:Ni()}t=t.return}if(J=e,K=e=fn(e.current,null),b=me=n,X=0,Yt=null,xi=cl=Ln=0,ae=Lt=null,En!==null){for(n=0;n<En.length;n++)if(t=En[n],r=t.interleaved,r!==null){t.interleaved=null;var l=r.next,o=t.pending;if(o!==null){var i=o.next;o.next=l,r.next=i}t.pending=r}En=null}return e}function Qa(e,n){do{var t=K;try{if(ai(),Cr.current=Xr,Yr){for(var r=A.memoizedState;r!==null;){var l=r.queue;l!==null&&(l.pending=null),r=r.next}Yr=!1}if(zn=0,Z=Y=A=null,Pt=!1,Wt=0,Ei.current=null,t===null||t.return===null){X=1,Yt=n,K=null;break}e:{var o=e,i=t.return,u=t,s=n;if(n=b,u.flags|=32768,s!==null&&typeof s=="object"&&typeof s.then=="function"){var c=s,h=u,m=h.tag;if(!(h.mode&1)&&(m===0||m===11||m===15)){var p=h.alternate;p?(h.updateQueue=p.updateQueue,h.memoizedState=p.memoizedState,h.lanes=p.lanes):(h.updateQueue=null,h.memoizedState=null)}var g=_u(i);if(g!==null){g.flags&=-257,Nu(g,i,u,o,n),g.mode&1&&Cu(o,c,n),n=g,s=c;var w=n.updateQueue;if(w===null){var S=new Set;S.add(s),n.updateQueue=S}else w.add(s);break e}else{if(!(n&1)){Cu(o,c,n),Pi();break e}s=Error(y(426))}}else if(U&&u.mode&1){var F=_u(i);if(F!==null){!(F.flags&65536)&&(F.flags|=256),Nu(F,i,u,o,n),ui(rt(s,u));break e}}o=s=rt(s,u),X!==4&&(X=2),Lt===null?Lt=[o]:Lt.push(o),o=i;do{switch(o.tag){case 3:o.flags|=65536,n&=-n,o.lanes|=n;var f=za(o,s,n);yu(o,f);break e;case 1:u=s;var a=o.type,d=o.stateNode;if(!(o.flags&128)&&(typeof a.getDerivedStateFromError=="function"||d!==null&&typeof d.componentDidCatch=="function"&&(an===null||!an.has(d)))){o.flags|=65536,n&=-n,o.lanes|=n;var v=La(o,u,n);yu(o,v);break e}}o=o.return}while(o!==null)}Xa(t)}catch(E){n=E,K===t&&t!==null&&(K=t=t.return);continue}break}while(1)}function Ka(){var e=Gr.current;return Gr.current=Xr,e===null?Xr:e}function Pi(){(X===0||X===3||X===2)&&(X=4),J===null||!(Ln&268435455)&&!(cl&268435455)||en(J,b)}function qr(e,n){var t=R;R|=2;var r=Ka();(J!==e||b!==n)&&(Ae=null,Cn(e,n));do try{vd();break}catch(l){Qa(e,l)}while(1);if(ai(),R=t,Gr.current=r,K!==null)throw Error(y(261));return J=null,b=0,X}function vd(){for(;K!==null;)Ya(K)}function yd(){for(;K!==null&&!Vc();)Ya(K)}function Ya(e){var n=Za(e.alternate,e,me);e.memoizedProps=e.pendingProps,n===null?Xa(e):K=n,Ei.current=null}function Xa(e){var n=e;do{var t=n.alternate;if(e=n.return,n.flags&32768){if(t=cd(t,n),t!==null){t.flags&=32767,K=t;return}if(e!==null)e.flags|=32768,e.subtreeFlags=0,e.deletions=null;else{X=6,K=null;return}}else if(t=ad(t,n,me),t!==null){K=t;return}if(n=n.sibling,n!==null){K=n;return}K=n=e}while(n!==null);X===0&&(X=5)}function Sn(e,n,t){var r=j,l=xe.transition;try{xe.transition=null,j=1,gd(e,n,t,r)}finally{xe.transition=l,j=r}return null}function gd(e,n,t,r){do Jn();while(tn!==null);if(R&6)throw Error(y(327));t=e.finishedWork;var l=e.finishedLanes;if(t===null)return null;if(e.finishedWork=null,e.finishedLanes=0,t===e.current)throw Error(y(177));e.callbackNode=null,e.callbackPriority=0;var o=t.lanes|t.childLanes;if(Jc(e,o),e===J&&(K=J=null,b=0),!(t.subtreeFlags&2064)&&!(t.flags&2064)||vr||(vr=!0,Ja(jr,function(){return Jn(),null})),o=(t.flags&15990)!==0,t.subtreeFlags&15990||o){o=xe.transition,xe.transition=null;var i=j;j=1;var u=R;R|=4,Ei.current=null,dd(e,t),Ba(t,e),Uf(ho),Dr=!!mo,ho=mo=null,e.current=t,pd(t),Bc(),R=u,j=i,xe.transition=o}else e.current=t;if(vr&&(vr=!1,tn=e,Jr=l),o=e.pendingLanes,o===0&&(an=null),Qc(t.stateNode),pe(e,Q()),n!==null)for(r=e.onRecoverableError,t=0;t<n.length;t++)l=n[t],r(l.value,{componentStack:l.stack,digest:l.digest});if(Zr)throw Zr=!1,e=Do,Do=null,e;return Jr&1&&e.tag!==0&&Jn(),o=e.pendingLanes,o&1?e===Io?Tt++:(Tt=0,Io=e):Tt=0,vn(),null}function Jn(){if(tn!==null){var e=Ps(Jr),n=xe.transition,t=j;try{if(xe.transition=null,j=16>e?16:e,tn===null)var r=!1;else{if(e=tn,tn=null,Jr=0,R&6)throw Error(y(331));var l=R;for(R|=4,k=e.current;k!==null;){var o=k,i=o.child;if(k.flags&16){var u=o.deletions;if(u!==null){for(var s=0;s<u.length;s++){var c=u[s];for(k=c;k!==null;){var h=k;switch(h.tag){case 0:case 11:case 15:zt(8,h,o)}var m=h.child;if(m!==null)m.return=h,k=m;else for(;k!==null;){h=k;var p=h.sibling,g=h.return;if($a(h),h===c){k=null;break}if(p!==null){p.return=g,k=p;break}k=g}}}var w=o.alternate;if(w!==null){var S=w.child;if(S!==null){w.child=null;do{var F=S.sibling;S.sibling=null,S=F}while(S!==null)}}k=o}}if(o.subtreeFlags&2064&&i!==null)i.return=o,k=i;else e:for(;k!==null;){if(o=k,o.flags&2048)switch(o.tag){case 0:case 11:case 15:zt(9,o,o.return)}var f=o.sibling;if(f!==null){f.return=o.return,k=f;break e}k=o.return}}var a=e.current;for(k=a;k!==null;){i=k;var d=i.child;if(i.subtreeFlags&2064&&d!==null)d.return=i,k=d;else e:for(i=a;k!==null;){if(u=k,u.flags&2048)try{switch(u.tag){case 0:case 11:case 15:al(9,u)}}catch(E){B(u,u.return,E)}if(u===i){k=null;break e}var v=u.sibling;if(v!==null){v.return=u.return,k=v;break e}k=u.return}}if(R=l,vn(),Ue&&typeof Ue.onPostCommitFiberRoot=="function")try{Ue.onPostCommitFiberRoot(nl,e)}catch{}r=!0}return r}finally{j=t,xe.transition=n}}return!1}function $u(e,n,t){n=rt(t,n),n=za(e,n,1),e=sn(e,n,1),n=ie(),e!==null&&(Gt(e,1,n),pe(e,n))}function B(e,n,t){if(e.tag===3)$u(e,e,t);else for(;n!==null;){if(n.tag===3){$u(n,e,t);break}else if(n.tag===1){var r=n.stateNode;if(typeof n.type.getDerivedStateFromError=="function"||typeof r.componentDidCatch=="function"&&(an===null||!an.has(r))){e=rt(t,e),e=La(n,e,1),n=sn(n,e,1),e=ie(),n!==null&&(Gt(n,1,e),pe(n,e));break}}n=n.return}}function wd(e,n,t){var r=e.pingCache;r!==null&&r.delete(n),n=ie(),e.pingedLanes|=e.suspendedLanes&t,J===e&&(b&t)===t&&(X===4||X===3&&(b&130023424)===b&&500>Q()-Ci?Cn(e,0):xi|=t),pe(e,n)}function Ga(e,n){n===0&&(e.mode&1?(n=ir,ir<<=1,!(ir&130023424)&&(ir=4194304)):n=1);var t=ie();e=Ye(e,n),e!==null&&(Gt(e,n,t),pe(e,t))}function Sd(e){var n=e.memoizedState,t=0;n!==null&&(t=n.retryLane),Ga(e,t)}function kd(e,n){var t=0;switch(e.tag){case 13:var r=e.stateNode,l=e.memoizedState;l!==null&&(t=l.retryLane);break;case 19:r=e.stateNode;break;default:throw Error(y(314))}r!==null&&r.delete(n),Ga(e,t)}var Za;Za=function(e,n,t){if(e!==null)if(e.memoizedProps!==n.pendingProps||fe.current)ce=!0;else{if(!(e.lanes&t)&&!(n.flags&128))return ce=!1,sd(e,n,t);ce=!!(e.flags&131072)}else ce=!1,U&&n.flags&1048576&&bs(n,Br,n.index);switch(n.lanes=0,n.tag){case 2:var r=n.type;Nr(e,n),e=n.pendingProps;var l=bn(n,le.current);Zn(n,t),l=yi(null,n,r,e,l,t);var o=gi();return n.flags|=1,typeof l=="object"&&l!==null&&typeof l.render=="function"&&l.$$typeof===void 0?(n.tag=1,n.memoizedState=null,n.updateQueue=null,de(r)?(o=!0,Ar(n)):o=!1,n.memoizedState=l.state!==null&&l.state!==void 0?l.state:null,di(n),l.updater=ul,n.stateNode=l,l._reactInternals=n,Co(n,r,e,t),n=Po(null,n,r,!0,o,t)):(n.tag=0,U&&o&&oi(n),oe(null,n,l,t),n=n.child),n;case 16:r=n.elementType;e:{switch(Nr(e,n),e=n.pendingProps,l=r._init,r=l(r._payload),n.type=r,l=n.tag=xd(r),e=ze(r,e),l){case 0:n=No(null,n,r,e,t);break e;case 1:n=Lu(null,n,r,e,t);break e;case 11:n=Pu(null,n,r,e,t);break e;case 14:n=zu(null,n,r,ze(r.type,e),t);break e}throw Error(y(306,r,""))}return n;case 0:return r=n.type,l=n.pendingProps,l=n.elementType===r?l:ze(r,l),No(e,n,r,l,t);case 1:return r=n.type,l=n.pendingProps,l=n.elementType===r?l:ze(r,l),Lu(e,n,r,l,t);case 3:e:{if(ja(n),e===null)throw Error(y(387));r=n.pendingProps,o=n.memoizedState,l=o.element,ra(e,n),Qr(n,r,null,t);var i=n.memoizedState;if(r=i.element,o.isDehydrated)if(o={element:r,isDehydrated:!1,cache:i.cache,pendingSuspenseBoundaries:i.pendingSuspenseBoundaries,transitions:i.transitions},n.updateQueue.baseState=o,n.memoizedState=o,n.flags&256){l=rt(Error(y(423)),n),n=Tu(e,n,r,t,l);break e}else if(r!==l){l=rt(Error(y(424)),n),n=Tu(e,n,r,t,l);break e}else for(he=un(n.stateNode.containerInfo.firstChild),ve=n,U=!0,Re=null,t=ua(n,null,r,t),n.child=t;t;)t.flags=t.flags&-3|4096,t=t.sibling;else{if(et(),r===l){n=Xe(e,n,t);break e}oe(e,n,r,t)}n=n.child}return n;case 5:return sa(n),e===null&&ko(n),r=n.type,l=n.pendingProps,o=e!==null?e.memoizedProps:null,i=l.children,vo(r,l)?i=null:o!==null&&vo(r,o)&&(n.flags|=32),Oa(e,n),oe(e,n,i,t),n.child;case 6:return e===null&&ko(n),null;case 13:return Ma(e,n,t);case 4:return pi(n,n.stateNode.containerInfo),r=n.pendingProps,e===null?n.child=nt(n,null,r,t):oe(e,n,r,t),n.child;case 11:return r=n.type,l=n.pendingProps,l=n.elementType===r?l:ze(r,l),Pu(e,n,r,l,t);case 7:return oe(e,n,n.pendingProps,t),n.child;case 8:return oe(e,n,n.pendingProps.children,t),n.child;case 12:return oe(e,n,n.pendingProps.children,t),n.child;case 10:e:{if(r=n.type._context,l=n.pendingProps,o=n.memoizedProps,i=l.value,M(Hr,r._currentValue),r._currentValue=i,o!==null)if(Me(o.value,i)){if(o.children===l.children&&!fe.current){n=Xe(e,n,t);break e}}else for(o=n.child,o!==null&&(o.return=n);o!==null;){var u=o.dependencies;if(u!==null){i=o.child;for(var s=u.firstContext;s!==null;){if(s.context===r){if(o.tag===1){s=We(-1,t&-t),s.tag=2;var c=o.updateQueue;if(c!==null){c=c.shared;var h=c.pending;h===null?s.next=s:(s.next=h.next,h.next=s),c.pending=s}}o.lanes|=t,s=o.alternate,s!==null&&(s.lanes|=t),Eo(o.return,t,n),u.lanes|=t;break}s=s.next}}else if(o.tag===10)i=o.type===n.type?null:o.child;else if(o.tag===18){if(i=o.return,i===null)throw Error(y(341));i.lanes|=t,u=i.alternate,u!==null&&(u.lanes|=t),Eo(i,t,n),i=o.sibling}else i=o.child;if(i!==null)i.return=o;else for(i=o;i!==null;){if(i===n){i=null;break}if(o=i.sibling,o!==null){o.return=i.return,i=o;break}i=i.return}o=i}oe(e,n,l.children,t),n=n.child}return n;case 9:return l=n.type,r=n.pendingProps.children,Zn(n,t),l=Ce(l),r=r(l),n.flags|=1,oe(e,n,r,t),n.child;case 14:return r=n.type,l=ze(r,n.pendingProps),l=ze(r.type,l),zu(e,n,r,l,t);case 15:return Ta(e,n,n.type,n.pendingProps,t);case 17:return r=n.type,l=n.pendingProps,l=n.elementType===r?l:ze(r,l),Nr(e,n),n.tag=1,de(r)?(e=!0,Ar(n)):e=!1,Zn(n,t),oa(n,r,l),Co(n,r,l,t),Po(null,n,r,!0,e,t);case 19:return Da(e,n,t);case 22:return Ra(e,n,t)}throw Error(y(156,n.tag))};function Ja(e,n){return xs(e,n)}function Ed(e,n,t,r){this.tag=e,this.key=t,this.sibling=this.child=this.return=this.stateNode=this.type=this.elementType=null,this.index=0,this.ref=null,this.pendingProps=n,this.dependencies=this.memoizedState=this.updateQueue=this.memoizedProps=null,this.mode=r,this.subtreeFlags=this.flags=0,this.deletions=null,this.childLanes=this.lanes=0,this.alternate=null}function Ee(e,n,t,r){return new Ed(e,n,t,r)}function zi(e){return e=e.prototype,!(!e||!e.isReactComponent)}function xd(e){if(typeof e=="function")return zi(e)?1:0;if(e!=null){if(e=e.$$typeof,e===Yo)return 11;if(e===Xo)return 14}return 2}function fn(e,n){var t=e.alternate;return t===null?(t=Ee(e.tag,n,e.key,e.mode),t.elementType=e.elementType,t.type=e.type,t.stateNode=e.stateNode,t.alternate=e,e.alternate=t):(t.pendingProps=n,t.type=e.type,t.flags=0,t.subtreeFlags=0,t.deletions=null),t.flags=e.flags&14680064,t.childLanes=e.childLanes,t.lanes=e.lanes,t.child=e.child,t.memoizedProps=e.memoizedProps,t.memoizedState=e.memoizedState,t.updateQueue=e.updateQueue,n=e.dependencies,t.dependencies=n===null?null:{lanes:n.lanes,firstContext:n.firstContext},t.sibling=e.sibling,t.index=e.index,t.ref=e.ref,t}function Lr(e,n,t,r,l,o){var i=2;if(r=e,typeof e=="function")zi(e)&&(i=1);else if(typeof e=="string")i=5;else e:switch(e){case Dn:return _n(t.children,l,o,n);case Ko:i=8,l|=8;break;case Yl:return e=Ee(12,t,n,l|2),e.elementType=Yl,e.lanes=o,e;case Xl:return e=Ee(13,t,n,l),e.elementType=Xl,e.lanes=o,e;case Gl:return e=Ee(19,t,n,l),e.elementType=Gl,e.lanes=o,e;case is:return fl(t,l,o,n);default:if(typeof e=="object"&&e!==null)switch(e.$$typeof){case ls:i=10;break e;case os:i=9;break e;case Yo:i=11;break e;case Xo:i=14;break e;case Je:i=16,r=null;break e}throw Error(y(130,e==null?e:typeof e,""))}return n=Ee(i,t,n,l),n.elementType=e,n.type=r,n.lanes=o,n}function _n(e,n,t,r){return e=Ee(7,e,r,n),e.lanes=t,e}function fl(e,n,t,r){return e=Ee(22,e,r,n),e.elementType=is,e.lanes=t,e.stateNode={isHidden:!1},e}function Hl(e,n,t){return e=Ee(6,e,null,n),e.lanes=t,e}function Wl(e,n,t){return n=Ee(4,e.children!==null?e.children:[],e.key,n),n.lanes=t,n.stateNode={containerInfo:e.containerInfo,pendingChildren:null,implementation:e.implementation},n}function Cd(e,n,t,r,l){this.tag=n,this.containerInfo=e,this.finishedWork=this.pingCache=this.current=this.pendingChildren=null,this.timeoutHandle=-1,this.callbackNode=this.pendingContext=this.context=null,this.callbackPriority=0,this.eventTimes=Cl(0),this.expirationTimes=Cl(-1),this.entangledLanes=this.finishedLanes=this.mutableReadLanes=this.expiredLanes=this.pingedLanes=this.suspendedLanes=this.pendingLanes=0,this.entanglements=Cl(0),this.identifierPrefix=r,this.onRecoverableError=l,this.mutableSourceEagerHydrationData=null}function Li(e,n,t,r,l,o,i,u,s){return e=new Cd(e,n,t,u,s),n===1?(n=1,o===!0&&(n|=8)):n=0,o=Ee(3,null,null,n),e.current=o,o.stateNode=e,o.memoizedState={element:r,isDehydrated:t,cache:null,transitions:null,pendingSuspenseBoundaries:null},di(o),e}function _d(e,n,t){var r=3<arguments.length&&arguments[3]!==void 0?arguments[3]:null;return{$$typeof:Mn,key:r==null?null:""+r,children:e,containerInfo:n,implementation:t}}function qa(e){if(!e)return pn;e=e._reactInternals;e:{if(On(e)!==e||e.tag!==1)throw Error(y(170));var n=e;do{switch(n.tag){case 3:n=n.stateNode.context;break e;case 1:if(de(n.type)){n=n.stateNode.__reactInternalMemoizedMergedChildContext;break e}}n=n.return}while(n!==null);throw Error(y(171))}if(e.tag===1){var t=e.type;if(de(t))return Js(e,t,n)}return n}function ba(e,n,t,r,l,o,i,u,s){return e=Li(t,r,!0,e,l,o,i,u,s),e.context=qa(null),t=e.current,r=ie(),l=cn(t),o=We(r,l),o.callback=n??null,sn(t,o,l),e.current.lanes=l,Gt(e,l,r),pe(e,r),e}function dl(e,n,t,r){var l=n.current,o=ie(),i=cn(l);return t=qa(t),n.context===null?n.context=t:n.pendingContext=t,n=We(o,i),n.payload={element:e},r=r===void 0?null:r,r!==null&&(n.callback=r),e=sn(l,n,i),e!==null&&(je(e,l,i,o),xr(e,l,i)),i}function br(e){if(e=e.current,!e.child)return null;switch(e.child.tag){case 5:return e.child.stateNode;default:return e.child.stateNode}}function Au(e,n){if(e=e.memoizedState,e!==null&&e.dehydrated!==null){var t=e.retryLane;e.retryLane=t!==0&&t<n?t:n}}function Ti(e,n){Au(e,n),(e=e.alternate)&&Au(e,n)}function Nd(){return null}var ec=typeof reportError=="function"?reportError:function(e){console.error(e)};function Ri(e){this._internalRoot=e}pl.prototype.render=Ri.prototype.render=function(e){var n=this._internalRoot;if(n===null)throw Error(y(409));dl(e,n,null,null)};pl.prototype.unmount=Ri.prototype.unmount=function(){var e=this._internalRoot;if(e!==null){this._internalRoot=null;var n=e.containerInfo;Tn(function(){dl(null,e,null,null)}),n[Ke]=null}};function pl(e){this._internalRoot=e}pl.prototype.unstable_scheduleHydration=function(e){if(e){var n=Ts();e={blockedOn:null,target:e,priority:n};for(var t=0;t<be.length&&n!==0&&n<be[t].priority;t++);be.splice(t,0,e),t===0&&Os(e)}};function Oi(e){return!(!e||e.nodeType!==1&&e.nodeType!==9&&e.nodeType!==11)}function ml(e){return!(!e||e.nodeType!==1&&e.nodeType!==9&&e.nodeType!==11&&(e.nodeType!==8||e.nodeValue!==" react-mount-point-unstable "))}function Vu(){}function Pd(e,n,t,r,l){if(l){if(typeof r=="function"){var o=r;r=function(){var c=br(i);o.call(c)}}var i=ba(n,r,e,0,null,!1,!1,"",Vu);return e._reactRootContainer=i,e[Ke]=i.current,$t(e.nodeType===8?e.parentNode:e),Tn(),i}for(;l=e.lastChild;)e.removeChild(l);if(typeof r=="function"){var u=r;r=function(){var c=br(s);u.call(c)}}var s=Li(e,0,!1,null,null,!1,!1,"",Vu);return e._reactRootContainer=s,e[Ke]=s.current,$t(e.nodeType===8?e.parentNode:e),Tn(function(){dl(n,s,t,r)}),s}function hl(e,n,t,r,l){var o=t._reactRootContainer;if(o){var i=o;if(typeof l=="function"){var u=l;l=function(){var s=br(i);u.call(s)}}dl(n,i,e,l)}else i=Pd(t,n,e,l,r);return br(i)}zs=function(e){switch(e.tag){case 3:var n=e.stateNode;if(n.current.memoizedState.isDehydrated){var t=St(n.pendingLanes);t!==0&&(Jo(n,t|1),pe(n,Q()),!(R&6)&&(lt=Q()+500,vn()))}break;case 13:Tn(function(){var r=Ye(e,1);if(r!==null){var l=ie();je(r,e,1,l)}}),Ti(e,1)}};qo=function(e){if(e.tag===13){var n=Ye(e,134217728);if(n!==null){var t=ie();je(n,e,134217728,t)}Ti(e,134217728)}};Ls=function(e){if(e.tag===13){var n=cn(e),t=Ye(e,n);if(t!==null){var r=ie();je(t,e,n,r)}Ti(e,n)}};Ts=function(){return j};Rs=function(e,n){var t=j;try{return j=e,n()}finally{j=t}};oo=function(e,n,t){switch(n){case"input":if(ql(e,t),n=t.name,t.type==="radio"&&n!=null){for(t=e;t.parentNode;)t=t.parentNode;for(t=t.querySelectorAll("input[name="+JSON.stringify(""+n)+'][type="radio"]'),n=0;n<t.length;n++){var r=t[n];if(r!==e&&r.form===e.form){var l=ol(r);if(!l)throw Error(y(90));ss(r),ql(r,l)}}}break;case"textarea":cs(e,t);break;case"select":n=t.value,n!=null&&Kn(e,!!t.multiple,n,!1)}};ys=_i;gs=Tn;var zd={usingClientEntryPoint:!1,Events:[Jt,$n,ol,hs,vs,_i]},yt={findFiberByHostInstance:kn,bundleType:0,version:"18.2.0",rendererPackageName:"react-dom"},Ld={bundleType:yt.bundleType,version:yt.version,rendererPackageName:yt.rendererPackageName,rendererConfig:yt.rendererConfig,overrideHookState:null,overrideHookStateDeletePath:null,overrideHookStateRenamePath:null,overrideProps:null,overridePropsDeletePath:null,overridePropsRenamePath:null,setErrorHandler:null,setSuspenseHandler:null,scheduleUpdate:null,currentDispatcherRef:Ge.ReactCurrentDispatcher,findHostInstanceByFiber:function(e){return e=ks(e),e===null?null:e.stateNode},findFiberByHostInstance:yt.findFiberByHostInstance||Nd,findHostInstancesForRefresh:null,scheduleRefresh:null,scheduleRoot:null,setRefreshHandler:null,getCurrentFiber:null,reconcilerVersion:"18.2.0-next-9e3b772b8-20220608"};if(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__<"u"){var yr=__REACT_DEVTOOLS_GLOBAL_HOOK__;if(!yr.isDisabled&&yr.supportsFiber)try{nl=yr.inject(Ld),Ue=yr}catch{}}ge.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED=zd;ge.createPortal=function(e,n){var t=2<arguments.length&&arguments[2]!==void 0?arguments[2]:null;if(!Oi(n))throw Error(y(200));return _d(e,n,null,t)};ge.createRoot=function(e,n){if(!Oi(e))throw Error(y(299));var t=!1,r="",l=ec;return n!=null&&(n.unstable_strictMode===!0&&(t=!0),n.identifierPrefix!==void 0&&(r=n.identifierPrefix),n.onRecoverableError!==void 0&&(l=n.onRecoverableError)),n=Li(e,1,!1,null,null,t,!1,r,l),e[Ke]=n.current,$t(e.nodeType===8?e.parentNode:e),new Ri(n)};ge.findDOMNode=function(e){if(e==null)return null;if(e.nodeType===1)return e;var n=e._reactInternals;if(n===void 0)throw typeof e.render=="function"?Error(y(188)):(e=Object.keys(e).join(","),Error(y(268,e)));return e=ks(n),e=e===null?null:e.stateNode,e};ge.flushSync=function(e){return Tn(e)};ge.hydrate=function(e,n,t){if(!ml(n))throw Error(y(200));return hl(null,e,n,!0,t)};ge.hydrateRoot=function(e,n,t){if(!Oi(e))throw Error(y(405));var r=t!=null&&t.hydratedSources||null,l=!1,o="",i=ec;if(t!=null&&(t.unstable_strictMode===!0&&(l=!0),t.identifierPrefix!==void 0&&(o=t.identifierPrefix),t.onRecoverableError!==void 0&&(i=t.onRecoverableError)),n=ba(n,null,e,1,t??null,l,!1,o,i),e[Ke]=n.current,$t(e),r)for(e=0;e<r.length;e++)t=r[e],l=t._getVersion,l=l(t._source),n.mutableSourceEagerHydrationData==null?n.mutableSourceEagerHydrationData=[t,l]:n.mutableSourceEagerHydrationData.push(t,l);return new pl(n)};ge.render=function(e,n,t){if(!ml(n))throw Error(y(200));return hl(null,e,n,!1,t)};ge.unmountComponentAtNode=function(e){if(!ml(e))throw Error(y(40));return e._reactRootContainer?(Tn(function(){hl(null,null,e,!1,function(){e._reactRootContainer=null,e[Ke]=null})}),!0):!1};ge.unstable_batchedUpdates=_i;ge.unstable_renderSubtreeIntoContainer=function(e,n,t,r){if(!ml(t))throw Error(y(200));if(e==null||e._reactInternals===void 0)throw Error(y(38));return hl(e,n,t,!1,r)};ge.version="18.2.0-next-9e3b772b8-20220608";function nc(){if(!(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__>"u"||typeof __REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE!="function"))try{__REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE(nc)}catch(e){console.error(e)}}nc(),bu.exports=ge;var Td=bu.exports,Bu=Td;Ql.createRoot=Bu.createRoot,Ql.hydrateRoot=Bu.hydrateRoot;function Rd({text:e,percentage:n}){return n=n??0,O.jsx("div",{className:"progress-container",children:O.jsxs("div",{className:"progress-bar",style:{width:`${n}%`},children:[e," (",`${n.toFixed(2)}%`,")"]})})}function Od(){return O.jsxs("div",{id:"loader",children:[O.jsx("div",{}),O.jsx("div",{}),O.jsx("div",{}),O.jsx("div",{})]})}function jd(){const[e,n]=Le.useState(null),[t,r]=Le.useState(!1),[l,o]=Le.useState([]),[i,u]=Le.useState(""),[s,c]=Le.useState([]),h=Le.useRef(null);Le.useEffect(()=>{h.current||(h.current=new Worker(`${window.location.origin}/librarian/worker.js`,{type:"module"}));const p=g=>{switch(g.data.status){case"initiate":n(!1),o(w=>[...w,g.data]);break;case"progress":o(w=>w.map(S=>S.file===g.data.file?{...S,progress:g.data.progress}:S));break;case"done":o(w=>w.filter(S=>S.file!==g.data.file));break;case"ready":n(!0);break;case"complete":fetch("/librarian/vector",{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({namespace:"default",includeValues:!1,includeMetadata:!0,topK:10,text:i,session:Ud,vector:Object.values(g.data.output)})}).then(w=>w.json()).then(w=>{console.log("res",w.matches),c(w.matches.map(S=>({...S,queryId:w.queryId,queryVector:Object.values(g.data.output)}))),r(!1)});break}};return h.current.addEventListener("message",p),()=>h.current.removeEventListener("message",p)});const m=()=>{r(!0),h.current.postMessage({text:i})};return O.jsxs(O.Fragment,{children:[O.jsx("h1",{children:"Uqbar Librarian"}),O.jsxs("h2",{children:["Searching ",O.jsx("code",{children:"drew.uq"}),"'s ",O.jsx("code",{children:"news"})," database"]}),O.jsx("div",{className:"container",style:{minWidth:"700px"},children:O.jsxs("div",{className:"textbox-container",style:{marginBottom:"0"},children:[O.jsx("input",{value:i,onChange:p=>u(p.target.value),placeholder:"query for articles here"}),O.jsx("button",{disabled:t,onClick:m,children:"Search"})]})}),t&&O.jsx(Od,{}),O.jsx("div",{className:"container",children:s&&s.map((p,g)=>O.jsx(Md,{article:p,position:g,onSimilar:c},p.id))}),O.jsxs("div",{className:"progress-bars-container",children:[e===!1&&O.jsx("label",{children:"Loading models... (only run once)"}),l.map(p=>O.jsx("div",{children:O.jsx(Rd,{text:p.file,percentage:p.progress})},p.file))]})]})}const Ud=Math.random().toString(36).slice(2);function Md(e){const{key:n,article:t}=e,f=x=>fetch("/librarian/feedback",{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({session:Ud,vector:t.queryVector,id:t.id,label:x})}),[r,l]=Le.useState(!1),[a,s]=Le.useState(t.metadata.article??null),d=()=>fetch("/librarian/click",{method:"POST",headers:{"Content-Type":"application/json"},body:JSON.stringify({queryId:t.queryId,id:t.id,position:e.position})}),u=()=>{!r&&d(),!r&&a===null&&fetch(`/librarian/document/${encodeURIComponent(t.id)}`).then(c=>c.json()).then(c=>s(c.text??"")),l(!r)};return t.metadata.truncated=`${(t.metadata.preview??t.metadata.article??"").slice(0,200)}...`,O.jsxs("div",{className:"article",children:[O.jsx("a",{href:t.metadata.url,onClick:d,children:O.jsx("h1",{className:"article-title",children:t.metadata.title})}),O.jsxs("h3",{className:"article-author",children:[t.metadata.author," | ",t.metadata.publication]}),O.jsx("p",{className:"article-content",children:r?a??t.metadata.truncated:t.metadata.truncated}),O.jsx("span",{className:"read-more-link",onClick:u,children:r?"Collapse":"Read More"})," ",O.jsx("span",{className:"read-more-link",onClick:()=>fetch(`/librarian/document/${encodeURIComponent(t.id)}/similar`).then(c=>c.json()).then(c=>e.onSimilar((c.matches??[]).map(S=>({...S,queryId:c.queryId})))),children:"More like this"})," ",O.jsx("span",{className:"read-more-link",title:"relevant",onClick:()=>f("up"),children:"\u{1F44D}"})," ",O.jsx("span",{className:"read-more-link",title:"not relevant",onClick:()=>f("down"),children:"\u{1F44E}"})]},n)}Ql.createRoot(document.getElementById("root")).render(O.jsx(wc.StrictMode,{children:O.jsx(jd,{})}));
@@ -1,37 +1,212 @@
+#[cfg(target_arch = "wasm32")]
 cargo_component_bindings::generate!();
 
-use bindings::component::uq_process::types::*;
-use bindings::{
-    get_payload, print_to_terminal, receive, send_and_await_response, send_request, send_requests,
-    send_response, Guest,
-};
+#[cfg(target_arch = "wasm32")]
+use bindings::Guest;
+use process_lib::handler::{self, Effect, Handler};
+use process_lib::http_server::{HttpRequest, HttpResponse, Router};
+use process_lib::kernel::{get_payload, print_to_terminal, send_and_await_response};
+use process_lib::state;
+use process_lib::types::*;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
-#[allow(dead_code)]
-mod process_lib;
+mod forward;
+mod searches;
+mod terminal;
+#[cfg(test)]
+mod tests;
 
+use forward::{forward, HttpAnswer, ServerCall, Then};
+use searches::{Alert, SaveSearchRequest, SavedSearch};
+
+/// the process every librarian server runs as
+const SERVER_PROCESS: &str = "server:librarian:drew.uq";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct State {
+    /// nodes running a librarian server; requests go to the first one, and
+    /// those forwarded for HTTP callers on to the next when it cannot be reached
+    servers: Vec<String>,
+    searches: BTreeMap<String, SavedSearch>,
+    /// oldest first
+    alerts: Vec<Alert>,
+    /// changed since it was last saved
+    #[serde(skip)]
+    unsaved: bool,
+}
+
+/// Version 0 is the same layout, saved before states were versioned.
+impl state::Versioned for State {
+    const VERSION: u32 = 1;
+    const MIGRATIONS: &'static [state::Migration] = &[state::unchanged];
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            // drew.uq hosts the shared library
+            servers: vec!["drew.uq".to_string()],
+            searches: BTreeMap::new(),
+            alerts: vec![],
+            unsaved: false,
+        }
+    }
+}
+
+impl State {
+    /// Has the state saved once the message being handled is done with.
+    fn mark_changed(&mut self) {
+        self.unsaved = true;
+    }
+
+    /// The effect saving the state, if it changed.
+    fn take_changes(&mut self) -> Option<Effect> {
+        std::mem::take(&mut self.unsaved).then(|| Effect::set_state(self))
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(target_arch = "wasm32")]
 struct Component;
 
-fn send_http_response(status: u16, headers: HashMap<String, String>, payload_bytes: Vec<u8>) {
-    send_response(
-        &Response {
+/// What route handlers have to work with.
+struct Librarian {
+    our: Address,
+    state: State,
+}
+
+const QUERY_TIMEOUT: u64 = 15;
+const PING_TIMEOUT: u64 = 5;
+/// ingestion embeds every chunk before upserting, which takes a while for long articles
+const INGEST_TIMEOUT: u64 = 120;
+
+fn json_response(status: u16, body: serde_json::Value) -> HttpResponse {
+    // documents can be deleted or changed at any time
+    HttpResponse::json(status, &body).header("Cache-Control", "no-cache")
+}
+
+/// Sends `action` to the server and waits for its JSON answer, or the status
+/// and body to answer the HTTP request with if it failed. Like requests
+/// forwarded for HTTP callers, an idempotent action goes once more to a server
+/// that timed out and then on to the next server, and any other action only
+/// to the first server, once. Routes forward to the server without waiting
+/// instead, see [`forward`].
+fn call_server(
+    state: &State,
+    action: serde_json::Value,
+    payload: Option<&Payload>,
+    timeout: u64,
+) -> Result<serde_json::Value, (u16, serde_json::Value)> {
+    if state.servers.is_empty() {
+        return Err((503, json!({ "error": "no librarian server configured" })));
+    }
+    // attempts per server
+    let (servers, attempts) = match forward::is_idempotent(&action) {
+        true => (&state.servers[..], 2),
+        false => (&state.servers[..1], 1),
+    };
+    let mut timed_out = false;
+    for node in servers {
+        for _ in 0..attempts {
+            match call_node(node, &action, payload, timeout) {
+                Ok((response, payload)) => return server_answer(&response, payload),
+                Err(SendErrorKind::Timeout) => timed_out = true,
+                Err(SendErrorKind::Offline) => {
+                    timed_out = false;
+                    break;
+                }
+            }
+        }
+    }
+    print_to_terminal(0, "librarian: could not reach server");
+    Err(match timed_out {
+        true => (504, json!({ "error": "librarian server timed out" })),
+        false => (502, json!({ "error": "could not reach librarian server" })),
+    })
+}
+
+/// Sends `action` to the server on `node` once, returning its response.
+fn call_node(
+    node: &str,
+    action: &serde_json::Value,
+    payload: Option<&Payload>,
+    timeout: u64,
+) -> Result<(Response, Option<Payload>), SendErrorKind> {
+    let res = send_and_await_response(
+        &Address {
+            node: node.to_string(),
+            process: ProcessId::from_str(SERVER_PROCESS).unwrap(),
+        },
+        &Request {
             inherit: false,
-            ipc: Some(
-                serde_json::json!({
-                    "status": status,
-                    "headers": headers,
-                })
-                .to_string(),
-            ),
+            expects_response: Some(timeout),
+            ipc: Some(action.to_string()),
             metadata: None,
         },
-        Some(&Payload {
-            mime: Some("application/octet-stream".to_string()),
-            bytes: payload_bytes,
-        }),
-    )
+        payload,
+    );
+    match res {
+        Ok((_, Message::Response((response, _)))) => {
+            print_to_terminal(0, "librarian: got drews res");
+            Ok((response, get_payload()))
+        }
+        Ok((_, Message::Request(_))) => Err(SendErrorKind::Offline),
+        Err(e) => Err(e.kind),
+    }
+}
+
+/// The JSON answer in a response from the server, or the status and body to
+/// answer the HTTP request with if the server failed.
+fn server_answer(
+    response: &Response,
+    payload: Option<Payload>,
+) -> Result<serde_json::Value, (u16, serde_json::Value)> {
+    // the server answers `"Ok"` or `{"Err": ...}` in the ipc, with the body as payload
+    let body = payload
+        .and_then(|payload| serde_json::from_slice(&payload.bytes).ok())
+        .unwrap_or_default();
+    if response.ipc.as_deref() == Some("\"Ok\"") {
+        Ok(body)
+    } else {
+        Err((500, body))
+    }
+}
+
+/// Forwards the JSON body of `request` to the server as the argument of `action`.
+fn forward_body(state: &State, request: &HttpRequest, action: &str, timeout: u64) -> HttpAnswer {
+    match request.json::<serde_json::Value>() {
+        Ok(body) => forward(state, json!({ action: body }), None, timeout, Then::Relay),
+        Err(res) => res.into(),
+    }
+}
+
+/// Pings every configured server, returning `{node, reachable, latency_ms}`
+/// for each.
+fn ping_servers(state: &State) -> Vec<serde_json::Value> {
+    state
+        .servers
+        .iter()
+        .map(|node| {
+            let start = Instant::now();
+            let reachable = call_node(node, &json!("Ping"), None, PING_TIMEOUT)
+                .is_ok_and(|(response, payload)| server_answer(&response, payload).is_ok());
+            json!({
+                "node": node,
+                "reachable": reachable,
+                "latency_ms": reachable.then(|| start.elapsed().as_secs_f64() * 1000.0),
+            })
+        })
+        .collect()
 }
 
 const LIBRARIAN_PAGE: &str = include_str!("index.html");
@@ -39,182 +214,315 @@ const LIBRARIAN_JS: &str = include_str!("index.js");
 const LIBRARIAN_CSS: &str = include_str!("index.css");
 const WORKER_JS: &str = include_str!("worker.js");
 
-impl Guest for Component {
-    fn init(our: Address) {
-        print_to_terminal(0, "librarian: start");
+fn router() -> Router<Librarian, HttpAnswer> {
+    Router::new("librarian")
+        .route("GET", "/librarian", false, page)
+        .route("GET", "/librarian/worker.js", false, worker)
+        .route("POST", "/librarian/vector", false, query)
+        .route("POST", "/librarian/ingest", true, ingest)
+        .route("POST", "/librarian/ingest/url", true, ingest_url)
+        .route("GET", "/librarian/document/{id}", false, document)
+        .route("GET", "/librarian/document/{id}/similar", false, similar)
+        .route("POST", "/librarian/delete", true, delete)
+        .route("POST", "/librarian/update", true, update)
+        .route("GET", "/librarian/stats", false, stats)
+        .route("POST", "/librarian/click", false, click)
+        .route("POST", "/librarian/feedback", false, feedback)
+        .route("GET", "/librarian/analytics", true, analytics)
+        .route("GET", "/librarian/searches", true, list_searches)
+        .route("POST", "/librarian/searches", true, save_search)
+        .route("GET", "/librarian/searches/{name}", true, get_search)
+        // runs the search right away
+        .route("POST", "/librarian/searches/{name}", true, run_search)
+        .route("DELETE", "/librarian/searches/{name}", true, remove_search)
+        .route("GET", "/librarian/alerts", true, alerts)
+}
+
+fn page(librarian: &mut Librarian, _: &HttpRequest) -> HttpAnswer {
+    HttpResponse::html(
+        LIBRARIAN_PAGE
+            .replace("${node}", &librarian.our.node)
+            .replace("${process}", &librarian.our.process.to_string())
+            .replace("${js}", LIBRARIAN_JS)
+            .replace("${css}", LIBRARIAN_CSS),
+    )
+    .into()
+}
+
+fn worker(_: &mut Librarian, _: &HttpRequest) -> HttpAnswer {
+    HttpResponse::new(200, "application/javascript", WORKER_JS.as_bytes().to_vec()).into()
+}
+
+fn query(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    forward_body(&librarian.state, request, "Query", QUERY_TIMEOUT)
+}
+
+fn ingest(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    // JSON bodies are batches of documents, anything else is a single document
+    // whose text the server extracts by mime type
+    let content_type = request.header("content-type").unwrap_or("application/json");
+    if content_type.starts_with("application/json") {
+        return forward_body(&librarian.state, request, "Ingest", INGEST_TIMEOUT);
+    }
+    if request.body.is_empty() {
+        return json_response(400, json!({ "error": "expected a document" })).into();
+    }
+    let mut ingest = serde_json::Map::new();
+    for key in ["id", "namespace"] {
+        if let Some(value) = request.query.get(key) {
+            ingest.insert(key.to_string(), value.clone().into());
+        }
+    }
+    forward(
+        &librarian.state,
+        json!({ "IngestPayload": ingest }),
+        Some(Payload {
+            mime: Some(content_type.to_string()),
+            bytes: request.body.clone(),
+        }),
+        INGEST_TIMEOUT,
+        Then::Relay,
+    )
+}
+
+fn ingest_url(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    forward_body(&librarian.state, request, "IngestUrl", INGEST_TIMEOUT)
+}
+
+fn document(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    forward(
+        &librarian.state,
+        json!({ "Fetch": { "ids": [request.param("id")] } }),
+        None,
+        QUERY_TIMEOUT,
+        Then::Document,
+    )
+}
+
+fn similar(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    forward(
+        &librarian.state,
+        json!({ "QueryById": {
+            "id": request.param("id"),
+            "topK": request.query_as::<u64>("topK").unwrap_or(10),
+            "includeMetadata": true,
+        } }),
+        None,
+        QUERY_TIMEOUT,
+        Then::Relay,
+    )
+}
+
+fn delete(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    forward_body(&librarian.state, request, "Delete", QUERY_TIMEOUT)
+}
+
+fn update(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    forward_body(&librarian.state, request, "UpdateMetadata", QUERY_TIMEOUT)
+}
 
-        let bindings_address = Address {
-            node: our.node.clone(),
-            process: ProcessId::from_str("http_bindings:http_bindings:uqbar").unwrap(),
+fn stats(librarian: &mut Librarian, _: &HttpRequest) -> HttpAnswer {
+    let servers = ping_servers(&librarian.state);
+    let (status, mut body) =
+        match call_server(&librarian.state, json!("Stats"), None, QUERY_TIMEOUT) {
+            Ok(body) => (200, body),
+            Err((status, body)) => (status, body),
         };
+    body["servers"] = servers.into();
+    json_response(status, body).into()
+}
 
-        // <address, request, option<context>, option<payload>>
-        let http_endpoint_binding_requests: [(Address, Request, Option<Context>, Option<Payload>);
-            3] = [
-            (
-                bindings_address.clone(),
-                Request {
-                    inherit: false,
-                    expects_response: None,
-                    ipc: Some(
-                        serde_json::json!({
-                            "action": "bind-app",
-                            "path": "/librarian",
-                            "app": "librarian",
-                            "authenticated": false,
-                        })
-                        .to_string(),
-                    ),
-                    metadata: None,
-                },
-                None,
-                None,
-            ),
-            (
-                bindings_address.clone(),
-                Request {
-                    inherit: false,
-                    expects_response: None,
-                    ipc: Some(
-                        serde_json::json!({
-                            "action": "bind-app",
-                            "path": "/librarian/vector",
-                            "app": "librarian",
-                            "authenticated": false, // TODO
-                        })
-                        .to_string(),
-                    ),
-                    metadata: None,
-                },
-                None,
-                None,
-            ),
-            (
-                bindings_address.clone(),
-                Request {
-                    inherit: false,
-                    expects_response: None,
-                    ipc: Some(
-                        serde_json::json!({
-                            "action": "bind-app",
-                            "path": "/librarian/worker.js",
-                            "app": "librarian",
-                            "authenticated": false, // TODO
-                        })
-                        .to_string(),
-                    ),
-                    metadata: None,
-                },
-                None,
-                None,
+fn click(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    forward_body(&librarian.state, request, "Click", QUERY_TIMEOUT)
+}
+
+fn feedback(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    forward_body(&librarian.state, request, "Feedback", QUERY_TIMEOUT)
+}
+
+fn analytics(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    let mut analytics = serde_json::Map::new();
+    for key in ["days", "limit"] {
+        if let Some(value) = request.query_as::<u64>(key) {
+            analytics.insert(key.to_string(), value.into());
+        }
+    }
+    forward(
+        &librarian.state,
+        json!({ "Analytics": analytics }),
+        None,
+        QUERY_TIMEOUT,
+        Then::Relay,
+    )
+}
+
+fn list_searches(librarian: &mut Librarian, _: &HttpRequest) -> HttpAnswer {
+    let searches: Vec<serde_json::Value> = librarian
+        .state
+        .searches
+        .iter()
+        .map(|(name, search)| search.describe(name))
+        .collect();
+    json_response(200, json!({ "searches": searches })).into()
+}
+
+fn save_search(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    let save: SaveSearchRequest = match request.json() {
+        Ok(save) => save,
+        Err(res) => return res.into(),
+    };
+    let name = save.name.clone();
+    let search = SavedSearch::new(save);
+    let description = search.describe(&name);
+    librarian.state.searches.insert(name, search);
+    librarian.state.mark_changed();
+    json_response(200, description).into()
+}
+
+fn get_search(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    let name = request.param("name");
+    match librarian.state.searches.get(name) {
+        Some(search) => json_response(200, search.describe(name)),
+        None => json_response(404, json!({ "error": "no such saved search" })),
+    }
+    .into()
+}
+
+fn run_search(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    match searches::run(&mut librarian.state, request.param("name")) {
+        Ok(alerts) => json_response(200, json!({ "alerts": alerts })),
+        Err(e) => json_response(404, json!({ "error": e.to_string() })),
+    }
+    .into()
+}
+
+fn remove_search(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    let name = request.param("name");
+    match librarian.state.searches.remove(name) {
+        Some(_) => {
+            librarian.state.mark_changed();
+            json_response(200, json!({ "removed": name }))
+        }
+        None => json_response(404, json!({ "error": "no such saved search" })),
+    }
+    .into()
+}
+
+fn alerts(librarian: &mut Librarian, request: &HttpRequest) -> HttpAnswer {
+    let since = request.query_as::<u64>("since").unwrap_or(0);
+    let alerts: Vec<&Alert> = librarian
+        .state
+        .alerts
+        .iter()
+        .filter(|alert| alert.found_at >= since)
+        .collect();
+    json_response(200, json!({ "alerts": alerts })).into()
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Guest for Component {
+    fn init(our: bindings::Address) {
+        // our own bindings only serve the entry point; everything else goes
+        // through process_lib's
+        run(Address {
+            node: our.node,
+            process: ProcessId::new(
+                &our.process.process_name,
+                &our.process.package_name,
+                &our.process.publisher_node,
             ),
-        ];
-        send_requests(&http_endpoint_binding_requests);
+        })
+    }
+}
 
-        loop {
-            let Ok((source, message)) = receive() else {
-                print_to_terminal(0, "librarian: got network error");
-                continue;
-            };
-            let Message::Request(request) = message else {
-                print_to_terminal(0, "librarian: got unexpected Response");
-                continue;
-            };
+/// The process itself, which `init` starts. On native targets tests run it
+/// against process_lib's mock kernel.
+pub fn run(our: Address) {
+    print_to_terminal(0, "librarian: start");
 
-            let Some(json) = request.ipc else {
-                print_to_terminal(0, "librarian: got unexpected Request");
-                continue;
-            };
+    handler::run(&mut Process {
+        router: router(),
+        librarian: Librarian {
+            our: our.clone(),
+            state: state::load_or_default(&our),
+        },
+    });
+}
 
-            let message_json: serde_json::Value = match serde_json::from_str(&json) {
-                Ok(v) => v,
-                Err(_) => {
-                    print_to_terminal(0, "librarian: failed to parse ipc JSON, skipping");
-                    continue;
-                }
-            };
+/// The librarian as its receive loop drives it.
+struct Process {
+    router: Router<Librarian, HttpAnswer>,
+    librarian: Librarian,
+}
 
-            if source.process.to_string() == "http_bindings:http_bindings:uqbar" {
-                print_to_terminal(0, "librarian: got message from http_bindings");
-
-                let path = message_json["path"].as_str().unwrap_or("");
-                let mut default_headers = HashMap::new();
-                default_headers.insert("Content-Type".to_string(), "text/html".to_string());
-
-                match path {
-                    "/librarian" => {
-                        send_http_response(
-                            200,
-                            default_headers.clone(),
-                            LIBRARIAN_PAGE
-                                .replace("${node}", &our.node)
-                                .replace("${process}", &source.process.to_string())
-                                .replace("${js}", LIBRARIAN_JS)
-                                .replace("${css}", LIBRARIAN_CSS)
-                                .to_string()
-                                .as_bytes()
-                                .to_vec(),
-                        );
-                    }
-                    "/librarian/worker.js" => {
-                        send_http_response(
-                            200,
-                            {
-                                default_headers.insert("Content-Type".to_string(), "application/javascript".to_string());
-                                default_headers
-                            },
-                            WORKER_JS
-                                .to_string()
-                                .as_bytes()
-                                .to_vec(),
-                        );
-                    }
-                    "/librarian/vector" => {
-                        print_to_terminal(0, "librarian: got request for /librarian/vector");
-
-                        let _drews_res = send_and_await_response(
-                            &Address {
-                                // always send to drew.uq because we are centralized for now
-                                node: "drew.uq".to_string(),
-                                process: ProcessId::from_str("server:librarian:drew.uq").unwrap(),
-                            },
-                            &Request {
-                                inherit: false,
-                                expects_response: Some(15),
-                                ipc: Some(json!({}).to_string()),
-                                metadata: None,
-                            },
-                            Some(
-                                &Payload {
-                                    mime: Some("application/octet-stream".to_string()),
-                                    bytes: get_payload().unwrap().bytes,
-                                }
-                            ),
-                        );
-                        print_to_terminal(0, "librarian: got drews res");
-        
-                        send_http_response(
-                            200,
-                            {
-                                let mut headers = HashMap::new();
-                                headers.insert("content-type".to_string(), "application/json".to_string());
-                                headers
-                            },
-                            get_payload().unwrap().bytes
-                        );
-                    }
-                    _ => {
-                        send_http_response(
-                            404,
-                            default_headers.clone(),
-                            "Not Found".to_string().as_bytes().to_vec(),
-                        );
-                        continue;
-                    }
-                }
-            } else {
-                print_to_terminal(0, "librarian: got message from source we do not handle");
+impl Handler for Process {
+    fn start(&mut self) -> Vec<Effect> {
+        let our = &self.librarian.our;
+        let mut effects = self.router.bindings(our);
+        effects.push(searches::tick(our));
+        effects
+    }
+
+    fn handle(
+        &mut self,
+        source: &Address,
+        message: Message,
+        payload: Option<Payload>,
+    ) -> Vec<Effect> {
+        let our = &self.librarian.our;
+        let request = match message {
+            Message::Request(request) => request,
+            // the server answering a request forwarded for an HTTP caller
+            Message::Response((response, context)) => {
+                return match ServerCall::from_context(context.as_ref()) {
+                    Some(call) => vec![call.answer(&response, payload).into_effect()],
+                    None => vec![Effect::print("librarian: got unexpected Response")],
+                };
             }
+        };
+        if source == our {
+            // the saved search timer, left unanswered on purpose
+            return vec![];
+        }
+        let Some(json) = request.ipc else {
+            return vec![Effect::print("librarian: got unexpected Request")];
+        };
+
+        let mut effects = if source.process == "terminal:terminal:uqbar" && source.node == our.node
+        {
+            // commands typed into the terminal come as plain text
+            vec![Effect::print(
+                match terminal::run(our, &mut self.librarian.state, &json) {
+                    Ok(output) => output,
+                    Err(e) => format!("librarian: {}", e),
+                },
+            )]
+        } else if source.process == "http_bindings:http_bindings:uqbar" {
+            vec![
+                Effect::print("librarian: got message from http_bindings"),
+                self.router
+                    .answer(&mut self.librarian, &json, payload)
+                    .into_effect(),
+            ]
+        } else {
+            vec![Effect::print(
+                "librarian: got message from source we do not handle",
+            )]
+        };
+        effects.extend(self.librarian.state.take_changes());
+        effects
+    }
+
+    fn handle_send_error(&mut self, error: SendError, context: Option<Context>) -> Vec<Effect> {
+        if context.as_deref() != Some(searches::TICK_CONTEXT) {
+            return match ServerCall::from_context(context.as_ref()) {
+                Some(call) => call.recover(&self.librarian.state, error),
+                None => vec![Effect::print("librarian: got network error")],
+            };
         }
+        let mut effects = searches::run_due(&mut self.librarian.state);
+        effects.push(searches::tick(&self.librarian.our));
+        effects.extend(self.librarian.state.take_changes());
+        effects
     }
 }
@@ -0,0 +1,214 @@
+use process_lib::handler::Effect;
+use process_lib::types::*;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::VecDeque;
+
+use super::{call_server, now, State, QUERY_TIMEOUT};
+
+/// how often saved searches are checked for being due, in seconds
+pub const TICK_INTERVAL: u64 = 60;
+/// the context of the request that wakes the librarian up to check saved searches
+pub const TICK_CONTEXT: &str = "\"saved-searches\"";
+/// results asked for on each run; new documents ranked below are missed
+const SAVED_SEARCH_TOP_K: usize = 25;
+/// alerts kept, beyond which the oldest are dropped
+const MAX_ALERTS: usize = 500;
+/// documents each saved search remembers having seen, beyond which the
+/// earliest are forgotten and may alert again
+pub const MAX_SEEN: usize = 1000;
+
+fn default_threshold() -> f32 {
+    0.5
+}
+
+fn default_interval() -> u64 {
+    24 * 60 * 60
+}
+
+/// The body POSTed to `/librarian/searches`.
+#[derive(Debug, Deserialize)]
+pub struct SaveSearchRequest {
+    pub name: String,
+    /// a query as POSTed to `/librarian/vector`, typically with a `text`
+    /// rather than a `vector`
+    pub query: serde_json::Value,
+    /// the lowest score of a match worth an alert
+    #[serde(default = "default_threshold")]
+    pub threshold: f32,
+    /// seconds between runs
+    #[serde(default = "default_interval")]
+    pub interval: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearch {
+    #[serde(with = "process_lib::as_json")]
+    pub query: serde_json::Value,
+    pub threshold: f32,
+    pub interval: u64,
+    /// seconds since the epoch
+    pub last_run: Option<u64>,
+    /// ids of the documents that matched on earlier runs, earliest first and
+    /// at most [`MAX_SEEN`] of them; saved as a list of ids
+    pub seen: VecDeque<String>,
+}
+
+impl SavedSearch {
+    pub fn new(save: SaveSearchRequest) -> Self {
+        SavedSearch {
+            query: save.query,
+            threshold: save.threshold,
+            interval: save.interval,
+            last_run: None,
+            seen: VecDeque::new(),
+        }
+    }
+
+    /// What `/librarian/searches` shows of a saved search.
+    pub fn describe(&self, name: &str) -> serde_json::Value {
+        json!({
+            "name": name,
+            "query": self.query,
+            "threshold": self.threshold,
+            "interval": self.interval,
+            "last_run": self.last_run,
+            "seen": self.seen.len(),
+        })
+    }
+
+    fn due(&self, now: u64) -> bool {
+        self.last_run
+            .map_or(true, |last_run| now >= last_run + self.interval)
+    }
+}
+
+/// A document that newly matched a saved search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alert {
+    pub search: String,
+    pub id: String,
+    pub score: f32,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    /// seconds since the epoch
+    pub found_at: u64,
+}
+
+/// There are no timers, so the librarian sends itself a request it never
+/// answers. When it times out, the kernel hands it back as a send error
+/// carrying [`TICK_CONTEXT`].
+pub fn tick(our: &Address) -> Effect {
+    Effect::Send {
+        target: our.clone(),
+        request: Request {
+            inherit: false,
+            expects_response: Some(TICK_INTERVAL),
+            ipc: Some("\"Tick\"".to_string()),
+            metadata: None,
+        },
+        context: Some(TICK_CONTEXT.to_string()),
+        payload: None,
+    }
+}
+
+/// Runs the saved searches that are due, returning what to tell the terminal
+/// about new matches.
+pub fn run_due(state: &mut State) -> Vec<Effect> {
+    let now = now();
+    let due: Vec<String> = state
+        .searches
+        .iter()
+        .filter(|(_, search)| search.due(now))
+        .map(|(name, _)| name.clone())
+        .collect();
+    let mut effects = vec![];
+    for name in due {
+        match run(state, &name) {
+            Ok(alerts) if !alerts.is_empty() => {
+                effects.push(Effect::print(notification(&name, &alerts)))
+            }
+            Ok(_) => {}
+            Err(e) => effects.push(Effect::print(format!(
+                "librarian: saved search {:?} failed: {}",
+                name, e
+            ))),
+        }
+    }
+    effects
+}
+
+/// Runs a saved search, returning an alert for each document that matches
+/// above the threshold for the first time. The first run only takes note of
+/// what matches already.
+pub fn run(state: &mut State, name: &str) -> anyhow::Result<Vec<Alert>> {
+    let search = state
+        .searches
+        .get(name)
+        .ok_or(anyhow::anyhow!("no saved search {:?}", name))?;
+    let mut query = search.query.clone();
+    query["topK"] = SAVED_SEARCH_TOP_K.into();
+    query["includeMetadata"] = true.into();
+    let res = call_server(state, json!({ "Query": query }), None, QUERY_TIMEOUT).map_err(
+        |(status, body)| {
+            anyhow::anyhow!(
+                "server error {}: {}",
+                status,
+                body["error"].as_str().unwrap_or_default()
+            )
+        },
+    )?;
+
+    let now = now();
+    let search = state.searches.get_mut(name).unwrap();
+    let first_run = search.last_run.is_none();
+    search.last_run = Some(now);
+    let mut alerts = vec![];
+    for m in res["matches"].as_array().into_iter().flatten() {
+        let (Some(id), Some(score)) = (m["id"].as_str(), m["score"].as_f64()) else {
+            continue;
+        };
+        if (score as f32) < search.threshold || search.seen.iter().any(|seen| seen == id) {
+            continue;
+        }
+        search.seen.push_back(id.to_string());
+        if search.seen.len() > MAX_SEEN {
+            search.seen.pop_front();
+        }
+        if first_run {
+            continue;
+        }
+        let field = |key: &str| m["metadata"][key].as_str().map(|value| value.to_string());
+        alerts.push(Alert {
+            search: name.to_string(),
+            id: id.to_string(),
+            score: score as f32,
+            title: field("title"),
+            url: field("url"),
+            found_at: now,
+        });
+    }
+
+    state.alerts.extend(alerts.iter().cloned());
+    let excess = state.alerts.len().saturating_sub(MAX_ALERTS);
+    state.alerts.drain(..excess);
+    state.mark_changed();
+    Ok(alerts)
+}
+
+pub fn notification(name: &str, alerts: &[Alert]) -> String {
+    let mut lines = vec![format!(
+        "librarian: saved search {:?} found {} new documents",
+        name,
+        alerts.len()
+    )];
+    for alert in alerts {
+        lines.push(format!(
+            "    {:.3}  {}  ({})",
+            alert.score,
+            alert.title.as_deref().unwrap_or(&alert.id),
+            alert.id
+        ));
+    }
+    lines.join("\n")
+}
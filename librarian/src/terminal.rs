@@ -0,0 +1,260 @@
+use process_lib::kernel::{get_payload, send_and_await_response};
+use process_lib::types::*;
+use serde_json::json;
+use std::str::FromStr;
+
+use super::searches::{self, SaveSearchRequest, SavedSearch};
+use super::{call_server, ping_servers, State, INGEST_TIMEOUT, QUERY_TIMEOUT};
+
+const HELP: &str = "commands:
+    search <text>             query the library
+    show <id>                 print a document
+    stats                     summarize the library
+    collections               list collections and their sizes
+    delete <id>               remove a document
+    querylog on [days]        log queries and clicks, keeping them for 30 or [days] days
+    querylog off              stop logging and delete the log
+    analytics [days]          summarize the query log, over the last [days] days
+    import <path | url>       ingest a file from this package's drive, or a web page
+    servers                   list librarian servers
+    servers add <node>        add a server, tried after the others
+    servers remove <node>     stop using a server
+    searches                  list saved searches
+    searches save <name> <text>
+                              save a search, run daily to alert on new matches
+    searches run <name>       run a saved search now
+    searches remove <name>    delete a saved search
+    alerts                    list documents saved searches found";
+
+const VFS_TIMEOUT: u64 = 15;
+
+/// Runs a command typed into the terminal, returning what to print.
+/// Commands about the library itself are run by the server.
+pub fn run(our: &Address, state: &mut State, line: &str) -> anyhow::Result<String> {
+    let line = line.trim();
+    let (name, argument) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let argument = argument.trim();
+    match name {
+        "help" => Ok(HELP.to_string()),
+        "servers" => servers(state, argument),
+        "import" => import(our, state, argument),
+        "searches" => saved_searches(state, argument),
+        "alerts" if state.alerts.is_empty() => Ok("no alerts".to_string()),
+        "alerts" => Ok(state
+            .alerts
+            .iter()
+            .map(|alert| {
+                format!(
+                    "{}  {:.3}  {}  ({})",
+                    alert.search,
+                    alert.score,
+                    alert.title.as_deref().unwrap_or(&alert.id),
+                    alert.id
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")),
+        "stats" => {
+            let mut output = server(state, json!({ "Command": line }), None, QUERY_TIMEOUT)
+                .map_or_else(
+                    |e| e.to_string(),
+                    |res| res["output"].as_str().unwrap_or_default().to_string(),
+                );
+            output.push_str("\nservers:");
+            for server in ping_servers(state) {
+                let node = server["node"].as_str().unwrap_or_default();
+                output.push_str(&match server["latency_ms"].as_f64() {
+                    Some(latency) => format!("\n    {} ({:.0} ms)", node, latency),
+                    None => format!("\n    {} (unreachable)", node),
+                });
+            }
+            Ok(output)
+        }
+        _ => Ok(
+            server(state, json!({ "Command": line }), None, QUERY_TIMEOUT)?["output"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        ),
+    }
+}
+
+fn server(
+    state: &State,
+    action: serde_json::Value,
+    payload: Option<&Payload>,
+    timeout: u64,
+) -> anyhow::Result<serde_json::Value> {
+    call_server(state, action, payload, timeout).map_err(|(status, body)| {
+        anyhow::anyhow!(
+            "server error {}: {}",
+            status,
+            body["error"].as_str().unwrap_or_default()
+        )
+    })
+}
+
+fn servers(state: &mut State, argument: &str) -> anyhow::Result<String> {
+    let (action, node) = argument
+        .split_once(char::is_whitespace)
+        .map_or((argument, ""), |(action, node)| (action, node.trim()));
+    match (action, node) {
+        ("", _) if state.servers.is_empty() => Ok("no servers".to_string()),
+        ("", _) => Ok(state.servers.join("\n")),
+        ("add", node) if !node.is_empty() => {
+            if state.servers.iter().any(|server| server == node) {
+                return Ok(format!("{} is already a server", node));
+            }
+            state.servers.push(node.to_string());
+            state.mark_changed();
+            Ok(format!("added {}", node))
+        }
+        ("remove", node) if !node.is_empty() => {
+            let before = state.servers.len();
+            state.servers.retain(|server| server != node);
+            if state.servers.len() == before {
+                return Ok(format!("{} is not a server", node));
+            }
+            state.mark_changed();
+            Ok(format!("removed {}", node))
+        }
+        _ => Err(anyhow::anyhow!(
+            "usage: servers [add <node> | remove <node>]"
+        )),
+    }
+}
+
+fn saved_searches(state: &mut State, argument: &str) -> anyhow::Result<String> {
+    let mut words = argument.split_whitespace();
+    match (words.next(), words.next()) {
+        (None, _) if state.searches.is_empty() => Ok("no saved searches".to_string()),
+        (None, _) => Ok(state
+            .searches
+            .iter()
+            .map(|(name, search)| {
+                format!(
+                    "{}: {}, above {}, every {}s",
+                    name, search.query, search.threshold, search.interval
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")),
+        (Some("save"), Some(name)) => {
+            let text = words.collect::<Vec<_>>().join(" ");
+            if text.is_empty() {
+                return Err(anyhow::anyhow!("usage: searches save <name> <text>"));
+            }
+            let save: SaveSearchRequest = serde_json::from_value(json!({
+                "name": name,
+                "query": { "text": text },
+            }))?;
+            state
+                .searches
+                .insert(name.to_string(), SavedSearch::new(save));
+            state.mark_changed();
+            Ok(format!("saved {}", name))
+        }
+        (Some("run"), Some(name)) => {
+            let alerts = searches::run(state, name)?;
+            Ok(searches::notification(name, &alerts))
+        }
+        (Some("remove"), Some(name)) => match state.searches.remove(name) {
+            Some(_) => {
+                state.mark_changed();
+                Ok(format!("removed {}", name))
+            }
+            None => Err(anyhow::anyhow!("no saved search {:?}", name)),
+        },
+        _ => Err(anyhow::anyhow!(
+            "usage: searches [save <name> <text> | run <name> | remove <name>]"
+        )),
+    }
+}
+
+fn import(our: &Address, state: &State, target: &str) -> anyhow::Result<String> {
+    if target.is_empty() {
+        return Err(anyhow::anyhow!("usage: import <path | url>"));
+    }
+    if target.starts_with("http://") || target.starts_with("https://") {
+        let res = server(
+            state,
+            json!({ "IngestUrl": { "url": target } }),
+            None,
+            INGEST_TIMEOUT,
+        )?;
+        return Ok(match res["duplicate"].as_bool() {
+            Some(true) => format!("{} is already in the library", res["id"]),
+            _ => format!("imported {}", res["id"]),
+        });
+    }
+
+    let bytes = read_file(our, target)?;
+    let extension = target
+        .rsplit_once('.')
+        .map_or("", |(_, extension)| extension);
+    let res = match extension.to_ascii_lowercase().as_str() {
+        // JSON files hold a batch of documents, as POSTed to /librarian/ingest
+        "json" => {
+            let mut ingest: serde_json::Value = serde_json::from_slice(&bytes)?;
+            if ingest.is_array() {
+                ingest = json!({ "documents": ingest });
+            }
+            server(state, json!({ "Ingest": ingest }), None, INGEST_TIMEOUT)?
+        }
+        extension => {
+            let mime = match extension {
+                "html" | "htm" => "text/html",
+                "md" | "markdown" => "text/markdown",
+                "txt" => "text/plain",
+                _ => return Err(anyhow::anyhow!("cannot import {} files", extension)),
+            };
+            server(
+                state,
+                json!({ "IngestPayload": {} }),
+                Some(&Payload {
+                    mime: Some(mime.to_string()),
+                    bytes,
+                }),
+                INGEST_TIMEOUT,
+            )?
+        }
+    };
+    Ok(format!(
+        "imported {} documents in {} chunks",
+        res["documents"], res["chunks"]
+    ))
+}
+
+/// Reads a file from this package's vfs drive.
+fn read_file(our: &Address, path: &str) -> anyhow::Result<Vec<u8>> {
+    let path = if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    };
+    let res = send_and_await_response(
+        &Address {
+            node: our.node.clone(),
+            process: ProcessId::from_str("vfs:sys:uqbar").unwrap(),
+        },
+        &Request {
+            inherit: false,
+            expects_response: Some(VFS_TIMEOUT),
+            ipc: Some(
+                json!({
+                    "drive": our.process.package(),
+                    "action": { "GetEntry": path },
+                })
+                .to_string(),
+            ),
+            metadata: None,
+        },
+        None,
+    );
+    let Ok((_, Message::Response(_))) = res else {
+        return Err(anyhow::anyhow!("could not reach vfs"));
+    };
+    get_payload()
+        .map(|payload| payload.bytes)
+        .ok_or(anyhow::anyhow!("no file {}", path))
+}
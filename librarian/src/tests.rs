@@ -0,0 +1,448 @@
+//! The librarian run against process_lib's mock kernel.
+
+use process_lib::handler::{Effect, Handler};
+use process_lib::mock::{self, MockKernel, SentResponse};
+use serde_json::{json, Value};
+
+use super::*;
+
+fn our() -> Address {
+    "our.uq@librarian:librarian:drew.uq".parse().unwrap()
+}
+
+fn terminal() -> Address {
+    "our.uq@terminal:terminal:uqbar".parse().unwrap()
+}
+
+fn http_bindings() -> Address {
+    "our.uq@http_bindings:http_bindings:uqbar".parse().unwrap()
+}
+
+/// Queues an HTTP request as http_bindings forwards it.
+fn push_http(kernel: &mut MockKernel, method: &str, path: &str, body: Option<Value>) {
+    kernel.push_request(
+        &http_bindings(),
+        &json!({
+            "method": method,
+            "path": path,
+            "headers": { "content-type": "application/json" },
+            "query_params": {},
+            "url_params": {},
+        })
+        .to_string(),
+        body.map(|body| Payload {
+            mime: Some("application/json".to_string()),
+            bytes: body.to_string().into_bytes(),
+        }),
+    );
+}
+
+fn http_status(response: &SentResponse) -> u16 {
+    let ipc: Value = serde_json::from_str(response.response.ipc.as_deref().unwrap()).unwrap();
+    ipc["status"].as_u64().unwrap() as u16
+}
+
+fn http_body(response: &SentResponse) -> Value {
+    serde_json::from_slice(&response.payload.as_ref().unwrap().bytes).unwrap()
+}
+
+/// A server answering `"Ok"` with `body`.
+fn server_answering(
+    body: Value,
+) -> impl FnMut(&Address, &Request, Option<&Payload>) -> mock::Reply {
+    move |_, _, _| {
+        mock::reply(
+            "\"Ok\"",
+            Some(Payload {
+                mime: Some("application/json".to_string()),
+                bytes: body.to_string().into_bytes(),
+            }),
+        )
+    }
+}
+
+fn run_librarian(kernel: MockKernel) -> MockKernel {
+    kernel.run(|| run(our()))
+}
+
+#[test]
+fn binds_its_paths_and_schedules_a_tick() {
+    let kernel = run_librarian(MockKernel::new(our()));
+
+    let binds: Vec<Value> = kernel
+        .requests_to("http_bindings:http_bindings:uqbar")
+        .iter()
+        .map(|sent| serde_json::from_str(sent.request.ipc.as_deref().unwrap()).unwrap())
+        .collect();
+    assert_eq!(binds.len(), 16);
+    assert!(binds.contains(&json!({
+        "action": "bind-app",
+        "path": "/librarian/document/:id",
+        "app": "librarian",
+        "authenticated": false,
+    })));
+    assert!(binds.contains(&json!({
+        "action": "bind-app",
+        "path": "/librarian/searches/:name",
+        "app": "librarian",
+        "authenticated": true,
+    })));
+
+    let ticks = kernel.requests_to(&our().to_string());
+    assert_eq!(ticks.len(), 1);
+    assert_eq!(ticks[0].context.as_deref(), Some(searches::TICK_CONTEXT));
+}
+
+#[test]
+fn forwards_queries_to_the_first_server() {
+    let mut kernel = MockKernel::new(our());
+    kernel.on_request(
+        "drew.uq@server:librarian:drew.uq",
+        server_answering(json!({ "matches": [{ "id": "a", "score": 0.9 }] })),
+    );
+    push_http(
+        &mut kernel,
+        "POST",
+        "/librarian/vector",
+        Some(json!({ "text": "rust", "topK": 3 })),
+    );
+    let kernel = run_librarian(kernel);
+
+    let sent = kernel.requests_to("server:librarian:drew.uq");
+    assert_eq!(sent.len(), 1);
+    let ipc: Value = serde_json::from_str(sent[0].request.ipc.as_deref().unwrap()).unwrap();
+    assert_eq!(ipc, json!({ "Query": { "text": "rust", "topK": 3 } }));
+
+    assert_eq!(kernel.responses.len(), 1);
+    assert_eq!(http_status(&kernel.responses[0]), 200);
+    assert_eq!(
+        http_body(&kernel.responses[0]),
+        json!({ "matches": [{ "id": "a", "score": 0.9 }] })
+    );
+}
+
+#[test]
+fn asks_for_documents_like_another_by_id() {
+    let mut kernel = MockKernel::new(our());
+    kernel.on_request(
+        "drew.uq@server:librarian:drew.uq",
+        server_answering(json!({ "matches": [{ "id": "b", "score": 0.8 }] })),
+    );
+    kernel.push_request(
+        &http_bindings(),
+        &json!({
+            "method": "GET",
+            "path": "/librarian/document/a/similar",
+            "headers": {},
+            "query_params": { "topK": "2" },
+            "url_params": {},
+        })
+        .to_string(),
+        None,
+    );
+    let kernel = run_librarian(kernel);
+
+    let sent = kernel.requests_to("server:librarian:drew.uq");
+    assert_eq!(sent.len(), 1);
+    let ipc: Value = serde_json::from_str(sent[0].request.ipc.as_deref().unwrap()).unwrap();
+    assert_eq!(
+        ipc,
+        json!({ "QueryById": { "id": "a", "topK": 2, "includeMetadata": true } })
+    );
+
+    assert_eq!(http_status(&kernel.responses[0]), 200);
+    assert_eq!(
+        http_body(&kernel.responses[0]),
+        json!({ "matches": [{ "id": "b", "score": 0.8 }] })
+    );
+}
+
+#[test]
+fn answers_502_when_the_server_is_unreachable() {
+    let mut kernel = MockKernel::new(our());
+    kernel.on_request("server:librarian:drew.uq", |_, _, _| {
+        Err(SendErrorKind::Offline)
+    });
+    push_http(&mut kernel, "GET", "/librarian/document/a", None);
+    let kernel = run_librarian(kernel);
+
+    assert_eq!(kernel.responses.len(), 1);
+    assert_eq!(http_status(&kernel.responses[0]), 502);
+}
+
+#[test]
+fn fails_over_to_the_next_server() {
+    let mut kernel = MockKernel::new(our());
+    kernel.set_state(&State {
+        servers: vec!["drew.uq".to_string(), "other.uq".to_string()],
+        ..Default::default()
+    });
+    kernel.on_request("drew.uq@server:librarian:drew.uq", |_, _, _| {
+        Err(SendErrorKind::Offline)
+    });
+    kernel.on_request(
+        "other.uq@server:librarian:drew.uq",
+        server_answering(json!({ "documents": [{ "id": "a" }] })),
+    );
+    push_http(&mut kernel, "GET", "/librarian/document/a", None);
+    let kernel = run_librarian(kernel);
+
+    let sent = kernel.requests_to("server:librarian:drew.uq");
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0].target.node, "drew.uq");
+    assert_eq!(sent[1].target.node, "other.uq");
+    // the same request goes to the next server
+    assert_eq!(sent[0].request.ipc, sent[1].request.ipc);
+
+    assert_eq!(kernel.responses.len(), 1);
+    assert_eq!(kernel.responses[0].target, Some(http_bindings()));
+    assert_eq!(http_status(&kernel.responses[0]), 200);
+    assert_eq!(http_body(&kernel.responses[0]), json!({ "id": "a" }));
+}
+
+#[test]
+fn retries_a_server_that_timed_out_once() {
+    let mut kernel = MockKernel::new(our());
+    let mut answered = false;
+    kernel.on_request("server:librarian:drew.uq", move |_, _, _| {
+        if !std::mem::replace(&mut answered, true) {
+            return Err(SendErrorKind::Timeout);
+        }
+        mock::reply("\"Ok\"", None)
+    });
+    push_http(
+        &mut kernel,
+        "POST",
+        "/librarian/vector",
+        Some(json!({ "text": "rust" })),
+    );
+    let kernel = run_librarian(kernel);
+
+    let sent = kernel.requests_to("drew.uq@server:librarian:drew.uq");
+    assert_eq!(sent.len(), 2);
+    assert_eq!(sent[0].request.ipc, sent[1].request.ipc);
+    assert_eq!(kernel.responses.len(), 1);
+    assert_eq!(http_status(&kernel.responses[0]), 200);
+
+    // an ingest the server may have done already is not sent again
+
+    let mut kernel = MockKernel::new(our());
+    kernel.on_request("server:librarian:drew.uq", |_, _, _| {
+        Err(SendErrorKind::Timeout)
+    });
+    push_http(
+        &mut kernel,
+        "POST",
+        "/librarian/ingest?id=notes",
+        Some(json!({ "text": "notes" })),
+    );
+    let kernel = run_librarian(kernel);
+
+    assert_eq!(kernel.requests_to("server:librarian:drew.uq").len(), 1);
+    assert_eq!(kernel.responses.len(), 1);
+    assert_eq!(http_status(&kernel.responses[0]), 504);
+}
+
+#[test]
+fn does_not_fail_over_requests_that_change_the_library() {
+    let mut kernel = MockKernel::new(our());
+    kernel.set_state(&State {
+        servers: vec!["drew.uq".to_string(), "other.uq".to_string()],
+        ..Default::default()
+    });
+    kernel.on_request("drew.uq@server:librarian:drew.uq", |_, _, _| {
+        Err(SendErrorKind::Offline)
+    });
+    kernel.on_request(
+        "other.uq@server:librarian:drew.uq",
+        server_answering(json!({})),
+    );
+    push_http(
+        &mut kernel,
+        "POST",
+        "/librarian/delete",
+        Some(json!({ "ids": ["a"] })),
+    );
+    let kernel = run_librarian(kernel);
+
+    let sent = kernel.requests_to("server:librarian:drew.uq");
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].target.node, "drew.uq");
+    assert_eq!(kernel.responses.len(), 1);
+    assert_eq!(http_status(&kernel.responses[0]), 502);
+}
+
+#[test]
+fn waits_on_servers_as_it_forwards_to_them() {
+    // stats are read the same from any server
+    let mut kernel = MockKernel::new(our());
+    kernel.set_state(&State {
+        servers: vec!["drew.uq".to_string(), "other.uq".to_string()],
+        ..Default::default()
+    });
+    kernel.on_request("drew.uq@server:librarian:drew.uq", |_, _, _| {
+        Err(SendErrorKind::Offline)
+    });
+    kernel.on_request(
+        "other.uq@server:librarian:drew.uq",
+        server_answering(json!({ "documents": 3 })),
+    );
+    push_http(&mut kernel, "GET", "/librarian/stats", None);
+    let kernel = run_librarian(kernel);
+
+    let stats: Vec<&str> = kernel
+        .requests_to("server:librarian:drew.uq")
+        .iter()
+        .filter(|sent| sent.request.ipc.as_deref() == Some("\"Stats\""))
+        .map(|sent| sent.target.node.as_str())
+        .collect();
+    assert_eq!(stats, ["drew.uq", "other.uq"]);
+    assert_eq!(http_status(&kernel.responses[0]), 200);
+    assert_eq!(http_body(&kernel.responses[0])["documents"], 3);
+
+    // a command that may have been run already is not sent again
+    let mut kernel = MockKernel::new(our());
+    kernel.set_state(&State {
+        servers: vec!["drew.uq".to_string(), "other.uq".to_string()],
+        ..Default::default()
+    });
+    kernel.on_request("server:librarian:drew.uq", |_, _, _| {
+        Err(SendErrorKind::Timeout)
+    });
+    kernel.push_request(&terminal(), "delete a", None);
+    let kernel = run_librarian(kernel);
+
+    let sent = kernel.requests_to("server:librarian:drew.uq");
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].target.node, "drew.uq");
+    assert!(kernel
+        .printed
+        .iter()
+        .any(|printed| printed.contains("librarian server timed out")));
+}
+
+#[test]
+fn answers_504_when_every_server_times_out() {
+    let mut kernel = MockKernel::new(our());
+    kernel.on_request("server:librarian:drew.uq", |_, _, _| {
+        Err(SendErrorKind::Timeout)
+    });
+    push_http(
+        &mut kernel,
+        "POST",
+        "/librarian/vector",
+        Some(json!({ "text": "rust" })),
+    );
+    let kernel = run_librarian(kernel);
+
+    assert_eq!(kernel.requests_to("server:librarian:drew.uq").len(), 2);
+    assert_eq!(kernel.responses.len(), 1);
+    assert_eq!(kernel.responses[0].target, Some(http_bindings()));
+    assert_eq!(http_status(&kernel.responses[0]), 504);
+}
+
+#[test]
+fn answers_unknown_routes() {
+    let mut kernel = MockKernel::new(our());
+    push_http(&mut kernel, "GET", "/librarian/nothing", None);
+    push_http(&mut kernel, "DELETE", "/librarian/alerts", None);
+    let kernel = run_librarian(kernel);
+
+    assert_eq!(http_status(&kernel.responses[0]), 404);
+    assert_eq!(http_status(&kernel.responses[1]), 405);
+    assert!(kernel.requests_to("server:librarian:drew.uq").is_empty());
+}
+
+#[test]
+fn keeps_saved_searches_across_restarts() {
+    let mut kernel = MockKernel::new(our());
+    push_http(
+        &mut kernel,
+        "POST",
+        "/librarian/searches",
+        Some(json!({ "name": "rust", "query": { "text": "rust" } })),
+    );
+    let mut kernel = run_librarian(kernel);
+    assert_eq!(http_status(&kernel.responses[0]), 200);
+    let state: State = kernel.get_state().unwrap();
+    assert!(state.searches.contains_key("rust"));
+
+    push_http(&mut kernel, "GET", "/librarian/searches/rust", None);
+    let kernel = run_librarian(kernel);
+    assert_eq!(http_status(&kernel.responses[1]), 200);
+    assert_eq!(http_body(&kernel.responses[1])["name"], "rust");
+}
+
+#[test]
+fn adds_servers_from_the_terminal() {
+    let mut kernel = MockKernel::new(our());
+    kernel.push_request(&terminal(), "servers add other.uq", None);
+    let kernel = run_librarian(kernel);
+
+    assert!(kernel.printed.contains(&"added other.uq".to_string()));
+    let state: State = kernel.get_state().unwrap();
+    assert_eq!(state.servers, vec!["drew.uq", "other.uq"]);
+}
+
+#[test]
+fn handles_messages_without_side_effects() {
+    // no kernel is running, so any side effect would panic
+    let mut process = Process {
+        router: router(),
+        librarian: Librarian {
+            our: our(),
+            state: State::default(),
+        },
+    };
+    let effects = process.handle(
+        &terminal(),
+        Message::Request(Request {
+            inherit: false,
+            expects_response: None,
+            ipc: Some("servers add other.uq".to_string()),
+            metadata: None,
+        }),
+        None,
+    );
+
+    assert!(matches!(&effects[0], Effect::Print(output) if output == "added other.uq"));
+    let Effect::SetState(bytes) = &effects[1] else {
+        panic!("expected the state to be saved, got {:?}", effects);
+    };
+    let state: State = state::decode(bytes).unwrap();
+    assert_eq!(state.servers, vec!["drew.uq", "other.uq"]);
+    assert_eq!(effects.len(), 2);
+}
+
+#[test]
+fn forgets_the_earliest_documents_a_saved_search_saw() {
+    let mut kernel = MockKernel::new(our());
+    let mut search = SavedSearch::new(SaveSearchRequest {
+        name: "rust".to_string(),
+        query: json!({ "text": "rust" }),
+        threshold: 0.5,
+        interval: 60,
+    });
+    search.last_run = Some(1);
+    search.seen = (0..searches::MAX_SEEN).map(|i| i.to_string()).collect();
+    let mut state = State::default();
+    state.searches.insert("rust".to_string(), search);
+    kernel.set_state(&state);
+    kernel.on_request(
+        SERVER_PROCESS,
+        server_answering(json!({ "matches": [
+            { "id": "new", "score": 0.9 },
+            { "id": "1", "score": 0.8 },
+        ] })),
+    );
+    kernel.push_request(&terminal(), "searches run rust", None);
+    let kernel = run_librarian(kernel);
+
+    let state: State = kernel.get_state().unwrap();
+    let alerts: Vec<&str> = state.alerts.iter().map(|alert| alert.id.as_str()).collect();
+    assert_eq!(alerts, ["new"]);
+    let seen = &state.searches["rust"].seen;
+    assert_eq!(seen.len(), searches::MAX_SEEN);
+    assert_eq!(seen.front().map(String::as_str), Some("1"));
+    assert_eq!(seen.back().map(String::as_str), Some("new"));
+}
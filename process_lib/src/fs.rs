@@ -0,0 +1,165 @@
+//! A client for `filesystem:sys:uqbar`, which stores files by uuid.
+
+use serde::{Deserialize, Serialize};
+
+use super::kernel::{get_payload, send_and_await_response};
+use super::types::*;
+
+const FS_TIMEOUT: u64 = 5;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum FsAction {
+    Write,
+    Replace(u128),
+    Append(Option<u128>),
+    Read(u128),
+    ReadChunk(ReadChunkRequest),
+    Delete(u128),
+    Length(u128),
+    //  process state management
+    GetState,
+    SetState,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReadChunkRequest {
+    pub file_uuid: u128,
+    pub start: u64,
+    pub length: u64,
+}
+
+/// What the filesystem answers, wrapped in a `Result` in the response ipc.
+#[derive(Debug, Deserialize)]
+pub enum FsResponse {
+    Write(u128),
+    Read(u128),
+    ReadChunk(u128),
+    Append(u128),
+    Delete(u128),
+    Length(u64),
+    GetState,
+    SetState,
+}
+
+#[derive(Debug)]
+pub enum FsError {
+    /// the filesystem did not answer, or its answer could not be delivered
+    Unreachable(SendErrorKind),
+    /// the filesystem refused the action, with its own description of why
+    Refused(serde_json::Value),
+    /// the response ipc was not an `FsResponse`
+    BadResponse(String),
+    /// the filesystem answered a different action, or left out the file's bytes
+    UnexpectedResponse(FsResponse),
+}
+
+impl std::fmt::Display for FsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FsError::Unreachable(SendErrorKind::Offline) => write!(f, "filesystem is offline"),
+            FsError::Unreachable(SendErrorKind::Timeout) => {
+                write!(f, "filesystem did not answer in {}s", FS_TIMEOUT)
+            }
+            FsError::Refused(e) => write!(f, "filesystem error: {}", e),
+            FsError::BadResponse(e) => write!(f, "malformed filesystem response: {}", e),
+            FsError::UnexpectedResponse(res) => {
+                write!(f, "unexpected filesystem response {:?}", res)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FsError {}
+
+pub type Result<T> = std::result::Result<T, FsError>;
+
+/// Sends `action` to our node's filesystem, returning its answer and payload.
+pub fn call(
+    our: &Address,
+    action: &FsAction,
+    payload: Option<&Payload>,
+) -> Result<(FsResponse, Option<Payload>)> {
+    let res = send_and_await_response(
+        &Address {
+            node: our.node.clone(),
+            process: ProcessId::new("filesystem", "sys", "uqbar"),
+        },
+        &Request {
+            inherit: false,
+            expects_response: Some(FS_TIMEOUT),
+            ipc: Some(serde_json::to_string(action).unwrap()),
+            metadata: None,
+        },
+        payload,
+    );
+    let response = match res {
+        Ok((_, Message::Response((response, _)))) => response,
+        Ok((_, Message::Request(_))) => {
+            return Err(FsError::BadResponse("got a request".to_string()))
+        }
+        Err(e) => return Err(FsError::Unreachable(e.kind)),
+    };
+    let result: std::result::Result<FsResponse, serde_json::Value> =
+        serde_json::from_str(response.ipc.as_deref().unwrap_or_default())
+            .map_err(|e| FsError::BadResponse(e.to_string()))?;
+    match result {
+        Ok(res) => Ok((res, get_payload())),
+        Err(e) => Err(FsError::Refused(e)),
+    }
+}
+
+/// Stores `bytes` as a new file, returning its uuid.
+pub fn write(our: &Address, bytes: Vec<u8>) -> Result<u128> {
+    match call(our, &FsAction::Write, Some(&Payload { mime: None, bytes }))? {
+        (FsResponse::Write(uuid), _) => Ok(uuid),
+        (res, _) => Err(FsError::UnexpectedResponse(res)),
+    }
+}
+
+/// Appends `bytes` to a file, creating a new one if `file` is `None`. Returns
+/// the file's uuid.
+pub fn append(our: &Address, file: Option<u128>, bytes: Vec<u8>) -> Result<u128> {
+    match call(
+        our,
+        &FsAction::Append(file),
+        Some(&Payload { mime: None, bytes }),
+    )? {
+        (FsResponse::Append(uuid), _) => Ok(uuid),
+        (res, _) => Err(FsError::UnexpectedResponse(res)),
+    }
+}
+
+pub fn read(our: &Address, file: u128) -> Result<Vec<u8>> {
+    match call(our, &FsAction::Read(file), None)? {
+        (FsResponse::Read(_), Some(payload)) => Ok(payload.bytes),
+        (res, _) => Err(FsError::UnexpectedResponse(res)),
+    }
+}
+
+/// Reads `length` bytes of a file from byte `start` on.
+pub fn read_chunk(our: &Address, file: u128, start: u64, length: u64) -> Result<Vec<u8>> {
+    let action = FsAction::ReadChunk(ReadChunkRequest {
+        file_uuid: file,
+        start,
+        length,
+    });
+    match call(our, &action, None)? {
+        (FsResponse::ReadChunk(_), Some(payload)) => Ok(payload.bytes),
+        (res, _) => Err(FsError::UnexpectedResponse(res)),
+    }
+}
+
+/// The size of a file in bytes.
+pub fn len(our: &Address, file: u128) -> Result<u64> {
+    match call(our, &FsAction::Length(file), None)? {
+        (FsResponse::Length(length), _) => Ok(length),
+        (res, _) => Err(FsError::UnexpectedResponse(res)),
+    }
+}
+
+pub fn delete(our: &Address, file: u128) -> Result<()> {
+    match call(our, &FsAction::Delete(file), None)? {
+        (FsResponse::Delete(_), _) => Ok(()),
+        (res, _) => Err(FsError::UnexpectedResponse(res)),
+    }
+}
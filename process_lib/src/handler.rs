@@ -0,0 +1,96 @@
+//! Message handling apart from the receive loop: a process implements
+//! [`Handler`], whose methods say what to do about each message as a list of
+//! [`Effect`]s, and [`run`] receives messages and carries the effects out.
+//! Handlers may still await responses to their own requests, since what comes
+//! back is part of handling the message, so the separation is only partial:
+//! a handler that awaits nothing can be tested without a kernel, while one
+//! that does needs the mock kernel to answer what it awaits. Everything else
+//! handlers do goes through effects.
+
+use super::kernel::{get_payload, print_to_terminal, receive, send_request, send_response};
+use super::state::{self, Versioned};
+use super::types::*;
+
+/// Something a handler does, carried out once it returns.
+#[derive(Debug, Clone)]
+pub enum Effect {
+    Send {
+        target: Address,
+        request: Request,
+        context: Option<Context>,
+        payload: Option<Payload>,
+    },
+    /// answers the message being handled
+    Respond {
+        response: Response,
+        payload: Option<Payload>,
+    },
+    /// saves the bytes as our state, see [`Effect::set_state`]
+    SetState(Vec<u8>),
+    Print(String),
+}
+
+impl Effect {
+    pub fn set_state<T: Versioned>(state: &T) -> Self {
+        Effect::SetState(state::encode(state))
+    }
+
+    pub fn print(message: impl Into<String>) -> Self {
+        Effect::Print(message.into())
+    }
+
+    pub fn execute(self) {
+        match self {
+            Effect::Send {
+                target,
+                request,
+                context,
+                payload,
+            } => send_request(&target, &request, context.as_ref(), payload.as_ref()),
+            Effect::Respond { response, payload } => send_response(&response, payload.as_ref()),
+            Effect::SetState(bytes) => super::kernel::set_state(&bytes),
+            Effect::Print(message) => print_to_terminal(0, &message),
+        }
+    }
+}
+
+pub trait Handler {
+    /// What to do before the first message arrives.
+    fn start(&mut self) -> Vec<Effect> {
+        vec![]
+    }
+
+    /// Handles a message, with the payload it came with.
+    fn handle(
+        &mut self,
+        source: &Address,
+        message: Message,
+        payload: Option<Payload>,
+    ) -> Vec<Effect>;
+
+    /// Handles a request of ours that could not be delivered or timed out,
+    /// with the context it was sent with.
+    fn handle_send_error(&mut self, error: SendError, context: Option<Context>) -> Vec<Effect>;
+}
+
+/// Runs `handler` for as long as the process lives.
+pub fn run(handler: &mut impl Handler) {
+    execute(handler.start());
+    loop {
+        let effects = match receive() {
+            Ok((source, message)) => {
+                // taken now, as any request the handler awaits replaces it
+                let payload = get_payload();
+                handler.handle(&source, message, payload)
+            }
+            Err((error, context)) => handler.handle_send_error(error, context),
+        };
+        execute(effects);
+    }
+}
+
+pub fn execute(effects: Vec<Effect>) {
+    for effect in effects {
+        effect.execute();
+    }
+}
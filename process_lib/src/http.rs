@@ -0,0 +1,308 @@
+//! A client for `http_client:sys:uqbar`, which makes HTTP requests on our behalf.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+
+use super::kernel::{get_payload, send_and_await_response};
+use super::types::{Address, Message, Payload, ProcessId, SendErrorKind};
+use super::urls;
+
+const DEFAULT_TIMEOUT: u64 = 30;
+const DEFAULT_MAX_REDIRECTS: u32 = 5;
+/// how much of an error response's body is kept in the error
+const ERROR_BODY_CHARS: usize = 500;
+/// headers always treated as credentials, see [`Request::credential`]
+const CREDENTIAL_HEADERS: &[&str] = &["authorization", "proxy-authorization", "cookie"];
+
+/// An outgoing HTTP request, e.g.
+/// `Request::post(uri).credential("Api-Key", key).json(&body)?.send(our)?.error_for_status()?.json()`.
+#[derive(Debug, Clone)]
+pub struct Request {
+    method: String,
+    uri: String,
+    headers: HashMap<String, String>,
+    /// names of headers set with [`Request::credential`]
+    credentials: Vec<String>,
+    body: Option<Vec<u8>>,
+    timeout: u64,
+    max_redirects: u32,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+    /// where the response came from, after any redirects
+    pub uri: String,
+}
+
+#[derive(Debug)]
+pub enum HttpError {
+    /// http_client did not answer in time, or could not be reached
+    Unreachable {
+        uri: String,
+        kind: SendErrorKind,
+    },
+    /// http_client answered with something other than a response
+    BadResponse {
+        uri: String,
+    },
+    /// the server answered with a 4xx or 5xx status
+    Status {
+        uri: String,
+        status: u16,
+        body: String,
+    },
+    TooManyRedirects {
+        uri: String,
+    },
+    /// the request or response body was not the JSON expected
+    Json {
+        uri: String,
+        error: serde_json::Error,
+    },
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpError::Unreachable { uri, kind } => {
+                write!(f, "request to {} failed: {:?}", uri, kind)
+            }
+            HttpError::BadResponse { uri } => {
+                write!(f, "http_client sent an unexpected message for {}", uri)
+            }
+            HttpError::Status { uri, status, body } => {
+                write!(f, "{} returned {}: {}", uri, status, body)
+            }
+            HttpError::TooManyRedirects { uri } => write!(f, "too many redirects from {}", uri),
+            HttpError::Json { uri, error } => write!(f, "bad JSON for {}: {}", uri, error),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+pub type Result<T> = std::result::Result<T, HttpError>;
+
+impl Request {
+    pub fn new(method: &str, uri: &str) -> Self {
+        Request {
+            method: method.to_ascii_uppercase(),
+            uri: uri.to_string(),
+            headers: HashMap::new(),
+            credentials: vec![],
+            body: None,
+            timeout: DEFAULT_TIMEOUT,
+            max_redirects: DEFAULT_MAX_REDIRECTS,
+        }
+    }
+
+    pub fn get(uri: &str) -> Self {
+        Request::new("GET", uri)
+    }
+
+    pub fn post(uri: &str) -> Self {
+        Request::new("POST", uri)
+    }
+
+    /// Sets a header, replacing any earlier value of the same name.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers
+            .retain(|key, _| !key.eq_ignore_ascii_case(name));
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Sets a header carrying a secret, such as an API key, which is dropped
+    /// when a redirect leads to another origin. `Authorization`,
+    /// `Proxy-Authorization` and `Cookie` are dropped even if set with
+    /// [`Request::header`].
+    pub fn credential(mut self, name: &str, value: &str) -> Self {
+        self.credentials.push(name.to_string());
+        self.header(name, value)
+    }
+
+    pub fn body(mut self, body: Vec<u8>) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Sends `body` as JSON.
+    pub fn json<T: Serialize + ?Sized>(self, body: &T) -> Result<Self> {
+        let bytes = serde_json::to_vec(body).map_err(|error| HttpError::Json {
+            uri: self.uri.clone(),
+            error,
+        })?;
+        Ok(self.header("content-type", "application/json").body(bytes))
+    }
+
+    /// How long to wait for each response, in seconds.
+    pub fn timeout(mut self, seconds: u64) -> Self {
+        self.timeout = seconds;
+        self
+    }
+
+    /// How many redirects to follow before giving up; 0 returns redirects as they are.
+    pub fn max_redirects(mut self, max_redirects: u32) -> Self {
+        self.max_redirects = max_redirects;
+        self
+    }
+
+    /// Sends the request, following redirects. Any status is returned as a
+    /// `Response`; see [`Response::error_for_status`].
+    pub fn send(&self, our: &Address) -> Result<Response> {
+        let mut request = self.clone();
+        let mut redirects = 0;
+        loop {
+            let res = request.send_once(our)?;
+            let location = match res.status {
+                301 | 302 | 303 | 307 | 308 => res.header("location"),
+                _ => None,
+            };
+            let Some(location) = location else {
+                return Ok(res);
+            };
+            if redirects == self.max_redirects {
+                return match self.max_redirects {
+                    0 => Ok(res),
+                    _ => Err(HttpError::TooManyRedirects {
+                        uri: self.uri.clone(),
+                    }),
+                };
+            }
+            redirects += 1;
+            let location = urls::resolve(&request.uri, location);
+            // credentials are for the origin they were set for
+            if urls::origin(&location) != urls::origin(&request.uri) {
+                let credentials = &request.credentials;
+                request.headers.retain(|key, _| {
+                    !CREDENTIAL_HEADERS.contains(&key.to_ascii_lowercase().as_str())
+                        && !credentials
+                            .iter()
+                            .any(|credential| credential.eq_ignore_ascii_case(key))
+                });
+            }
+            request.uri = location;
+            // like browsers, turn a redirected POST into a GET unless told to repeat it
+            if res.status == 303 || (matches!(res.status, 301 | 302) && request.method == "POST") {
+                request.method = "GET".to_string();
+                request.body = None;
+                request
+                    .headers
+                    .retain(|key, _| !key.eq_ignore_ascii_case("content-type"));
+            }
+        }
+    }
+
+    fn send_once(&self, our: &Address) -> Result<Response> {
+        let res = send_and_await_response(
+            &Address {
+                node: our.node.clone(),
+                process: ProcessId::new("http_client", "sys", "uqbar"),
+            },
+            &super::types::Request {
+                inherit: false,
+                expects_response: Some(self.timeout),
+                ipc: Some(
+                    json!({
+                        "method": self.method,
+                        "headers": self.headers,
+                        "uri": self.uri,
+                    })
+                    .to_string(),
+                ),
+                metadata: None,
+            },
+            self.body
+                .as_ref()
+                .map(|bytes| Payload {
+                    mime: Some("application/octet-stream".to_string()),
+                    bytes: bytes.clone(),
+                })
+                .as_ref(),
+        );
+        let response = match res {
+            Ok((_, Message::Response((response, _)))) => response,
+            Ok(_) => {
+                return Err(HttpError::BadResponse {
+                    uri: self.uri.clone(),
+                })
+            }
+            Err(e) => {
+                return Err(HttpError::Unreachable {
+                    uri: self.uri.clone(),
+                    kind: e.kind,
+                })
+            }
+        };
+        // http_client describes the response as `{"status": ..., "headers": {...}}`
+        let meta: serde_json::Value = response
+            .ipc
+            .and_then(|ipc| serde_json::from_str(&ipc).ok())
+            .ok_or(HttpError::BadResponse {
+                uri: self.uri.clone(),
+            })?;
+        let status = meta["status"]
+            .as_u64()
+            .and_then(|status| u16::try_from(status).ok())
+            .ok_or(HttpError::BadResponse {
+                uri: self.uri.clone(),
+            })?;
+        Ok(Response {
+            status,
+            headers: meta["headers"]
+                .as_object()
+                .map(|headers| {
+                    headers
+                        .iter()
+                        .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            body: get_payload()
+                .map(|payload| payload.bytes)
+                .unwrap_or_default(),
+            uri: self.uri.clone(),
+        })
+    }
+}
+
+impl Response {
+    /// Looks up a response header, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Turns a 4xx or 5xx response into an error carrying the start of its body.
+    pub fn error_for_status(self) -> Result<Self> {
+        if self.status < 400 {
+            return Ok(self);
+        }
+        Err(HttpError::Status {
+            status: self.status,
+            body: String::from_utf8_lossy(&self.body)
+                .chars()
+                .take(ERROR_BODY_CHARS)
+                .collect(),
+            uri: self.uri,
+        })
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_slice(&self.body).map_err(|error| HttpError::Json {
+            uri: self.uri.clone(),
+            error,
+        })
+    }
+}
@@ -0,0 +1,509 @@
+//! Serving HTTP through `http_bindings:http_bindings:uqbar`: routes are declared
+//! once on a [`Router`], which binds their paths and dispatches the requests
+//! http_bindings forwards to the matching handler.
+
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::collections::HashMap;
+
+use super::handler::{self, Effect};
+use super::kernel::get_payload;
+use super::types::*;
+use super::urls;
+
+/// A request forwarded by http_bindings.
+#[derive(Debug, Default)]
+pub struct HttpRequest {
+    pub method: String,
+    /// the path as bound, e.g. `/librarian/document/:id`
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    /// path parameters by name, percent-decoded
+    pub params: HashMap<String, String>,
+    /// query string parameters by name, percent-decoded
+    pub query: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// Parses the ipc of a request from http_bindings, taking the body from `payload`.
+    pub fn parse(ipc: &str, payload: Option<Payload>) -> anyhow::Result<Self> {
+        let message: serde_json::Value = serde_json::from_str(ipc)?;
+        let strings = |key: &str| -> HashMap<String, String> {
+            message[key]
+                .as_object()
+                .map(|object| {
+                    object
+                        .iter()
+                        .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let path = message["path"]
+            .as_str()
+            .ok_or(anyhow::anyhow!("http request without a path"))?;
+        let (path, query_string) = path.split_once('?').unwrap_or((path, ""));
+        let mut query = parse_query(query_string);
+        query.extend(strings("query_params"));
+        Ok(HttpRequest {
+            method: message["method"]
+                .as_str()
+                .unwrap_or("GET")
+                .to_ascii_uppercase(),
+            path: path.to_string(),
+            headers: strings("headers"),
+            params: strings("url_params")
+                .into_iter()
+                .map(|(k, v)| (k, urls::decode_component(&v)))
+                .collect(),
+            query,
+            body: payload.map(|payload| payload.bytes).unwrap_or_default(),
+        })
+    }
+
+    /// Looks up a header, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn param(&self, name: &str) -> &str {
+        self.params.get(name).map_or("", |value| value.as_str())
+    }
+
+    /// A query parameter parsed as `T`, or `None` if it is missing or malformed.
+    pub fn query_as<T: std::str::FromStr>(&self, name: &str) -> Option<T> {
+        self.query.get(name)?.parse().ok()
+    }
+
+    /// Parses the body as JSON, or gives the 400 response to answer with.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, HttpResponse> {
+        serde_json::from_slice(&self.body).map_err(|e| {
+            HttpResponse::json(
+                400,
+                &json!({ "error": format!("expected a JSON request body: {}", e) }),
+            )
+        })
+    }
+}
+
+/// Splits `a=1&b=two` into its percent-decoded parameters. A repeated key
+/// keeps its last value.
+pub fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            (
+                urls::decode_component(&key.replace('+', " ")),
+                urls::decode_component(&value.replace('+', " ")),
+            )
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn new(status: u16, content_type: &str, body: Vec<u8>) -> Self {
+        HttpResponse {
+            status,
+            headers: HashMap::from([("Content-Type".to_string(), content_type.to_string())]),
+            body,
+        }
+    }
+
+    pub fn json(status: u16, body: &serde_json::Value) -> Self {
+        HttpResponse::new(status, "application/json", body.to_string().into_bytes())
+    }
+
+    pub fn html(body: String) -> Self {
+        HttpResponse::new(200, "text/html", body.into_bytes())
+    }
+
+    pub fn not_found() -> Self {
+        HttpResponse::new(404, "text/plain", b"Not Found".to_vec())
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Answers the request being handled, see [`HttpResponse::into_effect`].
+    pub fn send(self) {
+        self.into_effect().execute()
+    }
+
+    /// The response to http_bindings. The payload's mime is the response's
+    /// content type.
+    pub fn into_effect(self) -> Effect {
+        let mime = self
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.clone());
+        Effect::Respond {
+            response: Response {
+                inherit: false,
+                ipc: Some(
+                    json!({
+                        "status": self.status,
+                        "headers": self.headers,
+                    })
+                    .to_string(),
+                ),
+                metadata: None,
+            },
+            payload: Some(Payload {
+                mime,
+                bytes: self.body,
+            }),
+        }
+    }
+}
+
+/// A route's handler, answering with an `R`: an [`HttpResponse`] unless the
+/// router says otherwise, see [`Router`].
+pub type Handler<S, R = HttpResponse> = fn(&mut S, &HttpRequest) -> R;
+
+struct Route<S, R> {
+    method: &'static str,
+    /// e.g. `/app/document/{id}`, where `{id}` matches any one segment
+    pattern: &'static str,
+    authenticated: bool,
+    handler: Handler<S, R>,
+}
+
+/// Routes requests by method and path pattern to handlers taking some `S`,
+/// e.g. `.route("GET", "/app/document/{id}", false, document)`. Handlers answer
+/// with an `R`, which processes that do not always answer right away can set
+/// to something an [`HttpResponse`] converts into.
+pub struct Router<S, R = HttpResponse> {
+    app: String,
+    routes: Vec<Route<S, R>>,
+}
+
+impl<S, R: From<HttpResponse>> Router<S, R> {
+    /// A router for the paths of `app`, as http_bindings knows it.
+    pub fn new(app: &str) -> Self {
+        Router {
+            app: app.to_string(),
+            routes: vec![],
+        }
+    }
+
+    /// Adds a route; `authenticated` routes are only served to our own node's user.
+    /// http_bindings authenticates paths rather than methods, so every route
+    /// for a path must agree on it.
+    ///
+    /// # Panics
+    ///
+    /// If another route for the same path was added with the other `authenticated`.
+    pub fn route(
+        mut self,
+        method: &'static str,
+        pattern: &'static str,
+        authenticated: bool,
+        handler: Handler<S, R>,
+    ) -> Self {
+        let path = bound_path(pattern);
+        if let Some(other) = self
+            .routes
+            .iter()
+            .find(|route| bound_path(route.pattern) == path && route.authenticated != authenticated)
+        {
+            panic!(
+                "{} {} and {} {} share a path but not whether it is authenticated",
+                other.method, other.pattern, method, pattern
+            );
+        }
+        self.routes.push(Route {
+            method,
+            pattern,
+            authenticated,
+            handler,
+        });
+        self
+    }
+
+    /// Asks http_bindings on our node to forward requests for every routed path.
+    pub fn bind(&self, our: &Address) {
+        handler::execute(self.bindings(our));
+    }
+
+    /// The requests [`Router::bind`] sends.
+    pub fn bindings(&self, our: &Address) -> Vec<Effect> {
+        let mut paths: Vec<(String, bool)> = vec![];
+        for route in &self.routes {
+            let path = bound_path(route.pattern);
+            if !paths.iter().any(|(bound, _)| *bound == path) {
+                paths.push((path, route.authenticated));
+            }
+        }
+        let bindings_address = Address {
+            node: our.node.clone(),
+            process: ProcessId::new("http_bindings", "http_bindings", "uqbar"),
+        };
+        paths
+            .into_iter()
+            .map(|(path, authenticated)| Effect::Send {
+                target: bindings_address.clone(),
+                request: Request {
+                    inherit: false,
+                    expects_response: None,
+                    ipc: Some(
+                        json!({
+                            "action": "bind-app",
+                            "path": path,
+                            "app": self.app,
+                            "authenticated": authenticated,
+                        })
+                        .to_string(),
+                    ),
+                    metadata: None,
+                },
+                context: None,
+                payload: None,
+            })
+            .collect()
+    }
+
+    /// Runs the handler routed for `request`, answering 404 for unknown paths
+    /// and 405 for methods the path does not serve.
+    pub fn handle(&self, state: &mut S, request: &mut HttpRequest) -> R {
+        let mut allowed = vec![];
+        for route in &self.routes {
+            let Some(params) = match_path(route.pattern, &request.path) else {
+                continue;
+            };
+            if route.method != request.method {
+                allowed.push(route.method);
+                continue;
+            }
+            for (name, value) in params {
+                request.params.entry(name).or_insert(value);
+            }
+            return (route.handler)(state, request);
+        }
+        if allowed.is_empty() {
+            return HttpResponse::not_found().into();
+        }
+        HttpResponse::json(405, &json!({ "error": "method not allowed" }))
+            .header("Allow", &allowed.join(", "))
+            .into()
+    }
+
+    /// The answer to a request from http_bindings carrying `payload`.
+    pub fn answer(&self, state: &mut S, ipc: &str, payload: Option<Payload>) -> R {
+        match HttpRequest::parse(ipc, payload) {
+            Ok(mut request) => self.handle(state, &mut request),
+            Err(e) => HttpResponse::json(400, &json!({ "error": e.to_string() })).into(),
+        }
+    }
+}
+
+impl<S> Router<S> {
+    /// Answers a request from http_bindings, see [`Router::handle`].
+    pub fn serve(&self, state: &mut S, ipc: &str) {
+        self.answer(state, ipc, get_payload()).send()
+    }
+}
+
+/// The path to bind for a pattern: http_bindings writes parameters as `:name`.
+fn bound_path(pattern: &str) -> String {
+    pattern
+        .split('/')
+        .map(|segment| match param_name(segment) {
+            Some(name) => format!(":{}", name),
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn param_name(segment: &str) -> Option<&str> {
+    segment.strip_prefix('{')?.strip_suffix('}')
+}
+
+/// Matches a request path against a pattern, returning the path parameters.
+/// http_bindings passes on the path as bound, so `:name` segments match the
+/// parameter of the same name, with the value in the request's `url_params`.
+fn match_path(pattern: &str, path: &str) -> Option<Vec<(String, String)>> {
+    let pattern: Vec<&str> = pattern.trim_end_matches('/').split('/').collect();
+    let path: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+    if pattern.len() != path.len() {
+        return None;
+    }
+    let mut params = vec![];
+    for (expected, actual) in pattern.into_iter().zip(path) {
+        match param_name(expected) {
+            Some(name) if actual == format!(":{}", name) => {}
+            Some(name) if !actual.is_empty() => {
+                params.push((name.to_string(), urls::decode_component(actual)))
+            }
+            None if expected == actual => {}
+            _ => return None,
+        }
+    }
+    Some(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(pairs: &[(&str, &str)]) -> Option<Vec<(String, String)>> {
+        Some(
+            pairs
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn matches_literal_paths_ignoring_trailing_slashes() {
+        assert_eq!(match_path("/app/stats", "/app/stats"), params(&[]));
+        assert_eq!(match_path("/app/stats", "/app/stats/"), params(&[]));
+        assert_eq!(match_path("/app/stats/", "/app/stats"), params(&[]));
+        assert_eq!(match_path("/app/stats", "/app/other"), None);
+        assert_eq!(match_path("/app/stats", "/app/stats/more"), None);
+        assert_eq!(match_path("/app/stats", "/app"), None);
+    }
+
+    #[test]
+    fn matches_parameters_as_bound_or_as_requested() {
+        // http_bindings passes on the bound path, with the value elsewhere
+        assert_eq!(
+            match_path("/app/document/{id}", "/app/document/:id"),
+            params(&[])
+        );
+        assert_eq!(
+            match_path("/app/document/{id}", "/app/document/:other"),
+            params(&[("id", ":other")])
+        );
+        assert_eq!(
+            match_path("/app/document/{id}", "/app/document/a%20b%2Fc"),
+            params(&[("id", "a b/c")])
+        );
+        assert_eq!(
+            match_path("/app/{kind}/{id}/", "/app/document/7/"),
+            params(&[("kind", "document"), ("id", "7")])
+        );
+        assert_eq!(match_path("/app/document/{id}", "/app/document/"), None);
+        assert_eq!(match_path("/app/document/{id}", "/app/document//x"), None);
+    }
+
+    #[test]
+    fn binds_parameters_as_http_bindings_writes_them() {
+        assert_eq!(bound_path("/app/{kind}/{id}"), "/app/:kind/:id");
+        assert_eq!(bound_path("/app/stats"), "/app/stats");
+    }
+
+    #[test]
+    fn parses_query_strings() {
+        let query = parse_query("a=1&b=two+words&c=%26%3D&flag&&d=");
+        assert_eq!(query["a"], "1");
+        assert_eq!(query["b"], "two words");
+        assert_eq!(query["c"], "&=");
+        assert_eq!(query["flag"], "");
+        assert_eq!(query["d"], "");
+        assert_eq!(query.len(), 5);
+        assert_eq!(parse_query("a=1&a=2")["a"], "2");
+        assert!(parse_query("").is_empty());
+    }
+
+    #[test]
+    fn takes_parameters_from_the_path_and_http_bindings() {
+        let request = HttpRequest::parse(
+            &json!({
+                "method": "get",
+                "path": "/app/document/:id?q=a%20b&limit=5",
+                "headers": { "Content-Type": "application/json" },
+                "url_params": { "id": "x%2Fy" },
+                "query_params": { "limit": "10" },
+            })
+            .to_string(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/app/document/:id");
+        assert_eq!(request.param("id"), "x/y");
+        assert_eq!(request.query["q"], "a b");
+        assert_eq!(request.query_as::<u32>("limit"), Some(10));
+        assert_eq!(request.header("content-type"), Some("application/json"));
+    }
+
+    fn ok(_: &mut (), request: &HttpRequest) -> HttpResponse {
+        HttpResponse::json(200, &json!({ "id": request.param("id") }))
+    }
+
+    fn router() -> Router<()> {
+        Router::new("app")
+            .route("GET", "/app/document/{id}", false, ok)
+            .route("DELETE", "/app/document/{id}", false, ok)
+            .route("POST", "/app/document/{id}/tags", true, ok)
+    }
+
+    fn handle(method: &str, path: &str) -> HttpResponse {
+        let mut request = HttpRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            ..Default::default()
+        };
+        router().handle(&mut (), &mut request)
+    }
+
+    #[test]
+    fn routes_by_method_and_path() {
+        let res = handle("GET", "/app/document/7");
+        assert_eq!(res.status, 200);
+        assert_eq!(res.body, br#"{"id":"7"}"#);
+        assert_eq!(handle("DELETE", "/app/document/7/").status, 200);
+    }
+
+    #[test]
+    fn tells_unknown_paths_from_unserved_methods() {
+        assert_eq!(handle("GET", "/app/documents").status, 404);
+        let res = handle("POST", "/app/document/7");
+        assert_eq!(res.status, 405);
+        assert_eq!(res.headers["Allow"], "GET, DELETE");
+    }
+
+    #[test]
+    fn binds_each_path_once() {
+        let our: Address = "our.uq@app:app:drew.uq".parse().unwrap();
+        let bound: Vec<serde_json::Value> = router()
+            .bindings(&our)
+            .into_iter()
+            .map(|effect| match effect {
+                Effect::Send { request, .. } => {
+                    serde_json::from_str(request.ipc.as_deref().unwrap()).unwrap()
+                }
+                effect => panic!("unexpected effect {:?}", effect),
+            })
+            .collect();
+        assert_eq!(bound.len(), 2);
+        assert_eq!(bound[0]["path"], "/app/document/:id");
+        assert_eq!(bound[0]["authenticated"], false);
+        assert_eq!(bound[1]["path"], "/app/document/:id/tags");
+        assert_eq!(bound[1]["authenticated"], true);
+    }
+
+    #[test]
+    #[should_panic(expected = "share a path but not whether it is authenticated")]
+    fn refuses_routes_that_disagree_on_authenticating_a_path() {
+        let _ = router().route("PUT", "/app/document/{id}", true, ok);
+    }
+}
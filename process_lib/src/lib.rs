@@ -0,0 +1,409 @@
+//! Code shared by the processes of this package: bindings to the kernel,
+//! helpers around them, and the messages the processes exchange.
+
+use serde::{Deserialize, Serialize};
+
+pub mod as_json;
+pub mod fs;
+pub mod handler;
+pub mod http;
+pub mod http_server;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mock;
+pub mod protocol;
+pub mod state;
+pub mod urls;
+
+/// Bindings to the functions the kernel offers a process. A process still
+/// generates its own bindings for its `init` entry point.
+#[cfg(target_arch = "wasm32")]
+pub mod kernel {
+    wit_bindgen::generate!({
+        path: "../wit",
+        world: "uq-process-lib",
+    });
+}
+
+/// On native targets, where processes only run in tests, the kernel's
+/// functions are those of the [`mock`] kernel.
+#[cfg(not(target_arch = "wasm32"))]
+pub mod kernel {
+    #[allow(dead_code)]
+    mod bindings {
+        wit_bindgen::generate!({
+            path: "../wit",
+            world: "uq-process-lib",
+        });
+    }
+
+    pub use super::mock::{
+        attach_capability, clear_state, create_capability, get_capabilities, get_capability,
+        get_eth_block, get_payload, get_state, has_capability, print_to_terminal, receive,
+        save_capabilities, send_and_await_response, send_request, send_requests, send_response,
+        set_on_panic, set_state, share_capability, spawn,
+    };
+    pub use bindings::component;
+}
+
+pub use kernel::component::uq_process::types;
+use types::*;
+
+pub use state::{get_state, set_state};
+
+/// Identifies a package: `package_name:publisher_node`. Serialized in that
+/// string form like [`ProcessId`] and [`Address`]; the struct form it used to
+/// be serialized in is still read from JSON.
+#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+pub struct PackageId {
+    pub package_name: String,
+    pub publisher_node: String,
+}
+
+impl PackageId {
+    pub fn new(package_name: &str, publisher_node: &str) -> Self {
+        PackageId {
+            package_name: package_name.into(),
+            publisher_node: publisher_node.into(),
+        }
+    }
+    pub fn package(&self) -> &str {
+        &self.package_name
+    }
+    pub fn publisher_node(&self) -> &str {
+        &self.publisher_node
+    }
+}
+
+impl std::str::FromStr for PackageId {
+    type Err = ProcessIdParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let [package_name, publisher_node] = split_names(input)?;
+        Ok(PackageId::new(package_name, publisher_node))
+    }
+}
+
+impl std::fmt::Display for PackageId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.package_name, self.publisher_node)
+    }
+}
+
+impl ProcessId {
+    pub fn new(process_name: &str, package_name: &str, publisher_node: &str) -> Self {
+        ProcessId {
+            process_name: process_name.into(),
+            package_name: package_name.into(),
+            publisher_node: publisher_node.into(),
+        }
+    }
+    pub fn process(&self) -> &str {
+        &self.process_name
+    }
+    pub fn package(&self) -> &str {
+        &self.package_name
+    }
+    pub fn package_id(&self) -> PackageId {
+        PackageId::new(&self.package_name, &self.publisher_node)
+    }
+    pub fn publisher_node(&self) -> &str {
+        &self.publisher_node
+    }
+}
+
+impl std::str::FromStr for ProcessId {
+    type Err = ProcessIdParseError;
+
+    /// Parses `process_name:package_name:publisher_node`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let [process_name, package_name, publisher_node] = split_names(input)?;
+        Ok(ProcessId::new(process_name, package_name, publisher_node))
+    }
+}
+
+impl std::fmt::Display for ProcessId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.process_name, self.package_name, self.publisher_node
+        )
+    }
+}
+
+impl PartialEq for ProcessId {
+    fn eq(&self, other: &Self) -> bool {
+        self.process_name == other.process_name
+            && self.package_name == other.package_name
+            && self.publisher_node == other.publisher_node
+    }
+}
+
+impl PartialEq<&str> for ProcessId {
+    fn eq(&self, other: &&str) -> bool {
+        &self.to_string() == other
+    }
+}
+
+impl PartialEq<ProcessId> for &str {
+    fn eq(&self, other: &ProcessId) -> bool {
+        self == &other.to_string()
+    }
+}
+
+/// Whether `c` may appear in a node, process, package or publisher name.
+pub fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+}
+
+/// Splits `input` on colons into exactly `N` names, checking each.
+fn split_names<const N: usize>(input: &str) -> Result<[&str; N], ProcessIdParseError> {
+    let mut names = [""; N];
+    let mut segments = input.split(':');
+    for name in names.iter_mut() {
+        *name = segments.next().ok_or(ProcessIdParseError::MissingField)?;
+        check_name(name)?;
+    }
+    if segments.next().is_some() {
+        return Err(ProcessIdParseError::TooManyColons);
+    }
+    Ok(names)
+}
+
+fn check_name(name: &str) -> Result<(), ProcessIdParseError> {
+    if name.is_empty() {
+        return Err(ProcessIdParseError::EmptyField);
+    }
+    match name.chars().find(|&c| !is_name_char(c)) {
+        Some(c) => Err(ProcessIdParseError::InvalidCharacter(c)),
+        None => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessIdParseError {
+    TooManyColons,
+    MissingField,
+    EmptyField,
+    /// a character other than an ASCII letter, digit, `-`, `_` or `.`
+    InvalidCharacter(char),
+}
+
+impl std::fmt::Display for ProcessIdParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcessIdParseError::TooManyColons => write!(f, "Too many colons in ProcessId string"),
+            ProcessIdParseError::MissingField => write!(f, "Missing field in ProcessId string"),
+            ProcessIdParseError::EmptyField => write!(f, "Empty field in ProcessId string"),
+            ProcessIdParseError::InvalidCharacter(c) => {
+                write!(f, "Invalid character {:?} in ProcessId string", c)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProcessIdParseError {}
+
+impl Address {
+    pub fn new(node: &str, process: ProcessId) -> Self {
+        Address {
+            node: node.into(),
+            process,
+        }
+    }
+}
+
+impl std::str::FromStr for Address {
+    type Err = AddressParseError;
+
+    /// Parses `node@process_name:package_name:publisher_node`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (node, process) = input
+            .split_once('@')
+            .ok_or(AddressParseError::MissingNodeId)?;
+        if process.contains('@') {
+            return Err(AddressParseError::TooManyAts);
+        }
+        check_name(node).map_err(|e| match e {
+            ProcessIdParseError::EmptyField => AddressParseError::MissingNodeId,
+            e => e.into(),
+        })?;
+        Ok(Address::new(node, process.parse()?))
+    }
+}
+
+impl std::fmt::Display for Address {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}@{}", self.node, self.process)
+    }
+}
+
+impl PartialEq for Address {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node && self.process == other.process
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressParseError {
+    TooManyColons,
+    MissingNodeId,
+    MissingField,
+    EmptyField,
+    InvalidCharacter(char),
+    TooManyAts,
+}
+
+impl From<ProcessIdParseError> for AddressParseError {
+    fn from(e: ProcessIdParseError) -> Self {
+        match e {
+            ProcessIdParseError::TooManyColons => AddressParseError::TooManyColons,
+            ProcessIdParseError::MissingField => AddressParseError::MissingField,
+            ProcessIdParseError::EmptyField => AddressParseError::EmptyField,
+            ProcessIdParseError::InvalidCharacter(c) => AddressParseError::InvalidCharacter(c),
+        }
+    }
+}
+
+impl std::fmt::Display for AddressParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddressParseError::TooManyColons => write!(f, "Too many colons in Address string"),
+            AddressParseError::MissingNodeId => write!(f, "Missing node ID in Address string"),
+            AddressParseError::MissingField => write!(f, "Missing field in Address string"),
+            AddressParseError::EmptyField => write!(f, "Empty field in Address string"),
+            AddressParseError::InvalidCharacter(c) => {
+                write!(f, "Invalid character {:?} in Address string", c)
+            }
+            AddressParseError::TooManyAts => write!(f, "Too many '@' in Address string"),
+        }
+    }
+}
+
+impl std::error::Error for AddressParseError {}
+
+/// `TryFrom<&str>` and serde (de)serialization through the string form, for
+/// types with `FromStr` and `Display`.
+macro_rules! string_conversions {
+    ($($t:ty),*) => {$(
+        impl TryFrom<&str> for $t {
+            type Error = <$t as std::str::FromStr>::Err;
+
+            fn try_from(input: &str) -> Result<Self, Self::Error> {
+                input.parse()
+            }
+        }
+
+        impl Serialize for $t {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+    )*};
+}
+
+string_conversions!(ProcessId, Address);
+
+impl TryFrom<&str> for PackageId {
+    type Error = ProcessIdParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+impl Serialize for PackageId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PackageId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Stored {
+            String(String),
+            Struct {
+                package_name: String,
+                publisher_node: String,
+            },
+        }
+        // formats that are not self-describing, like bincode, cannot tell the
+        // two forms apart, so only the string form is read from them
+        if !deserializer.is_human_readable() {
+            return String::deserialize(deserializer)?
+                .parse()
+                .map_err(serde::de::Error::custom);
+        }
+        match Stored::deserialize(deserializer)? {
+            Stored::String(id) => id.parse().map_err(serde::de::Error::custom),
+            Stored::Struct {
+                package_name,
+                publisher_node,
+            } => Ok(PackageId::new(&package_name, &publisher_node)),
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+pub fn send_and_await_response(
+    target: &Address,
+    inherit: bool,
+    ipc: Option<Json>,
+    metadata: Option<Json>,
+    payload: Option<&Payload>,
+    timeout: u64,
+) -> Result<(Address, Message), SendError> {
+    kernel::send_and_await_response(
+        target,
+        &Request {
+            inherit,
+            expects_response: Some(timeout),
+            ipc,
+            metadata,
+        },
+        payload,
+    )
+}
+
+pub fn send_request(
+    target: &Address,
+    inherit: bool,
+    ipc: Option<Json>,
+    metadata: Option<Json>,
+    context: Option<&Json>,
+    payload: Option<&Payload>,
+) {
+    kernel::send_request(
+        target,
+        &Request {
+            inherit,
+            expects_response: None,
+            ipc,
+            metadata,
+        },
+        context,
+        payload,
+    )
+}
+
+pub fn parse_message_ipc<T>(json_string: Option<String>) -> anyhow::Result<T>
+where
+    for<'a> T: serde::Deserialize<'a>,
+{
+    let parsed: T = serde_json::from_str(
+        json_string
+            .ok_or(anyhow::anyhow!("json payload empty"))?
+            .as_str(),
+    )?;
+    Ok(parsed)
+}
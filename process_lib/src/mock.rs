@@ -0,0 +1,467 @@
+//! A stand-in for the kernel on native targets, so that a process can be run
+//! by `cargo test`. A test scripts the messages the process will receive and
+//! how the processes it calls answer, runs it, then checks what it sent:
+//!
+//! ```ignore
+//! let mut kernel = MockKernel::new(our.clone());
+//! kernel.push_request(&terminal, "servers add other.uq", None);
+//! kernel.on_request("server:librarian:drew.uq", |_, _, _| mock::reply("\"Ok\"", None));
+//! let kernel = kernel.run(|| run(our));
+//! assert_eq!(kernel.requests_to("server:librarian:drew.uq").len(), 1);
+//! ```
+//!
+//! The process runs until it asks to receive from an empty inbox. Everything
+//! here has the signature of the kernel function it stands in for, and is what
+//! [`crate::kernel`] offers on native targets.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+
+use super::state::{self, Versioned};
+use super::types::*;
+
+/// How a process answers a request: its response and payload, or the error
+/// the sender gets instead.
+pub type Reply = Result<(Response, Option<Payload>), SendErrorKind>;
+
+type Responder = Box<dyn FnMut(&Address, &Request, Option<&Payload>) -> Reply>;
+
+thread_local! {
+    static KERNEL: RefCell<Option<MockKernel>> = const { RefCell::new(None) };
+}
+
+/// unwound from `receive` once the inbox is empty, ending the run
+struct InboxEmpty;
+
+/// Responses and errors come with the source of the request being handled
+/// when their request was sent, which the kernel keeps with the context so
+/// that a process can answer that request once they arrive.
+enum Incoming {
+    Message(Address, Message, Option<Payload>, Option<Address>),
+    Error(SendError, Option<Context>, Option<Address>),
+}
+
+#[derive(Debug, Clone)]
+pub struct SentRequest {
+    pub target: Address,
+    pub request: Request,
+    pub context: Option<Context>,
+    pub payload: Option<Payload>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SentResponse {
+    /// the source of the request answered, if any
+    pub target: Option<Address>,
+    pub response: Response,
+    pub payload: Option<Payload>,
+}
+
+pub struct MockKernel {
+    our: Address,
+    inbox: VecDeque<Incoming>,
+    /// the payload of the message last received
+    payload: Option<Payload>,
+    /// the source of the request being handled, which responses go to
+    prompting: Option<Address>,
+    /// by the target they answer for, see [`MockKernel::on_request`]
+    responders: Vec<(String, Responder)>,
+    /// every request sent, in order, including those awaited
+    pub requests: Vec<SentRequest>,
+    pub responses: Vec<SentResponse>,
+    /// what was printed to the terminal
+    pub printed: Vec<String>,
+    pub state: Option<Vec<u8>>,
+    /// our own signed capabilities
+    pub capabilities: Vec<SignedCapability>,
+    /// the params of the capabilities `has_capability` finds
+    pub granted: Vec<Json>,
+    /// capabilities created for or shared with other processes
+    pub given: Vec<(ProcessId, Json)>,
+    /// capabilities attached to outgoing messages
+    pub attached: Vec<SignedCapability>,
+    pub on_panic: Option<OnPanic>,
+    pub eth_block: u64,
+}
+
+impl MockKernel {
+    pub fn new(our: Address) -> Self {
+        MockKernel {
+            our,
+            inbox: VecDeque::new(),
+            payload: None,
+            prompting: None,
+            responders: vec![],
+            requests: vec![],
+            responses: vec![],
+            printed: vec![],
+            state: None,
+            capabilities: vec![],
+            granted: vec![],
+            given: vec![],
+            attached: vec![],
+            on_panic: None,
+            eth_block: 0,
+        }
+    }
+
+    pub fn our(&self) -> &Address {
+        &self.our
+    }
+
+    /// Queues a message for the process to receive.
+    pub fn push_message(&mut self, source: &Address, message: Message, payload: Option<Payload>) {
+        self.inbox
+            .push_back(Incoming::Message(source.clone(), message, payload, None));
+    }
+
+    pub fn push_request(&mut self, source: &Address, ipc: &str, payload: Option<Payload>) {
+        self.push_message(
+            source,
+            Message::Request(Request {
+                inherit: false,
+                expects_response: None,
+                ipc: Some(ipc.to_string()),
+                metadata: None,
+            }),
+            payload,
+        );
+    }
+
+    pub fn push_response(
+        &mut self,
+        source: &Address,
+        ipc: &str,
+        context: Option<&str>,
+        payload: Option<Payload>,
+    ) {
+        self.push_message(
+            source,
+            Message::Response((
+                Response {
+                    inherit: false,
+                    ipc: Some(ipc.to_string()),
+                    metadata: None,
+                },
+                context.map(str::to_string),
+            )),
+            payload,
+        );
+    }
+
+    /// Queues the error for a request the process sent, as `receive` returns it.
+    pub fn push_send_error(&mut self, error: SendError, context: Option<&str>) {
+        self.inbox
+            .push_back(Incoming::Error(error, context.map(str::to_string), None));
+    }
+
+    /// Answers requests to `target`, either a process id, matching it on any
+    /// node, or a whole address. Requests nothing answers time out when
+    /// awaited, and otherwise go unanswered.
+    pub fn on_request(
+        &mut self,
+        target: &str,
+        responder: impl FnMut(&Address, &Request, Option<&Payload>) -> Reply + 'static,
+    ) {
+        self.responders
+            .push((target.to_string(), Box::new(responder)));
+    }
+
+    /// Saves `state` as though the process had saved it before.
+    pub fn set_state<T: Versioned>(&mut self, state: &T) {
+        self.state = Some(state::encode(state));
+    }
+
+    /// The state the process saved, if any.
+    pub fn get_state<T: Versioned>(&self) -> Option<T> {
+        self.state
+            .as_ref()
+            .map(|bytes| state::decode(bytes).expect("saved state should decode"))
+    }
+
+    /// The requests sent to `target`, matched as in [`MockKernel::on_request`].
+    pub fn requests_to(&self, target: &str) -> Vec<&SentRequest> {
+        self.requests
+            .iter()
+            .filter(|sent| matches(target, &sent.target))
+            .collect()
+    }
+
+    /// Runs `process` with this kernel until it returns or waits on an empty
+    /// inbox, then gives the kernel back to be checked or run again.
+    pub fn run(self, process: impl FnOnce()) -> MockKernel {
+        KERNEL.with(|kernel| {
+            let mut kernel = kernel.borrow_mut();
+            assert!(kernel.is_none(), "a mock kernel is already running");
+            *kernel = Some(self);
+        });
+        let result = panic::catch_unwind(AssertUnwindSafe(process));
+        let kernel = KERNEL.with(|kernel| kernel.borrow_mut().take()).unwrap();
+        if let Err(e) = result {
+            if !e.is::<InboxEmpty>() {
+                panic::resume_unwind(e);
+            }
+        }
+        kernel
+    }
+
+    /// How the responder for `target` answers, if one does.
+    fn answer(
+        &mut self,
+        target: &Address,
+        request: &Request,
+        payload: Option<&Payload>,
+    ) -> Option<Reply> {
+        self.responders
+            .iter_mut()
+            .find(|(pattern, _)| matches(pattern, target))
+            .map(|(_, responder)| responder(target, request, payload))
+    }
+
+    fn record_request(
+        &mut self,
+        target: &Address,
+        request: &Request,
+        context: Option<&Context>,
+        payload: Option<&Payload>,
+    ) -> Option<Payload> {
+        // an inheriting request carries on the payload of the message being handled
+        let payload = match (payload, request.inherit) {
+            (None, true) => self.payload.clone(),
+            (payload, _) => payload.cloned(),
+        };
+        self.requests.push(SentRequest {
+            target: target.clone(),
+            request: request.clone(),
+            context: context.cloned(),
+            payload: payload.clone(),
+        });
+        payload
+    }
+}
+
+/// A successful reply with `ipc`, for [`MockKernel::on_request`].
+pub fn reply(ipc: &str, payload: Option<Payload>) -> Reply {
+    Ok((
+        Response {
+            inherit: false,
+            ipc: Some(ipc.to_string()),
+            metadata: None,
+        },
+        payload,
+    ))
+}
+
+fn matches(pattern: &str, address: &Address) -> bool {
+    match pattern.contains('@') {
+        true => address.to_string() == pattern,
+        false => address.process == pattern,
+    }
+}
+
+fn with_kernel<T>(f: impl FnOnce(&mut MockKernel) -> T) -> T {
+    KERNEL.with(|kernel| {
+        f(kernel
+            .borrow_mut()
+            .as_mut()
+            .expect("no mock kernel is running: run the process with MockKernel::run"))
+    })
+}
+
+pub fn print_to_terminal(_verbosity: u8, message: &str) {
+    with_kernel(|kernel| kernel.printed.push(message.to_string()))
+}
+
+pub fn get_eth_block() -> u64 {
+    with_kernel(|kernel| kernel.eth_block)
+}
+
+pub fn set_on_panic(on_panic: &OnPanic) {
+    with_kernel(|kernel| kernel.on_panic = Some(on_panic.clone()))
+}
+
+pub fn get_state() -> Option<Vec<u8>> {
+    with_kernel(|kernel| kernel.state.clone())
+}
+
+pub fn set_state(bytes: &[u8]) {
+    with_kernel(|kernel| kernel.state = Some(bytes.to_vec()))
+}
+
+pub fn clear_state() {
+    with_kernel(|kernel| kernel.state = None)
+}
+
+pub fn spawn(
+    name: Option<&str>,
+    _wasm_path: &str,
+    _on_panic: &OnPanic,
+    _capabilities: &Capabilities,
+    _public: bool,
+) -> Result<ProcessId, SpawnError> {
+    with_kernel(|kernel| {
+        Ok(ProcessId::new(
+            name.unwrap_or("spawned"),
+            kernel.our.process.package(),
+            kernel.our.process.publisher_node(),
+        ))
+    })
+}
+
+pub fn get_capabilities() -> Vec<SignedCapability> {
+    with_kernel(|kernel| kernel.capabilities.clone())
+}
+
+pub fn get_capability(issuer: &Address, params: &str) -> Option<SignedCapability> {
+    with_kernel(|kernel| {
+        kernel
+            .capabilities
+            .iter()
+            .find(|capability| capability.issuer == *issuer && capability.params == params)
+            .cloned()
+    })
+}
+
+pub fn attach_capability(capability: &SignedCapability) {
+    with_kernel(|kernel| kernel.attached.push(capability.clone()))
+}
+
+pub fn save_capabilities(capabilities: &[SignedCapability]) {
+    with_kernel(|kernel| kernel.capabilities.extend_from_slice(capabilities))
+}
+
+pub fn has_capability(params: &str) -> bool {
+    with_kernel(|kernel| kernel.granted.iter().any(|granted| granted == params))
+}
+
+pub fn create_capability(to: &ProcessId, params: &str) {
+    with_kernel(|kernel| kernel.given.push((to.clone(), params.to_string())))
+}
+
+pub fn share_capability(to: &ProcessId, capability: &SignedCapability) {
+    with_kernel(|kernel| kernel.given.push((to.clone(), capability.params.clone())))
+}
+
+#[allow(clippy::result_large_err)]
+pub fn receive() -> Result<(Address, Message), (SendError, Option<Context>)> {
+    let incoming = with_kernel(|kernel| {
+        let incoming = kernel.inbox.pop_front();
+        kernel.payload = match &incoming {
+            Some(Incoming::Message(_, _, payload, _)) => payload.clone(),
+            _ => None,
+        };
+        match &incoming {
+            Some(Incoming::Message(source, Message::Request(_), _, _)) => {
+                kernel.prompting = Some(source.clone())
+            }
+            Some(Incoming::Message(_, _, _, prompting) | Incoming::Error(_, _, prompting)) => {
+                kernel.prompting = prompting.clone()
+            }
+            None => {}
+        }
+        incoming
+    });
+    match incoming {
+        Some(Incoming::Message(source, message, _, _)) => Ok((source, message)),
+        Some(Incoming::Error(error, context, _)) => Err((error, context)),
+        // not a panic, so nothing is printed
+        None => panic::resume_unwind(Box::new(InboxEmpty)),
+    }
+}
+
+pub fn get_payload() -> Option<Payload> {
+    with_kernel(|kernel| kernel.payload.clone())
+}
+
+/// Answered right away by the responder for `target`, queueing its response
+/// for `receive` if the request expects one. Requests to ourselves are queued
+/// for `receive` instead.
+pub fn send_request(
+    target: &Address,
+    request: &Request,
+    context: Option<&Context>,
+    payload: Option<&Payload>,
+) {
+    with_kernel(|kernel| {
+        let payload = kernel.record_request(target, request, context, payload);
+        if *target == kernel.our {
+            kernel.inbox.push_back(Incoming::Message(
+                target.clone(),
+                Message::Request(request.clone()),
+                payload,
+                None,
+            ));
+            return;
+        }
+        if request.expects_response.is_none() {
+            return;
+        }
+        let incoming = match kernel.answer(target, request, payload.as_ref()) {
+            Some(Ok((response, payload))) => Incoming::Message(
+                target.clone(),
+                Message::Response((response, context.cloned())),
+                payload,
+                kernel.prompting.clone(),
+            ),
+            Some(Err(kind)) => Incoming::Error(
+                SendError {
+                    kind,
+                    message: Message::Request(request.clone()),
+                    payload,
+                },
+                context.cloned(),
+                kernel.prompting.clone(),
+            ),
+            None => return,
+        };
+        kernel.inbox.push_back(incoming);
+    })
+}
+
+pub fn send_requests(requests: &[(Address, Request, Option<Context>, Option<Payload>)]) {
+    for (target, request, context, payload) in requests {
+        send_request(target, request, context.as_ref(), payload.as_ref());
+    }
+}
+
+pub fn send_response(response: &Response, payload: Option<&Payload>) {
+    with_kernel(|kernel| {
+        let payload = match (payload, response.inherit) {
+            (None, true) => kernel.payload.clone(),
+            (payload, _) => payload.cloned(),
+        };
+        kernel.responses.push(SentResponse {
+            target: kernel.prompting.clone(),
+            response: response.clone(),
+            payload,
+        })
+    })
+}
+
+/// Answered by the responder for `target`; with none, the request times out.
+/// The response's payload becomes the one `get_payload` returns.
+#[allow(clippy::result_large_err)]
+pub fn send_and_await_response(
+    target: &Address,
+    request: &Request,
+    payload: Option<&Payload>,
+) -> Result<(Address, Message), SendError> {
+    with_kernel(|kernel| {
+        let payload = kernel.record_request(target, request, None, payload);
+        match kernel.answer(target, request, payload.as_ref()) {
+            Some(Ok((response, response_payload))) => {
+                kernel.payload = response_payload;
+                Ok((target.clone(), Message::Response((response, None))))
+            }
+            error => Err(SendError {
+                kind: match error {
+                    Some(Err(kind)) => kind,
+                    _ => SendErrorKind::Timeout,
+                },
+                message: Message::Request(request.clone()),
+                payload,
+            }),
+        }
+    })
+}
@@ -0,0 +1,482 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The unit a document body is cut into before units are windowed into chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChunkStrategy {
+    /// whitespace-separated words
+    Tokens,
+    /// runs of text ending in `.`, `!` or `?`
+    Sentences,
+    /// blocks of text separated by blank lines
+    Paragraphs,
+}
+
+/// `size` and `overlap` are counted in units of `strategy`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkingConfig {
+    pub strategy: ChunkStrategy,
+    pub size: usize,
+    pub overlap: usize,
+}
+
+impl Default for ChunkingConfig {
+    fn default() -> Self {
+        // MiniLM truncates its input at 256 word pieces, so keep chunks well under that
+        ChunkingConfig {
+            strategy: ChunkStrategy::Tokens,
+            size: 128,
+            overlap: 32,
+        }
+    }
+}
+
+/// Requests the librarian sends to the server, carried as JSON in the request ipc.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ServerRequest {
+    Query(QueryRequest),
+    /// "more like this": queries with the vector of a stored document,
+    /// leaving the document itself out of the results
+    QueryById(QueryByIdRequest),
+    Fetch(FetchRequest),
+    Ingest(IngestRequest),
+    /// ingests a single document carried as the request payload; its text and
+    /// metadata are extracted according to the payload mime type
+    IngestPayload(PayloadIngestRequest),
+    /// fetches a web page and ingests it under its canonical url
+    IngestUrl(UrlIngestRequest),
+    /// removes documents from the library, by id or by metadata filter
+    Delete(DeleteRequest),
+    /// merges a patch into a document's metadata
+    UpdateMetadata(UpdateMetadataRequest),
+    Stats,
+    /// answered right away, to check the server is reachable
+    Ping,
+    /// turns the query log on with the given retention limits, or off with `null`
+    ConfigureQueryLog(Option<QueryLogConfig>),
+    /// sets the url of the HTTP re-ranker's `/rerank` endpoint, or unsets it with `null`
+    ConfigureReranker(Option<String>),
+    /// sets the nodes besides the server's own that may ingest, update and
+    /// delete documents, configure the server and read its analytics; only
+    /// the server's own node may send it
+    ConfigureAdmins(Vec<String>),
+    /// records whether a result was relevant to a query
+    Feedback(FeedbackRequest),
+    /// records that a query result was opened, for click-through analytics
+    Click(ClickRequest),
+    /// aggregates the query log
+    Analytics(AnalyticsRequest),
+    /// a command typed into the terminal, answered with `{"output": ...}`
+    /// ready to be printed; only the server's own node and its admins may
+    /// send it
+    Command(String),
+}
+
+/// Carried as JSON in the response ipc. The body of the response, or an
+/// `{"error": ...}` object on failure, is always the JSON payload.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ServerResponse {
+    Ok,
+    Err(String),
+}
+
+fn default_namespace() -> String {
+    "default".to_string()
+}
+
+fn default_top_k() -> usize {
+    10
+}
+
+/// Matches the body the frontend POSTs to `/librarian/vector`. A request
+/// carrying a `cursor` repeats the query the cursor came from; only `topK`
+/// may differ.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryRequest {
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+    #[serde(default = "default_top_k")]
+    pub top_k: usize,
+    #[serde(default)]
+    pub vector: Vec<f32>,
+    /// number of results to skip
+    #[serde(default)]
+    pub offset: usize,
+    /// the `nextCursor` of a previous page
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// the text `vector` was embedded from, needed for re-ranking. Without a
+    /// `vector`, the server embeds the text itself
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub filter: Option<serde_json::Value>,
+    #[serde(default)]
+    pub include_values: bool,
+    #[serde(default)]
+    pub include_metadata: bool,
+    /// send whole article bodies in `metadata.article` instead of a
+    /// `metadata.preview`; otherwise bodies are loaded with Fetch
+    #[serde(default)]
+    pub include_content: bool,
+    /// when set, matches whose vectors have at least this cosine similarity,
+    /// or that were clustered together at ingestion, are collapsed into one
+    #[serde(default)]
+    pub dedupe: Option<f32>,
+    /// re-rank for diversity with maximal marginal relevance
+    #[serde(default)]
+    pub mmr: Option<MmrOptions>,
+    /// re-score the best candidates against the query text with a re-ranker
+    #[serde(default)]
+    pub rerank: Option<RerankOptions>,
+    /// ids of documents to leave out of the results
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// moves the query towards documents marked relevant with Feedback in
+    /// this session, and away from those marked not relevant
+    #[serde(default)]
+    pub session: Option<String>,
+    /// when set, adds this weight times each document's popularity, between
+    /// -1 and 1, to its similarity score
+    #[serde(default)]
+    pub popularity: Option<f32>,
+}
+
+/// Takes all the options of a [`QueryRequest`], except that the vector comes
+/// from the document `id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryByIdRequest {
+    pub id: String,
+    #[serde(flatten)]
+    pub query: QueryRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RerankOptions {
+    #[serde(default)]
+    pub reranker: RerankerKind,
+    /// how many candidates to re-score; defaults to three times `topK`
+    #[serde(default)]
+    pub candidates: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RerankerKind {
+    /// a cross-encoder served over HTTP, at the endpoint set with
+    /// [`ServerRequest::ConfigureReranker`]
+    Http,
+    /// BM25 term matching, computed in-process
+    #[default]
+    Lexical,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MmrOptions {
+    /// trades relevance (1.0) against diversity (0.0)
+    #[serde(default = "default_mmr_lambda")]
+    pub lambda: f32,
+    /// how many of the most relevant documents to choose from; defaults to
+    /// five times `topK`
+    #[serde(default)]
+    pub candidates: Option<usize>,
+}
+
+fn default_mmr_lambda() -> f32 {
+    0.5
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryResponse {
+    pub namespace: String,
+    pub matches: Vec<Match>,
+    /// present if there are more results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// identifies the query in the query log, for reporting clicks; only set
+    /// while the log is on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_id: Option<String>,
+    /// changes whenever documents are added, removed or updated, so clients
+    /// know when results they hold on to are stale
+    pub generation: u64,
+}
+
+/// A single document hit. When the document was ingested in chunks, `passage`
+/// is the chunk that matched best and `score` is that chunk's score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Match {
+    pub id: String,
+    pub score: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<f32>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Map<String, serde_json::Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passage: Option<Passage>,
+    /// near-duplicates collapsed into this match
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub also_published_in: Vec<Source>,
+    /// set when the match was re-ranked; `score` is then the vector similarity
+    /// it was first retrieved with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank_score: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Source {
+    pub id: String,
+    pub score: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publication: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// `start` and `end` are byte offsets into the document body, for highlighting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Passage {
+    pub text: String,
+    pub chunk_index: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchRequest {
+    pub ids: Vec<String>,
+    /// where to look for documents the server did not ingest itself
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchResponse {
+    pub documents: Vec<FetchedDocument>,
+    /// requested ids that are not in the library
+    pub missing: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchedDocument {
+    pub id: String,
+    pub namespace: String,
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestRequest {
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+    pub documents: Vec<Document>,
+    #[serde(default)]
+    pub chunking: ChunkingConfig,
+    #[serde(default)]
+    pub duplicates: DuplicatePolicy,
+}
+
+/// What to do with a document whose fingerprint is close to one already in the library.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DuplicatePolicy {
+    /// index it like any other document
+    Allow,
+    /// do not index it
+    Reject,
+    /// index it, but as part of the earlier document's cluster
+    #[default]
+    Cluster,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Document {
+    pub id: String,
+    pub text: String,
+    #[serde(default)]
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayloadIngestRequest {
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+    /// defaults to the document's canonical url if it has one, otherwise to a
+    /// hash of its text
+    #[serde(default)]
+    pub id: Option<String>,
+    /// takes precedence over metadata extracted from the document
+    #[serde(default)]
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    pub chunking: ChunkingConfig,
+    #[serde(default)]
+    pub duplicates: DuplicatePolicy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UrlIngestRequest {
+    pub url: String,
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+    /// takes precedence over metadata extracted from the page
+    #[serde(default)]
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    pub chunking: ChunkingConfig,
+    #[serde(default)]
+    pub duplicates: DuplicatePolicy,
+    /// re-fetch and re-index the page even if its url is already in the library
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+/// Exactly one of `ids` and `filter` must be given.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteRequest {
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+    #[serde(default)]
+    pub ids: Vec<String>,
+    /// a pinecone metadata filter, matched against the documents the server ingested
+    #[serde(default)]
+    pub filter: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateMetadataRequest {
+    pub id: String,
+    /// where to look for the document if the server did not ingest it itself
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+    /// keys set to `null` are removed, everything else is merged in
+    pub patch: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsResponse {
+    pub vectors: u64,
+    pub documents: usize,
+    /// deleted documents whose vectors await compaction
+    pub deleted: usize,
+    pub generation: u64,
+    /// how full the index is, from 0 to 1
+    pub fullness: f64,
+    pub collections: BTreeMap<String, CollectionStats>,
+    /// over the most recent queries since the server started
+    pub query_latency: LatencyStats,
+}
+
+/// Collections are pinecone namespaces, so the shape of their vectors is that
+/// of the whole index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionStats {
+    pub vectors: u64,
+    /// documents the server ingested itself; older ones are only counted as vectors
+    pub documents: usize,
+    pub dimension: u64,
+    /// `None` if the index could not be described
+    pub metric: Option<String>,
+    /// the pinecone pod type, or `"serverless"`
+    pub index_type: Option<String>,
+    /// estimated size of the vectors in the index
+    pub memory_bytes: u64,
+    /// size of the documents' text and metadata kept in the server's state
+    pub disk_bytes: u64,
+    /// seconds since the epoch
+    pub last_ingest: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LatencyStats {
+    pub count: usize,
+    pub p50_ms: Option<f64>,
+    pub p90_ms: Option<f64>,
+    pub p99_ms: Option<f64>,
+}
+
+fn default_retention_days() -> u64 {
+    30
+}
+
+fn default_max_entries() -> usize {
+    100_000
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryLogConfig {
+    /// entries older than this are dropped
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u64,
+    /// the oldest entries are dropped beyond this many
+    #[serde(default = "default_max_entries")]
+    pub max_entries: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClickRequest {
+    /// the `queryId` of the response the result was in
+    #[serde(default)]
+    pub query_id: Option<String>,
+    /// the clicked document
+    pub id: String,
+    /// zero-based position of the result in the list
+    #[serde(default)]
+    pub position: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsRequest {
+    /// only entries from this many days back
+    #[serde(default)]
+    pub days: Option<u64>,
+    /// length of each top list
+    #[serde(default = "default_top_k")]
+    pub limit: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsResponse {
+    pub queries: usize,
+    pub clicks: usize,
+    /// share of queries with at least one clicked result
+    pub click_through_rate: f64,
+    /// by query text, lowercased
+    pub top_queries: Vec<Count>,
+    pub zero_result_queries: Vec<Count>,
+    /// by document id
+    pub top_clicked: Vec<Count>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Count {
+    pub key: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedbackLabel {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedbackRequest {
+    /// the session whose later queries the feedback refines
+    #[serde(default)]
+    pub session: Option<String>,
+    /// the vector of the query the document was a result of
+    #[serde(default)]
+    pub vector: Vec<f32>,
+    pub id: String,
+    pub label: FeedbackLabel,
+    /// where to look for the document if the server did not ingest it itself
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+}
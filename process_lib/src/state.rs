@@ -0,0 +1,152 @@
+//! Process state, persisted by the kernel as bytes. States are stored with a
+//! schema version so that a process upgraded to a new layout can migrate the
+//! state an older version left behind instead of losing it.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::kernel::{self, print_to_terminal};
+use super::types::Address;
+
+/// marks a versioned state; states written before versioning are plain bincode
+const MAGIC: &[u8; 4] = b"UQST";
+
+/// Turns the bincode of a version `n` state into the bincode of version `n + 1`.
+pub type Migration = fn(Vec<u8>) -> anyhow::Result<Vec<u8>>;
+
+/// A state with a versioned layout. Bump `VERSION` whenever the bincode of the
+/// state changes, keeping the old type around to write the migration with, e.g.
+/// `|bytes| state::convert(bytes, |old: StateV1| State { ..., added: 0 })`.
+pub trait Versioned: Serialize + DeserializeOwned {
+    const VERSION: u32;
+    /// `MIGRATIONS[n]` migrates version `n` to `n + 1`, so there is one per
+    /// version; version 0 is a state written before versioning.
+    const MIGRATIONS: &'static [Migration];
+}
+
+#[derive(Debug)]
+pub enum StateError {
+    /// the state is newer than this version of the process knows
+    UnknownVersion {
+        found: u32,
+        current: u32,
+    },
+    MissingMigration(u32),
+    Migration {
+        from: u32,
+        error: anyhow::Error,
+    },
+    Decode {
+        version: u32,
+        error: bincode::Error,
+    },
+}
+
+impl std::fmt::Display for StateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::UnknownVersion { found, current } => write!(
+                f,
+                "state is version {}, newer than the current version {}",
+                found, current
+            ),
+            StateError::MissingMigration(from) => {
+                write!(f, "no migration from state version {}", from)
+            }
+            StateError::Migration { from, error } => {
+                write!(f, "migrating state from version {} failed: {}", from, error)
+            }
+            StateError::Decode { version, error } => {
+                write!(f, "decoding version {} state failed: {}", version, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+/// Encodes `state` with its version.
+pub fn encode<T: Versioned>(state: &T) -> Vec<u8> {
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend(T::VERSION.to_le_bytes());
+    bytes.extend(bincode::serialize(state).unwrap());
+    bytes
+}
+
+/// Decodes a state written by any version up to `T::VERSION`, migrating it.
+pub fn decode<T: Versioned>(bytes: &[u8]) -> Result<T, StateError> {
+    let (mut version, mut bytes) = match bytes.strip_prefix(MAGIC) {
+        Some(versioned) if versioned.len() >= 4 => (
+            u32::from_le_bytes(versioned[..4].try_into().unwrap()),
+            versioned[4..].to_vec(),
+        ),
+        _ => (0, bytes.to_vec()),
+    };
+    if version > T::VERSION {
+        return Err(StateError::UnknownVersion {
+            found: version,
+            current: T::VERSION,
+        });
+    }
+    while version < T::VERSION {
+        let migration = T::MIGRATIONS
+            .get(version as usize)
+            .ok_or(StateError::MissingMigration(version))?;
+        bytes = migration(bytes).map_err(|error| StateError::Migration {
+            from: version,
+            error,
+        })?;
+        version += 1;
+    }
+    bincode::deserialize(&bytes).map_err(|error| StateError::Decode { version, error })
+}
+
+/// Writes a migration: decodes the old state, converts it and encodes the result.
+pub fn convert<Old: DeserializeOwned, New: Serialize>(
+    bytes: Vec<u8>,
+    f: impl FnOnce(Old) -> New,
+) -> anyhow::Result<Vec<u8>> {
+    let old: Old = bincode::deserialize(&bytes)?;
+    Ok(bincode::serialize(&f(old))?)
+}
+
+/// The migration for a version whose layout did not change.
+pub fn unchanged(bytes: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+    Ok(bytes)
+}
+
+/// Our saved state, or `None` if there is none.
+pub fn get_state<T: Versioned>() -> Result<Option<T>, StateError> {
+    kernel::get_state().map(|bytes| decode(&bytes)).transpose()
+}
+
+pub fn set_state<T: Versioned>(state: &T) {
+    kernel::set_state(&encode(state));
+}
+
+/// Our saved state, or the default if there is none. A state that cannot be
+/// decoded is reported and copied to a file before the default replaces it.
+pub fn load_or_default<T: Versioned + Default>(our: &Address) -> T {
+    match get_state() {
+        Ok(state) => state.unwrap_or_default(),
+        Err(e) => {
+            let backup = kernel::get_state()
+                .map(|bytes| super::fs::write(our, bytes))
+                .transpose();
+            print_to_terminal(
+                0,
+                &format!(
+                    "{}: could not load state, starting afresh: {} ({})",
+                    our.process.process(),
+                    e,
+                    match backup {
+                        Ok(Some(file)) => format!("old state saved to file {}", file),
+                        Ok(None) => "no state to save".to_string(),
+                        Err(e) => format!("old state lost: {}", e),
+                    }
+                ),
+            );
+            T::default()
+        }
+    }
+}
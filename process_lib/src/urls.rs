@@ -0,0 +1,230 @@
+/// query parameters that only track where a visitor came from
+const TRACKING_PARAMS: &[&str] = &["fbclid", "gclid", "mc_cid", "mc_eid", "ref"];
+
+/// Normalizes an http(s) url so that trivially different links to the same page
+/// compare equal: the scheme and host are lowercased, default ports, fragments
+/// and tracking parameters are dropped, and a trailing slash is removed.
+pub fn normalize(url: &str) -> anyhow::Result<String> {
+    let (scheme, rest) = url
+        .trim()
+        .split_once("://")
+        .ok_or(anyhow::anyhow!("not an absolute url: {}", url))?;
+    let scheme = scheme.to_ascii_lowercase();
+    let default_port = match scheme.as_str() {
+        "http" => ":80",
+        "https" => ":443",
+        _ => return Err(anyhow::anyhow!("not an http(s) url: {}", url)),
+    };
+
+    let rest = rest.split('#').next().unwrap_or_default();
+    let authority_end = rest.find(['/', '?']).unwrap_or(rest.len());
+    let authority = rest[..authority_end].to_ascii_lowercase();
+    let authority = authority.strip_suffix(default_port).unwrap_or(&authority);
+    if authority.is_empty() {
+        return Err(anyhow::anyhow!("url has no host: {}", url));
+    }
+
+    let (path, query) = rest[authority_end..]
+        .split_once('?')
+        .unwrap_or((&rest[authority_end..], ""));
+    let path = path.trim_end_matches('/');
+    let query: Vec<&str> = query
+        .split('&')
+        .filter(|param| {
+            let name = param.split('=').next().unwrap_or_default();
+            !name.is_empty() && !name.starts_with("utm_") && !TRACKING_PARAMS.contains(&name)
+        })
+        .collect();
+
+    let mut normalized = format!("{}://{}{}", scheme, authority, path);
+    if !query.is_empty() {
+        normalized.push('?');
+        normalized.push_str(&query.join("&"));
+    }
+    Ok(normalized)
+}
+
+/// The scheme, host and port of an absolute url, lowercased and without a
+/// default port, or `None` if `url` is not absolute.
+pub fn origin(url: &str) -> Option<String> {
+    let (scheme, rest) = url.trim().split_once("://")?;
+    let scheme = scheme.to_ascii_lowercase();
+    let authority = rest[..rest.find(['/', '?', '#']).unwrap_or(rest.len())].to_ascii_lowercase();
+    let authority = match scheme.as_str() {
+        "http" => authority.strip_suffix(":80"),
+        "https" => authority.strip_suffix(":443"),
+        _ => None,
+    }
+    .unwrap_or(&authority);
+    Some(format!("{}://{}", scheme, authority))
+}
+
+/// Resolves a possibly relative link, such as an `href`, against the page it was found on.
+pub fn resolve(base: &str, href: &str) -> String {
+    if href.contains("://") {
+        return href.to_string();
+    }
+    let (scheme, rest) = base.split_once("://").unwrap_or(("https", base));
+    if let Some(network_path) = href.strip_prefix("//") {
+        return format!("{}://{}", scheme, network_path);
+    }
+    let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let origin = format!("{}://{}", scheme, &rest[..authority_end]);
+    if href.starts_with('/') {
+        return format!("{}{}", origin, href);
+    }
+    let path = rest[authority_end..]
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default();
+    let directory = &path[..path.rfind('/').map_or(0, |i| i + 1)];
+    if directory.is_empty() {
+        format!("{}/{}", origin, href)
+    } else {
+        format!("{}{}{}", origin, directory, href)
+    }
+}
+
+/// Percent-encodes everything but unreserved characters, for use in a path
+/// segment or query parameter.
+pub fn encode_component(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Decodes `%XX` escapes, as in a url path segment or query parameter.
+pub fn decode_component(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| text.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_trivially_different_urls() {
+        for url in [
+            "https://example.com/post",
+            "HTTPS://Example.COM/post/",
+            "https://example.com:443/post#comments",
+            "https://example.com/post?utm_source=feed&ref=home",
+            "  https://example.com/post?fbclid=1  ",
+        ] {
+            assert_eq!(
+                normalize(url).unwrap(),
+                "https://example.com/post",
+                "{}",
+                url
+            );
+        }
+        assert_eq!(
+            normalize("http://example.com:8080/a?b=1&utm_medium=x&c=2").unwrap(),
+            "http://example.com:8080/a?b=1&c=2"
+        );
+        // only the scheme and host are case-insensitive
+        assert_eq!(
+            normalize("https://example.com/Post").unwrap(),
+            "https://example.com/Post"
+        );
+        assert_eq!(
+            normalize("https://example.com").unwrap(),
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    fn rejects_urls_it_cannot_normalize() {
+        assert!(normalize("example.com/post").is_err());
+        assert!(normalize("ftp://example.com/file").is_err());
+        assert!(normalize("https:///post").is_err());
+    }
+
+    #[test]
+    fn resolves_links_against_their_page() {
+        let page = "https://example.com/blog/post?page=2#top";
+        for (href, resolved) in [
+            ("https://other.org/x", "https://other.org/x"),
+            ("//cdn.example.com/a.png", "https://cdn.example.com/a.png"),
+            ("/about", "https://example.com/about"),
+            ("next", "https://example.com/blog/next"),
+        ] {
+            assert_eq!(resolve(page, href), resolved, "{}", href);
+        }
+        assert_eq!(
+            resolve("https://example.com", "about"),
+            "https://example.com/about"
+        );
+        assert_eq!(
+            resolve("https://example.com?q=1", "/a"),
+            "https://example.com/a"
+        );
+    }
+
+    #[test]
+    fn compares_origins() {
+        assert_eq!(
+            origin("HTTPS://Example.com:443/a?b").as_deref(),
+            Some("https://example.com")
+        );
+        assert_eq!(
+            origin("http://example.com:80").as_deref(),
+            Some("http://example.com")
+        );
+        assert_eq!(
+            origin("https://example.com:8443/").as_deref(),
+            Some("https://example.com:8443")
+        );
+        assert_ne!(
+            origin("http://example.com/"),
+            origin("https://example.com/")
+        );
+        assert_eq!(origin("/relative"), None);
+    }
+
+    #[test]
+    fn round_trips_components() {
+        for text in ["plain", "a b/c?d=e&f", "naïve ☃", "100%", ""] {
+            assert_eq!(decode_component(&encode_component(text)), text);
+        }
+        assert_eq!(encode_component("a b~"), "a%20b~");
+    }
+
+    #[test]
+    fn decodes_components_leniently() {
+        assert_eq!(decode_component("a%2Fb%2fc"), "a/b/c");
+        assert_eq!(decode_component("%E2%98%83"), "☃");
+        // malformed or cut short escapes are kept as they are
+        assert_eq!(decode_component("100%"), "100%");
+        assert_eq!(decode_component("%zz%4"), "%zz%4");
+        // plus signs only mean spaces in query strings, see `parse_query`
+        assert_eq!(decode_component("a+b"), "a+b");
+        assert_eq!(decode_component("%FF"), "\u{FFFD}");
+    }
+}
@@ -0,0 +1,151 @@
+//! The filesystem client against the mock kernel, standing in for
+//! `filesystem:sys:uqbar`.
+
+use process_lib::fs::{self, FsAction, FsError, FsResponse};
+use process_lib::mock::{self, MockKernel};
+use process_lib::types::*;
+use serde_json::{json, Value};
+
+const FILESYSTEM: &str = "filesystem:sys:uqbar";
+const CONTENTS: &[u8] = b"hello, filesystem";
+
+fn our() -> Address {
+    "our.uq@notes:notes:uqbar".parse().unwrap()
+}
+
+/// Runs `f` as the process, with `kernel` answering its requests.
+fn run(kernel: MockKernel, f: impl FnOnce(&Address)) -> MockKernel {
+    kernel.run(|| f(&our()))
+}
+
+/// Serves [`CONTENTS`] as file 7.
+fn serve_file(kernel: &mut MockKernel) {
+    kernel.on_request(FILESYSTEM, |_, request, _| {
+        let (res, bytes) = match serde_json::from_str(request.ipc.as_deref().unwrap()).unwrap() {
+            FsAction::Read(7) => (json!({ "Read": 7 }), Some(CONTENTS.to_vec())),
+            FsAction::ReadChunk(chunk) if chunk.file_uuid == 7 => {
+                let start = (chunk.start as usize).min(CONTENTS.len());
+                let end = (start + chunk.length as usize).min(CONTENTS.len());
+                (
+                    json!({ "ReadChunk": 7 }),
+                    Some(CONTENTS[start..end].to_vec()),
+                )
+            }
+            FsAction::Length(7) => (json!({ "Length": CONTENTS.len() }), None),
+            FsAction::Append(Some(7)) => (json!({ "Append": 7 }), None),
+            action => (json!({ "Err": format!("no file for {:?}", action) }), None),
+        };
+        let res = match res.get("Err") {
+            Some(_) => res,
+            None => json!({ "Ok": res }),
+        };
+        mock::reply(
+            &res.to_string(),
+            bytes.map(|bytes| Payload { mime: None, bytes }),
+        )
+    });
+}
+
+/// Answers every request with `ipc` and no payload.
+fn answer_with(kernel: &mut MockKernel, ipc: Value) {
+    kernel.on_request(FILESYSTEM, move |_, _, _| {
+        mock::reply(&ipc.to_string(), None)
+    });
+}
+
+#[test]
+fn reads_files_whole_and_in_chunks() {
+    let mut kernel = MockKernel::new(our());
+    serve_file(&mut kernel);
+    let kernel = run(kernel, |our| {
+        assert_eq!(fs::read(our, 7).unwrap(), CONTENTS);
+        assert_eq!(fs::read_chunk(our, 7, 0, 5).unwrap(), b"hello");
+        assert_eq!(fs::read_chunk(our, 7, 7, 100).unwrap(), b"filesystem");
+        assert_eq!(fs::read_chunk(our, 7, 100, 5).unwrap(), b"");
+        assert_eq!(fs::len(our, 7).unwrap(), CONTENTS.len() as u64);
+    });
+
+    let ipc: Value =
+        serde_json::from_str(kernel.requests[2].request.ipc.as_deref().unwrap()).unwrap();
+    assert_eq!(
+        ipc,
+        json!({ "ReadChunk": { "file_uuid": 7, "start": 7, "length": 100 } })
+    );
+    assert!(kernel
+        .requests
+        .iter()
+        .all(|request| request.target.to_string() == "our.uq@filesystem:sys:uqbar"));
+}
+
+#[test]
+fn appends_with_the_bytes_as_payload() {
+    let mut kernel = MockKernel::new(our());
+    serve_file(&mut kernel);
+    let kernel = run(kernel, |our| {
+        assert_eq!(fs::append(our, Some(7), b"!".to_vec()).unwrap(), 7);
+    });
+    assert_eq!(kernel.requests[0].payload.as_ref().unwrap().bytes, b"!");
+}
+
+#[test]
+fn passes_on_what_the_filesystem_refused() {
+    let mut kernel = MockKernel::new(our());
+    serve_file(&mut kernel);
+    run(kernel, |our| {
+        let e = fs::read(our, 8).unwrap_err();
+        assert!(matches!(&e, FsError::Refused(Value::String(e)) if e == "no file for Read(8)"));
+        assert_eq!(e.to_string(), "filesystem error: \"no file for Read(8)\"");
+    });
+}
+
+#[test]
+fn tells_a_filesystem_that_did_not_answer_from_an_offline_one() {
+    run(MockKernel::new(our()), |our| {
+        let e = fs::len(our, 7).unwrap_err();
+        assert!(matches!(e, FsError::Unreachable(SendErrorKind::Timeout)));
+        assert_eq!(e.to_string(), "filesystem did not answer in 5s");
+    });
+
+    let mut kernel = MockKernel::new(our());
+    kernel.on_request(FILESYSTEM, |_, _, _| Err(SendErrorKind::Offline));
+    run(kernel, |our| {
+        let e = fs::delete(our, 7).unwrap_err();
+        assert!(matches!(e, FsError::Unreachable(SendErrorKind::Offline)));
+        assert_eq!(e.to_string(), "filesystem is offline");
+    });
+}
+
+#[test]
+fn rejects_malformed_responses() {
+    let mut kernel = MockKernel::new(our());
+    answer_with(&mut kernel, json!("Read"));
+    run(kernel, |our| {
+        let e = fs::read(our, 7).unwrap_err();
+        assert!(matches!(e, FsError::BadResponse(_)));
+        assert!(e.to_string().starts_with("malformed filesystem response: "));
+    });
+}
+
+#[test]
+fn rejects_answers_to_other_actions() {
+    let mut kernel = MockKernel::new(our());
+    answer_with(&mut kernel, json!({ "Ok": { "Write": 7 } }));
+    run(kernel, |our| {
+        let e = fs::len(our, 7).unwrap_err();
+        assert!(matches!(
+            e,
+            FsError::UnexpectedResponse(FsResponse::Write(7))
+        ));
+    });
+
+    // a read answered without the file's bytes
+    let mut kernel = MockKernel::new(our());
+    answer_with(&mut kernel, json!({ "Ok": { "ReadChunk": 7 } }));
+    run(kernel, |our| {
+        let e = fs::read_chunk(our, 7, 0, 5).unwrap_err();
+        assert!(matches!(
+            e,
+            FsError::UnexpectedResponse(FsResponse::ReadChunk(7))
+        ));
+    });
+}
@@ -0,0 +1,85 @@
+//! The HTTP client against the mock kernel, standing in for
+//! `http_client:sys:uqbar`.
+
+use process_lib::http::Request;
+use process_lib::mock::{self, MockKernel};
+use process_lib::types::*;
+use serde_json::{json, Value};
+
+fn our() -> Address {
+    "our.uq@notes:notes:uqbar".parse().unwrap()
+}
+
+/// Redirects requests to `from` to `to`, and answers everything else with 200.
+fn redirect(kernel: &mut MockKernel, from: &'static str, to: &'static str) {
+    kernel.on_request("http_client:sys:uqbar", move |_, request, _| {
+        let ipc: Value = serde_json::from_str(request.ipc.as_deref().unwrap()).unwrap();
+        let res = match ipc["uri"] == from {
+            true => json!({ "status": 307, "headers": { "Location": to } }),
+            false => json!({ "status": 200, "headers": {} }),
+        };
+        mock::reply(&res.to_string(), None)
+    });
+}
+
+/// Sends an authenticated request to `uri`, returning the headers each
+/// request to http_client carried, by uri.
+fn send(kernel: MockKernel, uri: &str) -> Vec<(String, Value)> {
+    let kernel = kernel.run(|| {
+        let res = Request::post(uri)
+            .credential("Api-Key", "secret")
+            .header("Authorization", "Bearer secret")
+            .header("accept", "application/json")
+            .send(&our())
+            .unwrap();
+        assert_eq!(res.status, 200);
+    });
+    kernel
+        .requests
+        .iter()
+        .map(|request| {
+            let ipc: Value = serde_json::from_str(request.request.ipc.as_deref().unwrap()).unwrap();
+            (
+                ipc["uri"].as_str().unwrap().to_string(),
+                ipc["headers"].clone(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn keeps_credentials_on_redirects_within_the_origin() {
+    let mut kernel = MockKernel::new(our());
+    redirect(&mut kernel, "https://api.example.com/v1", "/v2");
+    let sent = send(kernel, "https://api.example.com/v1");
+
+    assert_eq!(sent.len(), 2);
+    let (uri, headers) = &sent[1];
+    assert_eq!(uri, "https://api.example.com/v2");
+    assert_eq!(headers["Api-Key"], "secret");
+    assert_eq!(headers["Authorization"], "Bearer secret");
+}
+
+#[test]
+fn drops_credentials_when_a_redirect_leaves_the_origin() {
+    for to in [
+        "https://elsewhere.example.net/v2",
+        "https://api.example.com:8443/v2",
+        "http://api.example.com/v2",
+    ] {
+        let mut kernel = MockKernel::new(our());
+        redirect(&mut kernel, "https://api.example.com/v1", to);
+        let sent = send(kernel, "https://api.example.com/v1");
+
+        assert_eq!(sent[0].1["Api-Key"], "secret");
+        let (uri, headers) = &sent[1];
+        assert_eq!(uri, to);
+        assert!(headers.get("Api-Key").is_none(), "{} got the key", to);
+        assert!(
+            headers.get("Authorization").is_none(),
+            "{} got the token",
+            to
+        );
+        assert_eq!(headers["accept"], "application/json");
+    }
+}
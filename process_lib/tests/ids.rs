@@ -0,0 +1,142 @@
+use process_lib::types::{Address, ProcessId};
+use process_lib::{AddressParseError, PackageId, ProcessIdParseError};
+use proptest::prelude::*;
+
+/// a valid node, process, package or publisher name
+fn name() -> impl Strategy<Value = String> {
+    "[A-Za-z0-9._-]{1,16}"
+}
+
+fn process_id() -> impl Strategy<Value = ProcessId> {
+    (name(), name(), name())
+        .prop_map(|(process, package, publisher)| ProcessId::new(&process, &package, &publisher))
+}
+
+fn address() -> impl Strategy<Value = Address> {
+    (name(), process_id()).prop_map(|(node, process)| Address::new(&node, process))
+}
+
+proptest! {
+    #[test]
+    fn package_id_round_trips(package in name(), publisher in name()) {
+        let id = PackageId::new(&package, &publisher);
+        prop_assert_eq!(id.to_string().parse::<PackageId>(), Ok(id.clone()));
+        let json = serde_json::to_string(&id).unwrap();
+        prop_assert_eq!(&json, &format!("\"{}\"", id));
+        prop_assert_eq!(serde_json::from_str::<PackageId>(&json).unwrap(), id.clone());
+        let old = serde_json::json!({ "package_name": package, "publisher_node": publisher });
+        prop_assert_eq!(serde_json::from_value::<PackageId>(old).unwrap(), id.clone());
+        let bytes = bincode::serialize(&id).unwrap();
+        prop_assert_eq!(bincode::deserialize::<PackageId>(&bytes).unwrap(), id);
+    }
+
+    #[test]
+    fn process_id_round_trips(id in process_id()) {
+        prop_assert_eq!(id.to_string().parse::<ProcessId>(), Ok(id.clone()));
+        prop_assert_eq!(ProcessId::try_from(id.to_string().as_str()), Ok(id.clone()));
+        let json = serde_json::to_string(&id).unwrap();
+        prop_assert_eq!(&json, &format!("\"{}\"", id));
+        prop_assert_eq!(serde_json::from_str::<ProcessId>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn address_round_trips(address in address()) {
+        prop_assert_eq!(address.to_string().parse::<Address>(), Ok(address.clone()));
+        prop_assert_eq!(Address::try_from(address.to_string().as_str()), Ok(address.clone()));
+        let json = serde_json::to_string(&address).unwrap();
+        prop_assert_eq!(&json, &format!("\"{}\"", address));
+        prop_assert_eq!(serde_json::from_str::<Address>(&json).unwrap(), address);
+    }
+
+    /// Whatever parses is printed back exactly as it was written.
+    #[test]
+    fn parsing_is_canonical(input in "[a-z:@.%_ ]{0,24}") {
+        if let Ok(id) = input.parse::<ProcessId>() {
+            prop_assert_eq!(id.to_string(), input.clone());
+        }
+        if let Ok(address) = input.parse::<Address>() {
+            prop_assert_eq!(address.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn invalid_characters_are_rejected(id in process_id(), at in 0usize..3, c in "[^A-Za-z0-9._\\-:@]") {
+        let c = c.chars().next().unwrap();
+        let mut names = [id.process_name, id.package_name, id.publisher_node];
+        names[at].push(c);
+        let input = names.join(":");
+        prop_assert_eq!(
+            input.parse::<ProcessId>(),
+            Err(ProcessIdParseError::InvalidCharacter(c))
+        );
+    }
+}
+
+#[test]
+fn empty_fields_are_rejected() {
+    for input in ["a::c", ":b:c", "a:b:", "::"] {
+        assert_eq!(
+            input.parse::<ProcessId>(),
+            Err(ProcessIdParseError::EmptyField),
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        "node@a::c".parse::<Address>(),
+        Err(AddressParseError::EmptyField)
+    );
+    assert_eq!(
+        "@a:b:c".parse::<Address>(),
+        Err(AddressParseError::MissingNodeId)
+    );
+    assert_eq!(
+        ":b".parse::<PackageId>(),
+        Err(ProcessIdParseError::EmptyField)
+    );
+}
+
+#[test]
+fn field_counts_are_checked() {
+    assert_eq!(
+        "a:b".parse::<ProcessId>(),
+        Err(ProcessIdParseError::MissingField)
+    );
+    assert_eq!(
+        "a:b:c:d".parse::<ProcessId>(),
+        Err(ProcessIdParseError::TooManyColons)
+    );
+    assert_eq!(
+        "a:b:c".parse::<Address>(),
+        Err(AddressParseError::MissingNodeId)
+    );
+    assert_eq!(
+        "node@a:b:c:d".parse::<Address>(),
+        Err(AddressParseError::TooManyColons)
+    );
+}
+
+#[test]
+fn a_second_at_is_rejected() {
+    assert_eq!(
+        "node@a:b:c@other".parse::<Address>(),
+        Err(AddressParseError::TooManyAts)
+    );
+    assert_eq!(
+        "node@@a:b:c".parse::<Address>(),
+        Err(AddressParseError::TooManyAts)
+    );
+}
+
+#[test]
+fn deserializing_checks_the_grammar() {
+    assert!(serde_json::from_str::<ProcessId>("\"a::c\"").is_err());
+    assert!(serde_json::from_str::<Address>("\"n@a:b:c@d\"").is_err());
+    assert_eq!(
+        serde_json::from_str::<Address>("\"our.uq@librarian:librarian:drew.uq\"").unwrap(),
+        Address::new(
+            "our.uq",
+            ProcessId::new("librarian", "librarian", "drew.uq")
+        )
+    );
+}
@@ -0,0 +1,145 @@
+//! Loading states saved by older versions of a process. The fixtures were
+//! written by those versions and must never be regenerated: they are what a
+//! node upgrading from them actually has saved.
+
+use process_lib::state::{self, Migration, StateError, Versioned};
+use serde::{Deserialize, Serialize};
+
+/// saved before states were versioned
+const NOTES_V0: &[u8] = include_bytes!("fixtures/notes-v0.bin");
+const NOTES_V1: &[u8] = include_bytes!("fixtures/notes-v1.bin");
+const NOTES_V2: &[u8] = include_bytes!("fixtures/notes-v2.bin");
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct NotesV0 {
+    notes: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct NotesV1 {
+    notes: Vec<String>,
+    pinned: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Notes {
+    notes: Vec<Note>,
+    /// index into `notes`
+    pinned: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Note {
+    text: String,
+    created: u64,
+}
+
+impl Versioned for Notes {
+    const VERSION: u32 = 2;
+    const MIGRATIONS: &'static [Migration] = &[
+        |bytes| {
+            state::convert(bytes, |old: NotesV0| NotesV1 {
+                notes: old.notes,
+                pinned: None,
+            })
+        },
+        |bytes| {
+            state::convert(bytes, |old: NotesV1| Notes {
+                pinned: old
+                    .pinned
+                    .and_then(|pinned| old.notes.iter().position(|note| *note == pinned)),
+                notes: old
+                    .notes
+                    .into_iter()
+                    .map(|text| Note { text, created: 0 })
+                    .collect(),
+            })
+        },
+    ];
+}
+
+/// version 1 as it would be without a migration from version 0
+impl Versioned for NotesV1 {
+    const VERSION: u32 = 1;
+    const MIGRATIONS: &'static [Migration] = &[];
+}
+
+fn note(text: &str, created: u64) -> Note {
+    Note {
+        text: text.to_string(),
+        created,
+    }
+}
+
+#[test]
+fn loads_an_unversioned_state() {
+    assert_eq!(
+        state::decode::<Notes>(NOTES_V0).unwrap(),
+        Notes {
+            notes: vec![note("milk", 0), note("eggs", 0)],
+            pinned: None,
+        }
+    );
+}
+
+#[test]
+fn migrates_through_every_version() {
+    assert_eq!(
+        state::decode::<Notes>(NOTES_V1).unwrap(),
+        Notes {
+            notes: vec![note("milk", 0), note("eggs", 0)],
+            pinned: Some(1),
+        }
+    );
+}
+
+#[test]
+fn loads_the_current_version() {
+    let notes = Notes {
+        notes: vec![note("milk", 1700000000), note("eggs", 1700000100)],
+        pinned: Some(0),
+    };
+    assert_eq!(state::decode::<Notes>(NOTES_V2).unwrap(), notes);
+    assert_eq!(state::encode(&notes), NOTES_V2);
+}
+
+#[test]
+fn rejects_a_newer_version() {
+    assert!(matches!(
+        state::decode::<NotesV1>(NOTES_V2),
+        Err(StateError::UnknownVersion {
+            found: 2,
+            current: 1
+        })
+    ));
+}
+
+#[test]
+fn reports_a_missing_migration() {
+    assert!(matches!(
+        state::decode::<NotesV1>(NOTES_V0),
+        Err(StateError::MissingMigration(0))
+    ));
+}
+
+#[test]
+fn reports_a_failed_migration() {
+    // a version 1 state that is not a NotesV1
+    let mut bytes = b"UQST".to_vec();
+    bytes.extend(1u32.to_le_bytes());
+    bytes.push(7);
+    assert!(matches!(
+        state::decode::<Notes>(&bytes),
+        Err(StateError::Migration { from: 1, .. })
+    ));
+}
+
+#[test]
+fn reports_a_corrupt_state() {
+    let mut bytes = NOTES_V2.to_vec();
+    bytes.truncate(bytes.len() - 3);
+    assert!(matches!(
+        state::decode::<Notes>(&bytes),
+        Err(StateError::Decode { version: 2, .. })
+    ));
+}
@@ -0,0 +1,209 @@
+use process_lib::protocol::{ChunkStrategy, ChunkingConfig};
+
+#[derive(Debug, Clone)]
+pub struct Chunk {
+    pub index: usize,
+    /// byte offsets of the chunk within the document body
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+/// Splits `text` into overlapping chunks. Each chunk is a window of `config.size`
+/// units, and consecutive windows share `config.overlap` units.
+pub fn chunk(text: &str, config: &ChunkingConfig) -> Vec<Chunk> {
+    let units = match config.strategy {
+        ChunkStrategy::Tokens => token_spans(text),
+        ChunkStrategy::Sentences => sentence_spans(text),
+        ChunkStrategy::Paragraphs => paragraph_spans(text),
+    };
+    if units.is_empty() {
+        return vec![];
+    }
+    let size = config.size.max(1);
+    let step = size.saturating_sub(config.overlap).max(1);
+
+    let mut chunks = vec![];
+    let mut first = 0;
+    loop {
+        let last = (first + size).min(units.len());
+        let (start, end) = (units[first].0, units[last - 1].1);
+        chunks.push(Chunk {
+            index: chunks.len(),
+            start,
+            end,
+            text: text[start..end].to_string(),
+        });
+        if last == units.len() {
+            return chunks;
+        }
+        first += step;
+    }
+}
+
+fn token_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = vec![];
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        if c.is_whitespace() {
+            if let Some(s) = start.take() {
+                spans.push((s, i));
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, text.len()));
+    }
+    spans
+}
+
+fn sentence_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = vec![];
+    let mut start = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if start.is_none() {
+            if c.is_whitespace() {
+                continue;
+            }
+            start = Some(i);
+        }
+        // punctuation only ends a sentence when followed by whitespace, so that
+        // decimals like "3.5" and domains like "uqbar.org" stay in one sentence
+        if matches!(c, '.' | '!' | '?') && chars.peek().map_or(true, |(_, n)| n.is_whitespace()) {
+            if let Some(s) = start.take() {
+                spans.push((s, i + c.len_utf8()));
+            }
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, text.trim_end().len()));
+    }
+    spans
+}
+
+fn paragraph_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = vec![];
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(s) = start.take() {
+                spans.push((s, end));
+            }
+        } else {
+            if start.is_none() {
+                start = Some(offset + line.len() - line.trim_start().len());
+            }
+            end = offset + line.trim_end().len();
+        }
+        offset += line.len();
+    }
+    if let Some(s) = start {
+        spans.push((s, end));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(strategy: ChunkStrategy, size: usize, overlap: usize) -> ChunkingConfig {
+        ChunkingConfig {
+            strategy,
+            size,
+            overlap,
+        }
+    }
+
+    fn texts(chunks: &[Chunk]) -> Vec<&str> {
+        chunks.iter().map(|chunk| chunk.text.as_str()).collect()
+    }
+
+    #[test]
+    fn empty_documents_have_no_chunks() {
+        for strategy in [
+            ChunkStrategy::Tokens,
+            ChunkStrategy::Sentences,
+            ChunkStrategy::Paragraphs,
+        ] {
+            assert!(chunk("", &config(strategy, 4, 1)).is_empty());
+            assert!(chunk(" \n\n \t", &config(strategy, 4, 1)).is_empty());
+        }
+    }
+
+    #[test]
+    fn text_shorter_than_a_chunk_is_one_chunk() {
+        let chunks = chunk(
+            "  three short words \n",
+            &config(ChunkStrategy::Tokens, 10, 2),
+        );
+        assert_eq!(texts(&chunks), vec!["three short words"]);
+        assert_eq!(
+            (chunks[0].index, chunks[0].start, chunks[0].end),
+            (0, 2, 19)
+        );
+    }
+
+    #[test]
+    fn consecutive_chunks_overlap() {
+        let chunks = chunk("a b c d e f g", &config(ChunkStrategy::Tokens, 3, 1));
+        assert_eq!(texts(&chunks), vec!["a b c", "c d e", "e f g"]);
+        assert_eq!(
+            chunks.iter().map(|chunk| chunk.index).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn the_last_chunk_ends_the_document() {
+        let chunks = chunk("a b c d e", &config(ChunkStrategy::Tokens, 3, 1));
+        assert_eq!(texts(&chunks), vec!["a b c", "c d e"]);
+
+        // a chunk that reaches the end is the last, even if the step would fit another
+        let chunks = chunk("a b c d", &config(ChunkStrategy::Tokens, 3, 1));
+        assert_eq!(texts(&chunks), vec!["a b c", "c d"]);
+    }
+
+    #[test]
+    fn overlap_as_large_as_the_chunk_still_advances() {
+        let chunks = chunk("a b c", &config(ChunkStrategy::Tokens, 2, 5));
+        assert_eq!(texts(&chunks), vec!["a b", "b c"]);
+        let chunks = chunk("a b", &config(ChunkStrategy::Tokens, 0, 0));
+        assert_eq!(texts(&chunks), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn splits_between_multi_byte_characters() {
+        let text = "naïve café — 東京 übermäßig";
+        let chunks = chunk(text, &config(ChunkStrategy::Tokens, 2, 0));
+        assert_eq!(texts(&chunks), vec!["naïve café", "— 東京", "übermäßig"]);
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.start..chunk.end], chunk.text);
+        }
+    }
+
+    #[test]
+    fn sentences_end_at_punctuation_followed_by_whitespace() {
+        let text = "Rust 1.75 is out. See rust-lang.org! Why? Because\n";
+        let chunks = chunk(text, &config(ChunkStrategy::Sentences, 1, 0));
+        assert_eq!(
+            texts(&chunks),
+            vec!["Rust 1.75 is out.", "See rust-lang.org!", "Why?", "Because"]
+        );
+    }
+
+    #[test]
+    fn paragraphs_are_separated_by_blank_lines() {
+        let text = "  first line\nsecond line  \n\n \nnext paragraph\n";
+        let chunks = chunk(text, &config(ChunkStrategy::Paragraphs, 1, 0));
+        assert_eq!(
+            texts(&chunks),
+            vec!["first line\nsecond line", "next paragraph"]
+        );
+    }
+}
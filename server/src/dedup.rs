@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+/// words per shingle when fingerprinting
+const SHINGLE_SIZE: usize = 3;
+/// fingerprints at most this many bits apart are considered near-duplicates
+pub const NEAR_DUPLICATE_DISTANCE: u32 = 3;
+
+/// 64-bit SimHash of the word shingles of `text`. Texts that differ only in
+/// a few words, punctuation or case have fingerprints a few bits apart. Texts
+/// without any words have no fingerprint.
+pub fn simhash(text: &str) -> Option<u64> {
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+
+    let mut weights = [0i32; 64];
+    for shingle in words.windows(SHINGLE_SIZE.min(words.len())) {
+        let hash = fnv1a(shingle.join(" ").as_bytes());
+        for (bit, weight) in weights.iter_mut().enumerate() {
+            if hash >> bit & 1 == 1 {
+                *weight += 1;
+            } else {
+                *weight -= 1;
+            }
+        }
+    }
+    let fingerprint = weights
+        .iter()
+        .enumerate()
+        .filter(|(_, &weight)| weight > 0)
+        .fold(0, |fingerprint, (bit, _)| fingerprint | 1 << bit);
+    Some(fingerprint)
+}
+
+pub fn is_near_duplicate(a: u64, b: u64) -> bool {
+    (a ^ b).count_ones() <= NEAR_DUPLICATE_DISTANCE
+}
+
+/// fingerprints are split into this many bands; two fingerprints that differ in
+/// at most `NEAR_DUPLICATE_DISTANCE` bits agree on at least one of them
+const BANDS: u32 = NEAR_DUPLICATE_DISTANCE + 1;
+const BAND_BITS: u32 = 64 / BANDS;
+
+/// Document fingerprints by band, so that finding near-duplicates only compares
+/// fingerprints that share a band rather than every one.
+#[derive(Debug, Default)]
+pub struct FingerprintIndex {
+    /// by band number and the band's bits
+    bands: HashMap<(u32, u64), Vec<(String, u64)>>,
+}
+
+fn bands(fingerprint: u64) -> impl Iterator<Item = (u32, u64)> {
+    (0..BANDS).map(move |band| {
+        let bits = fingerprint >> (band * BAND_BITS) & ((1 << BAND_BITS) - 1);
+        (band, bits)
+    })
+}
+
+impl FingerprintIndex {
+    pub fn insert(&mut self, id: &str, fingerprint: u64) {
+        for band in bands(fingerprint) {
+            self.bands
+                .entry(band)
+                .or_default()
+                .push((id.to_string(), fingerprint));
+        }
+    }
+
+    pub fn remove(&mut self, id: &str, fingerprint: u64) {
+        for band in bands(fingerprint) {
+            if let Some(entries) = self.bands.get_mut(&band) {
+                entries.retain(|(entry, _)| entry != id);
+                if entries.is_empty() {
+                    self.bands.remove(&band);
+                }
+            }
+        }
+    }
+
+    /// The ids of the near-duplicates of `fingerprint`, in order.
+    pub fn near_duplicates(&self, fingerprint: u64) -> Vec<&str> {
+        let mut ids: Vec<&str> = bands(fingerprint)
+            .filter_map(|band| self.bands.get(&band))
+            .flatten()
+            .filter(|(_, other)| is_near_duplicate(fingerprint, *other))
+            .map(|(id, _)| id.as_str())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+}
+
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// FNV-1a, used instead of `DefaultHasher` because fingerprints and cursors
+/// outlive the process and must not change between compiler versions.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STORY: &str = "The city council voted on Tuesday to expand the bike lane network \
+        across the downtown core, adding twelve miles of protected lanes by next spring. \
+        Supporters packed the chamber for the vote, which passed seven to two after a long \
+        debate about parking and loading zones. The plan also funds new crossings near three \
+        schools, and the transit agency will retime signals along the main avenues so that \
+        buses and bikes can share the corridor. Construction is expected to start in March, \
+        with the first segment opening before the summer.";
+    const OTHER: &str = "Knead the dough for ten minutes, cover it and let the bread \
+        rise overnight before shaping the loaves in the morning.";
+
+    fn fingerprint(text: &str) -> u64 {
+        simhash(text).unwrap()
+    }
+
+    fn distance(a: &str, b: &str) -> u32 {
+        (fingerprint(a) ^ fingerprint(b)).count_ones()
+    }
+
+    #[test]
+    fn fingerprints_ignore_case_and_punctuation() {
+        let shouted = STORY.to_uppercase().replace([',', '.'], "");
+        assert_eq!(simhash(STORY), simhash(&shouted));
+        assert_eq!(simhash(""), None);
+        assert_eq!(simhash("?! ..."), None);
+    }
+
+    #[test]
+    fn edits_move_fingerprints_less_than_other_texts() {
+        let unrelated = distance(STORY, OTHER);
+        for (from, to) in [("Tuesday", "Monday"), ("twelve", "12"), ("seven", "eight")] {
+            let edited = distance(STORY, &STORY.replace(from, to));
+            assert!(edited > 0 && edited < unrelated / 2, "{} -> {}", from, to);
+        }
+        assert!(is_near_duplicate(
+            fingerprint(STORY),
+            fingerprint(&STORY.replace("Tuesday", "Monday"))
+        ));
+        assert!(!is_near_duplicate(fingerprint(STORY), fingerprint(OTHER)));
+    }
+
+    #[test]
+    fn near_duplicate_distance_is_inclusive() {
+        assert!(is_near_duplicate(0, 0b111));
+        assert!(!is_near_duplicate(0, 0b1111));
+    }
+
+    #[test]
+    fn finds_near_duplicates_differing_in_every_band() {
+        let mut index = FingerprintIndex::default();
+        let fingerprint = 0x0123_4567_89ab_cdef;
+        // three bits flipped in three different bands leaves one band to match on
+        let close = fingerprint ^ (1 | 1 << 20 | 1 << 40);
+        let far = fingerprint ^ (1 | 1 << 20 | 1 << 40 | 1 << 60);
+        index.insert("close", close);
+        index.insert("far", far);
+        index.insert("same", fingerprint);
+        assert_eq!(index.near_duplicates(fingerprint), vec!["close", "same"]);
+
+        index.remove("same", fingerprint);
+        assert_eq!(index.near_duplicates(fingerprint), vec!["close"]);
+        index.remove("close", close);
+        assert!(index.near_duplicates(fingerprint).is_empty());
+        assert_eq!(index.near_duplicates(far), vec!["far"]);
+    }
+
+    #[test]
+    fn cosine_similarity_of_zero_vectors_is_zero() {
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+        assert!((cosine_similarity(&[1.0, 1.0], &[2.0, 2.0]) - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,42 @@
+use process_lib::http;
+use process_lib::types::*;
+use serde_json::json;
+
+#[cfg(target_arch = "wasm32")]
+const HUGGINGFACE_API_KEY: &str = include_str!("huggingface-api-key.txt");
+/// tests run without the keys, which are not checked in
+#[cfg(not(target_arch = "wasm32"))]
+const HUGGINGFACE_API_KEY: &str = "test";
+/// same model the frontend runs in its worker, so stored and query vectors are comparable
+const EMBEDDING_URI: &str = "https://api-inference.huggingface.co/pipeline/feature-extraction/sentence-transformers/all-MiniLM-L6-v2";
+const EMBEDDING_TIMEOUT: u64 = 60;
+const EMBEDDING_BATCH_SIZE: usize = 32;
+
+/// Embeds each of `texts`, returning one vector per text in the same order.
+pub fn embed(our: &Address, texts: &[String]) -> anyhow::Result<Vec<Vec<f32>>> {
+    let mut vectors = Vec::with_capacity(texts.len());
+    for batch in texts.chunks(EMBEDDING_BATCH_SIZE) {
+        let batch_vectors: Vec<Vec<f32>> = http::Request::post(EMBEDDING_URI)
+            .credential(
+                "Authorization",
+                &format!("Bearer {}", HUGGINGFACE_API_KEY.trim()),
+            )
+            .json(&json!({
+                "inputs": batch,
+                "options": { "wait_for_model": true }
+            }))?
+            .timeout(EMBEDDING_TIMEOUT)
+            .send(our)?
+            .error_for_status()?
+            .json()?;
+        if batch_vectors.len() != batch.len() {
+            return Err(anyhow::anyhow!(
+                "embedding service returned {} vectors for {} inputs",
+                batch_vectors.len(),
+                batch.len()
+            ));
+        }
+        vectors.extend(batch_vectors);
+    }
+    Ok(vectors)
+}
@@ -0,0 +1,111 @@
+mod html;
+mod markdown;
+
+/// Text and metadata pulled out of a document before it is chunked.
+#[derive(Debug, Default)]
+pub struct Extracted {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub date: Option<String>,
+    pub publication: Option<String>,
+    pub url: Option<String>,
+    /// set when the document asks not to be indexed, e.g. `<meta name="robots" content="noindex">`
+    pub noindex: bool,
+    pub text: String,
+}
+
+impl Extracted {
+    /// Adds the extracted fields to `metadata` without overwriting fields the
+    /// caller set explicitly, and returns the body text.
+    pub fn merge_into(self, metadata: &mut serde_json::Map<String, serde_json::Value>) -> String {
+        for (key, value) in [
+            ("title", self.title),
+            ("author", self.author),
+            ("date", self.date),
+            ("publication", self.publication),
+            ("url", self.url),
+        ] {
+            if let Some(value) = value {
+                metadata.entry(key).or_insert_with(|| value.into());
+            }
+        }
+        self.text
+    }
+}
+
+/// Whether a robots directive list, as found in `X-Robots-Tag` headers and
+/// robots meta tags, forbids indexing. Directives may be scoped to a user agent,
+/// as in `googlebot: noindex`.
+pub fn forbids_indexing(directives: &str) -> bool {
+    directives.split(',').any(|directive| {
+        let directive = directive.rsplit(':').next().unwrap_or_default();
+        matches!(
+            directive.trim().to_ascii_lowercase().as_str(),
+            "noindex" | "none"
+        )
+    })
+}
+
+/// Extracts the readable text of a document based on its mime type. Documents
+/// without a mime type are taken to be plain text.
+pub fn extract(mime: Option<&str>, bytes: &[u8]) -> anyhow::Result<Extracted> {
+    let content = String::from_utf8_lossy(bytes);
+    // ignore parameters such as "; charset=utf-8"
+    let essence = mime.map(|mime| {
+        mime.split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase()
+    });
+    match essence.as_deref() {
+        Some("text/html") | Some("application/xhtml+xml") => Ok(html::extract(&content)),
+        Some("text/markdown") | Some("text/x-markdown") => Ok(markdown::extract(&content)),
+        None | Some("text/plain") => Ok(Extracted {
+            text: content.trim().to_string(),
+            ..Default::default()
+        }),
+        Some(other) => Err(anyhow::anyhow!(
+            "cannot extract text from {} content",
+            other
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn noindex_and_none_forbid_indexing() {
+        for directives in ["noindex", "NOINDEX", "nofollow, noindex", "none", " None "] {
+            assert!(forbids_indexing(directives), "{}", directives);
+        }
+        for directives in ["", "index, follow", "nofollow", "noarchive", "noindexer"] {
+            assert!(!forbids_indexing(directives), "{}", directives);
+        }
+    }
+
+    #[test]
+    fn directives_scoped_to_any_user_agent_forbid_indexing() {
+        assert!(forbids_indexing("googlebot: noindex"));
+        assert!(forbids_indexing("otherbot: none"));
+        assert!(forbids_indexing("googlebot: nofollow, bingbot: noindex"));
+        assert!(!forbids_indexing("googlebot: nofollow"));
+        // a date with colons in it is not a user agent
+        assert!(!forbids_indexing(
+            "unavailable_after: 25 Jun 2030 15:00:00 PST"
+        ));
+    }
+
+    #[test]
+    fn extracts_by_mime_type() {
+        let extracted = extract(Some("text/plain; charset=utf-8"), b"  plain text \n").unwrap();
+        assert_eq!(extracted.text, "plain text");
+        assert_eq!(extract(None, b"no type").unwrap().text, "no type");
+        let extracted = extract(Some("Text/HTML"), b"<p>Hello</p>").unwrap();
+        assert_eq!(extracted.text, "Hello");
+        let e = extract(Some("image/png"), b"\x89PNG").unwrap_err();
+        assert_eq!(e.to_string(), "cannot extract text from image/png content");
+    }
+}
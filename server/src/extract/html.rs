@@ -0,0 +1,438 @@
+use super::Extracted;
+
+/// elements whose content is page furniture rather than part of the document
+const SKIPPED_ELEMENTS: &[&str] = &[
+    "nav", "header", "footer", "aside", "form", "button", "select", "svg", "iframe", "noscript",
+    "template", "object",
+];
+/// elements whose content is raw text that may itself contain `<`
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "title", "textarea"];
+/// elements that start a new paragraph of text
+const BLOCK_ELEMENTS: &[&str] = &[
+    "p",
+    "div",
+    "section",
+    "article",
+    "main",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "li",
+    "ul",
+    "ol",
+    "dl",
+    "dt",
+    "dd",
+    "blockquote",
+    "pre",
+    "table",
+    "tr",
+    "figure",
+    "figcaption",
+    "br",
+    "hr",
+];
+
+/// Extracts the readable text and `<head>` metadata of an HTML page. If the page
+/// marks up its content with `<article>` or `<main>`, only that text is kept.
+pub fn extract(html: &str) -> Extracted {
+    let mut extracted = Extracted::default();
+    let mut meta_title = None;
+    let mut page = Paragraphs::default();
+    let mut main = Paragraphs::default();
+    let mut main_depth = 0usize;
+    // the skipped element we are inside of, and how deeply it is nested in itself
+    let mut skipped: Option<(String, usize)> = None;
+
+    // ASCII lowercasing keeps byte offsets identical to `html`
+    let lower = html.to_ascii_lowercase();
+    let mut pos = 0;
+    while pos < html.len() {
+        let lt = html[pos..].find('<').map_or(html.len(), |i| pos + i);
+        if lt > pos && skipped.is_none() {
+            let text = decode_entities(&html[pos..lt]);
+            page.push(&text);
+            if main_depth > 0 {
+                main.push(&text);
+            }
+        }
+        if lt == html.len() {
+            break;
+        }
+        if lower[lt..].starts_with("<!--") {
+            pos = lower[lt..].find("-->").map_or(html.len(), |i| lt + i + 3);
+            continue;
+        }
+        let Some(gt) = find_tag_end(html, lt) else {
+            break;
+        };
+        pos = gt + 1;
+        let Some(tag) = Tag::parse(&html[lt + 1..gt]) else {
+            continue;
+        };
+
+        if let Some((name, depth)) = &mut skipped {
+            if tag.name == *name {
+                if tag.closing {
+                    *depth -= 1;
+                    if *depth == 0 {
+                        skipped = None;
+                    }
+                } else if !tag.self_closing {
+                    *depth += 1;
+                }
+            }
+            continue;
+        }
+
+        if tag.closing {
+            if tag.name == "article" || tag.name == "main" {
+                main_depth = main_depth.saturating_sub(1);
+            }
+            if BLOCK_ELEMENTS.contains(&tag.name.as_str()) {
+                page.break_paragraph();
+                main.break_paragraph();
+            }
+            continue;
+        }
+
+        let name = tag.name.as_str();
+        if RAW_TEXT_ELEMENTS.contains(&name) {
+            let end = lower[pos..]
+                .find(&format!("</{}", name))
+                .map_or(html.len(), |i| pos + i);
+            if name == "title" && extracted.title.is_none() {
+                extracted.title = non_empty(&decode_entities(&html[pos..end]));
+            }
+            pos = html[end..].find('>').map_or(html.len(), |i| end + i + 1);
+        } else if name == "meta" {
+            read_meta(&tag, &mut extracted, &mut meta_title);
+        } else if name == "link" {
+            if tag
+                .attr("rel")
+                .is_some_and(|rel| rel.eq_ignore_ascii_case("canonical"))
+            {
+                extracted.url = tag.attr("href").and_then(non_empty);
+            }
+        } else if SKIPPED_ELEMENTS.contains(&name) {
+            if !tag.self_closing {
+                skipped = Some((tag.name.clone(), 1));
+            }
+        } else if BLOCK_ELEMENTS.contains(&name) {
+            if name == "article" || name == "main" {
+                main_depth += 1;
+            }
+            page.break_paragraph();
+            main.break_paragraph();
+        }
+    }
+
+    // og:title and friends are usually cleaner than a <title> with the site name appended
+    if meta_title.is_some() {
+        extracted.title = meta_title;
+    }
+    let main = main.finish();
+    extracted.text = if main.is_empty() { page.finish() } else { main };
+    extracted
+}
+
+fn read_meta(tag: &Tag, extracted: &mut Extracted, meta_title: &mut Option<String>) {
+    let Some(key) = tag
+        .attr("name")
+        .or_else(|| tag.attr("property"))
+        .or_else(|| tag.attr("itemprop"))
+    else {
+        return;
+    };
+    let Some(content) = tag.attr("content").and_then(non_empty) else {
+        return;
+    };
+    let key = key.to_ascii_lowercase();
+    if key == "robots" {
+        extracted.noindex |= super::forbids_indexing(&content);
+        return;
+    }
+    let field = match key.as_str() {
+        "og:title" | "twitter:title" => meta_title,
+        "author" | "article:author" | "dc.creator" => &mut extracted.author,
+        "date"
+        | "pubdate"
+        | "publish-date"
+        | "datepublished"
+        | "dc.date"
+        | "article:published_time" => &mut extracted.date,
+        "og:site_name" | "application-name" => &mut extracted.publication,
+        "og:url" => &mut extracted.url,
+        _ => return,
+    };
+    if field.is_none() {
+        *field = Some(content);
+    }
+}
+
+/// Finds the `>` closing the tag opened at `lt`, skipping over quoted attribute values.
+fn find_tag_end(html: &str, lt: usize) -> Option<usize> {
+    let mut quote = None;
+    for (i, b) in html.bytes().enumerate().skip(lt + 1) {
+        match (quote, b) {
+            (None, b'"') | (None, b'\'') => quote = Some(b),
+            (Some(q), _) if q == b => quote = None,
+            (None, b'>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+struct Tag {
+    name: String,
+    closing: bool,
+    self_closing: bool,
+    attrs: Vec<(String, String)>,
+}
+
+impl Tag {
+    /// Parses the inside of `<...>`. Returns `None` for doctypes, processing
+    /// instructions and anything else that is not an element.
+    fn parse(inner: &str) -> Option<Self> {
+        let inner = inner.trim();
+        let (closing, inner) = match inner.strip_prefix('/') {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, inner),
+        };
+        let self_closing = inner.ends_with('/');
+        let inner = inner.strip_suffix('/').unwrap_or(inner);
+        let name_end = inner
+            .find(|c: char| c.is_whitespace())
+            .unwrap_or(inner.len());
+        let name = inner[..name_end].to_ascii_lowercase();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return None;
+        }
+        Some(Tag {
+            name,
+            closing,
+            self_closing,
+            attrs: parse_attributes(&inner[name_end..]),
+        })
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn parse_attributes(input: &str) -> Vec<(String, String)> {
+    let mut attrs = vec![];
+    let mut rest = input.trim_start();
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        let mut value = String::new();
+        if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            match after_eq.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let body = &after_eq[1..];
+                    let end = body.find(quote).unwrap_or(body.len());
+                    value = decode_entities(&body[..end]);
+                    rest = body.get(end + 1..).unwrap_or_default();
+                }
+                _ => {
+                    let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    value = decode_entities(&after_eq[..end]);
+                    rest = &after_eq[end..];
+                }
+            }
+        }
+        if !name.is_empty() {
+            attrs.push((name, value));
+        }
+        rest = rest.trim_start();
+    }
+    attrs
+}
+
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let entity = rest
+            .find(';')
+            .filter(|&semi| semi <= 10)
+            .and_then(|semi| decode_entity(&rest[1..semi]).map(|c| (c, semi)));
+        match entity {
+            Some((c, semi)) => {
+                decoded.push(c);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    let c = match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        _ => {
+            let code = if let Some(hex) = entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+            {
+                u32::from_str_radix(hex, 16).ok()?
+            } else {
+                entity.strip_prefix('#')?.parse().ok()?
+            };
+            return char::from_u32(code);
+        }
+    };
+    Some(c)
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+/// Accumulates text with collapsed whitespace, split into paragraphs.
+#[derive(Default)]
+struct Paragraphs {
+    done: Vec<String>,
+    current: String,
+}
+
+impl Paragraphs {
+    fn push(&mut self, text: &str) {
+        if text.starts_with(char::is_whitespace) {
+            self.space();
+        }
+        let mut words = text.split_whitespace();
+        if let Some(first) = words.next() {
+            self.current.push_str(first);
+            for word in words {
+                self.current.push(' ');
+                self.current.push_str(word);
+            }
+            if text.ends_with(char::is_whitespace) {
+                self.space();
+            }
+        }
+    }
+
+    fn space(&mut self) {
+        if !self.current.is_empty() && !self.current.ends_with(' ') {
+            self.current.push(' ');
+        }
+    }
+
+    fn break_paragraph(&mut self) {
+        let paragraph = self.current.trim();
+        if !paragraph.is_empty() {
+            self.done.push(paragraph.to_string());
+        }
+        self.current.clear();
+    }
+
+    fn finish(mut self) -> String {
+        self.break_paragraph();
+        self.done.join("\n\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_entities() {
+        let extracted =
+            extract("<p>Fish &amp; chips &lt;3 &#8212; &#x41;&nbsp;la carte &bogus; &</p>");
+        assert_eq!(extracted.text, "Fish & chips <3 — A la carte &bogus; &");
+        assert_eq!(decode_entities("&ldquo;hi&rdquo;&#xZZ;"), "“hi”&#xZZ;");
+    }
+
+    #[test]
+    fn strips_scripts_and_styles() {
+        let extracted = extract(
+            "<html><head><title>Page</title>\
+             <style>p::before { content: \"<b>\" }</style></head>\
+             <body><script>if (a < b) { document.write(\"<p>hi</p>\") }</script>\
+             <p>Visible</p><SCRIPT>x()</SCRIPT></body></html>",
+        );
+        assert_eq!(extracted.text, "Visible");
+        assert_eq!(extracted.title.as_deref(), Some("Page"));
+    }
+
+    #[test]
+    fn tolerates_unclosed_tags() {
+        assert_eq!(
+            extract("<p>First<p>Second <b>bold<div>Third").text,
+            "First\n\nSecond bold\n\nThird"
+        );
+        // a tag cut off at the end of the page ends the text
+        assert_eq!(extract("<p>Text <a href=\"x").text, "Text");
+        assert_eq!(extract("<p>a<!-- <p>hidden</p>").text, "a");
+    }
+
+    #[test]
+    fn skips_nested_page_furniture() {
+        let extracted =
+            extract("<nav><nav>menu</nav>still menu<br/></nav><p>Body <!-- hidden --> text</p>");
+        assert_eq!(extracted.text, "Body text");
+    }
+
+    #[test]
+    fn prefers_the_article_and_reads_metadata() {
+        let extracted = extract(
+            "<head><title>Title | Site</title>\
+             <meta property=\"og:title\" content=\"Clean Title\">\
+             <meta name=author content='Ada Lovelace'>\
+             <link rel=\"Canonical\" href=\"https://example.org/a\">\
+             <meta name=\"robots\" content=\"noindex, nofollow\"></head>\
+             <body><header>Site</header>\
+             <article><h1>Heading</h1><p>Body text</p></article>\
+             <footer>(c)</footer><p>unrelated</p></body>",
+        );
+        assert_eq!(extracted.text, "Heading\n\nBody text");
+        assert_eq!(extracted.title.as_deref(), Some("Clean Title"));
+        assert_eq!(extracted.author.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(extracted.url.as_deref(), Some("https://example.org/a"));
+        assert!(extracted.noindex);
+    }
+
+    #[test]
+    fn keeps_the_whole_page_without_an_article() {
+        let extracted = extract("<div>One</div>\n<div>Two  <i>and</i>\nthree</div>");
+        assert_eq!(extracted.text, "One\n\nTwo and three");
+        assert_eq!(extracted.title, None);
+    }
+}
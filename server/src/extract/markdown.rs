@@ -0,0 +1,275 @@
+use super::html::decode_entities;
+use super::Extracted;
+
+/// Extracts the text of a Markdown note with its formatting stripped. Metadata
+/// comes from YAML-style front matter, and the first `#` heading is used as the
+/// title if the front matter has none.
+pub fn extract(markdown: &str) -> Extracted {
+    let mut extracted = Extracted::default();
+    let body = match split_front_matter(markdown) {
+        Some((front_matter, body)) => {
+            read_front_matter(front_matter, &mut extracted);
+            body
+        }
+        None => markdown,
+    };
+
+    let mut paragraphs: Vec<String> = vec![];
+    let mut current: Vec<String> = vec![];
+    let mut flush = |current: &mut Vec<String>| {
+        if !current.is_empty() {
+            paragraphs.push(current.join(" "));
+            current.clear();
+        }
+    };
+    let mut in_fence = false;
+    for line in body.lines() {
+        let line = line.trim();
+        if line.starts_with("```") || line.starts_with("~~~") {
+            in_fence = !in_fence;
+            flush(&mut current);
+            continue;
+        }
+        if in_fence {
+            // keep code verbatim, it is often what a note is about
+            if !line.is_empty() {
+                current.push(line.to_string());
+            }
+            continue;
+        }
+        if line.is_empty() || is_rule(line) {
+            flush(&mut current);
+            continue;
+        }
+        if let Some((level, heading)) = heading(line) {
+            flush(&mut current);
+            let heading = strip_inline(heading);
+            if level == 1 && extracted.title.is_none() {
+                extracted.title = Some(heading.clone());
+            }
+            current.push(heading);
+            flush(&mut current);
+            continue;
+        }
+        let text = strip_inline(strip_block_marker(line));
+        if !text.is_empty() {
+            current.push(text);
+        }
+    }
+    flush(&mut current);
+
+    extracted.text = paragraphs.join("\n\n");
+    extracted
+}
+
+/// Splits off front matter delimited by `---` lines at the very top of the note.
+fn split_front_matter(markdown: &str) -> Option<(&str, &str)> {
+    let rest = markdown
+        .strip_prefix("---\n")
+        .or_else(|| markdown.strip_prefix("---\r\n"))?;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return Some((&rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+fn read_front_matter(front_matter: &str, extracted: &mut Extracted) {
+    for line in front_matter.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim().trim_matches(|c| c == '"' || c == '\'').trim();
+        if value.is_empty() {
+            continue;
+        }
+        let field = match key.trim().to_ascii_lowercase().as_str() {
+            "title" => &mut extracted.title,
+            "author" | "authors" => &mut extracted.author,
+            "date" | "published" => &mut extracted.date,
+            "publication" | "source" => &mut extracted.publication,
+            "url" | "canonical_url" | "link" => &mut extracted.url,
+            _ => continue,
+        };
+        *field = Some(value.to_string());
+    }
+}
+
+fn is_rule(line: &str) -> bool {
+    let marks: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+    marks.len() >= 3 && ['-', '*', '_'].contains(&marks[0]) && marks.iter().all(|&c| c == marks[0])
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    Some((level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+/// Strips blockquote markers and list bullets or numbers from the start of a line.
+fn strip_block_marker(line: &str) -> &str {
+    let mut line = line;
+    while let Some(rest) = line.strip_prefix('>') {
+        line = rest.trim_start();
+    }
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(rest) = line.strip_prefix(bullet) {
+            return rest.trim_start();
+        }
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        if let Some(rest) = line[digits..]
+            .strip_prefix(". ")
+            .or_else(|| line[digits..].strip_prefix(") "))
+        {
+            return rest.trim_start();
+        }
+    }
+    line
+}
+
+/// Removes inline formatting: emphasis, code spans, links, images and HTML tags.
+fn strip_inline(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            // images keep their alt text and links their label
+            '!' if chars.get(i + 1) == Some(&'[') => {}
+            '[' => {
+                if let Some((label, next)) = link(&chars, i) {
+                    out.push_str(&strip_inline(&label));
+                    i = next;
+                    continue;
+                }
+                out.push(c);
+            }
+            '*' | '`' => {}
+            '~' if chars.get(i + 1) == Some(&'~') => i += 1,
+            // underscores inside words are part of the word, as in snake_case
+            '_' if !(i > 0
+                && chars[i - 1].is_alphanumeric()
+                && chars.get(i + 1).is_some_and(|n| n.is_alphanumeric())) => {}
+            '<' => match chars[i + 1..].iter().position(|&c| c == '>') {
+                Some(len) => {
+                    let inner: String = chars[i + 1..i + 1 + len].iter().collect();
+                    // autolinks like <https://uqbar.org> keep their url
+                    if inner.contains("://") {
+                        out.push_str(&inner);
+                    } else if !inner.starts_with(|c: char| c.is_ascii_alphabetic() || c == '/') {
+                        out.push('<');
+                        i += 1;
+                        continue;
+                    }
+                    i += len + 2;
+                    continue;
+                }
+                None => out.push(c),
+            },
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+    decode_entities(out.trim())
+}
+
+/// Parses `[label](url)` or `[label][ref]` starting at `start`, returning the
+/// label and the index just past the link.
+fn link(chars: &[char], start: usize) -> Option<(String, usize)> {
+    let mut depth = 0;
+    let mut close = None;
+    for (i, &c) in chars.iter().enumerate().skip(start) {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let close = close?;
+    let label: String = chars[start + 1..close].iter().collect();
+    let end = match chars.get(close + 1) {
+        Some('(') => ')',
+        Some('[') => ']',
+        _ => return None,
+    };
+    let target_len = chars[close + 2..].iter().position(|&c| c == end)?;
+    Some((label, close + 2 + target_len + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_front_matter() {
+        let extracted = extract(
+            "---\ntitle: \"Notes on Rust\"\nauthor: Ada\ntags: [rust]\n---\n# Heading\n\nBody",
+        );
+        assert_eq!(extracted.title.as_deref(), Some("Notes on Rust"));
+        assert_eq!(extracted.author.as_deref(), Some("Ada"));
+        assert_eq!(extracted.text, "Heading\n\nBody");
+    }
+
+    #[test]
+    fn takes_the_title_from_the_first_heading() {
+        let extracted = extract("## Not this\n\n# Title #\n\n# Nor this");
+        assert_eq!(extracted.title.as_deref(), Some("Title"));
+        assert_eq!(extracted.text, "Not this\n\nTitle\n\nNor this");
+    }
+
+    #[test]
+    fn strips_inline_formatting() {
+        let extracted = extract(
+            "Some **bold**, _emphasis_, `code`, ~~struck~~ and snake_case.\n\
+             A [link](https://example.org) and ![an image](cat.png) and <https://uqbar.org>.\n\
+             Fish &amp; chips, 1 < 2, <span>html</span>.",
+        );
+        assert_eq!(
+            extracted.text,
+            "Some bold, emphasis, code, struck and snake_case. \
+             A link and an image and https://uqbar.org. \
+             Fish & chips, 1 < 2, html."
+        );
+    }
+
+    #[test]
+    fn strips_nested_list_markers() {
+        let extracted = extract("- outer\n  - inner\n    1. deep\n    2) deeper\n\n> > quoted");
+        assert_eq!(extracted.text, "outer inner deep deeper\n\nquoted");
+    }
+
+    #[test]
+    fn keeps_code_fences_verbatim() {
+        let extracted = extract(
+            "Before\n```rust\nfn main() {\n\n    let x = *y_z;\n}\n```\n---\n~~~\n# not a heading\n~~~\nAfter",
+        );
+        assert_eq!(
+            extracted.text,
+            "Before\n\nfn main() { let x = *y_z; }\n\n# not a heading\n\nAfter"
+        );
+        assert_eq!(extracted.title, None);
+    }
+
+    #[test]
+    fn keeps_an_unclosed_fence_to_the_end() {
+        assert_eq!(extract("```\n*a*\n\nb").text, "*a* b");
+    }
+}
@@ -0,0 +1,201 @@
+use process_lib::fs;
+use process_lib::protocol::FeedbackLabel;
+use process_lib::types::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+// Rocchio weights of the original query, relevant documents and non-relevant documents
+const ROCCHIO_ALPHA: f32 = 1.0;
+const ROCCHIO_BETA: f32 = 0.75;
+const ROCCHIO_GAMMA: f32 = 0.15;
+
+/// sessions without feedback for this long are forgotten
+const SESSION_TTL: Duration = Duration::from_secs(60 * 60);
+const SESSION_CAPACITY: usize = 256;
+/// documents of each kind remembered per session, beyond which the oldest go
+const SESSION_DOCUMENTS: usize = 32;
+/// votes a document needs before its popularity approaches -1 or 1
+const POPULARITY_SMOOTHING: f32 = 5.0;
+/// feedback records kept for tuning, beyond which the oldest file of them goes
+const MAX_FEEDBACK_RECORDS: usize = 10_000;
+/// records per file of the feedback log
+const SEGMENT_RECORDS: usize = 1000;
+
+/// One thumbs up or down, kept for tuning ranking offline.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FeedbackRecord {
+    /// seconds since the epoch
+    pub timestamp: u64,
+    pub source: String,
+    pub session: Option<String>,
+    pub query: Vec<f32>,
+    pub id: String,
+    pub label: FeedbackLabel,
+}
+
+/// Feedback records, kept as JSON lines in filesystem files rather than with
+/// the rest of the state, which would otherwise be saved with all of them on
+/// every vote. Only which files hold them is saved.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FeedbackLog {
+    /// oldest first; records are appended to the last one
+    files: VecDeque<u128>,
+    /// records in the last file, read from it when first needed
+    #[serde(skip)]
+    last_records: Option<usize>,
+}
+
+impl FeedbackLog {
+    /// Appends a record, dropping the oldest file once the others hold
+    /// enough records without it. The log is unchanged if the record cannot
+    /// be written.
+    pub fn append(&mut self, our: &Address, record: FeedbackRecord) -> anyhow::Result<()> {
+        let mut lines = serde_json::to_vec(&record)?;
+        lines.push(b'\n');
+
+        let last_records = match (self.files.back(), self.last_records) {
+            (Some(_), Some(last_records)) => last_records,
+            (Some(&file), None) => fs::read(our, file)?
+                .split(|&byte| byte == b'\n')
+                .filter(|line| !line.is_empty())
+                .count(),
+            (None, _) => 0,
+        };
+        match self.files.back() {
+            Some(&file) if last_records < SEGMENT_RECORDS => {
+                fs::append(our, Some(file), lines)?;
+                self.last_records = Some(last_records + 1);
+            }
+            _ => {
+                self.files.push_back(fs::append(our, None, lines)?);
+                self.last_records = Some(1);
+            }
+        }
+
+        // the record is written by now, so a file that cannot be deleted is
+        // only reported, and tried again with the next record
+        while (self.files.len() - 1) * SEGMENT_RECORDS >= MAX_FEEDBACK_RECORDS {
+            let oldest = self.files[0];
+            if let Err(e) = fs::delete(our, oldest) {
+                println!("server: could not delete old feedback records: {}", e);
+                break;
+            }
+            self.files.pop_front();
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Votes {
+    pub up: u32,
+    pub down: u32,
+}
+
+impl Votes {
+    pub fn add(&mut self, label: FeedbackLabel) {
+        match label {
+            FeedbackLabel::Up => self.up += 1,
+            FeedbackLabel::Down => self.down += 1,
+        }
+    }
+
+    /// Between -1 and 1, and close to 0 until a document has a few votes.
+    pub fn popularity(&self) -> f32 {
+        (self.up as f32 - self.down as f32)
+            / (self.up as f32 + self.down as f32 + POPULARITY_SMOOTHING)
+    }
+}
+
+/// Vectors of the documents marked relevant or not in each recent session.
+/// Kept in memory only, like the sessions themselves.
+#[derive(Default)]
+pub struct Sessions {
+    sessions: HashMap<String, Session>,
+}
+
+struct Session {
+    updated: Instant,
+    relevant: VecDeque<Vec<f32>>,
+    nonrelevant: VecDeque<Vec<f32>>,
+}
+
+impl Sessions {
+    pub fn add(&mut self, session: &str, label: FeedbackLabel, document: Vec<f32>) {
+        self.sessions
+            .retain(|_, session| session.updated.elapsed() < SESSION_TTL);
+        if !self.sessions.contains_key(session) && self.sessions.len() >= SESSION_CAPACITY {
+            let oldest = self
+                .sessions
+                .iter()
+                .min_by_key(|(_, session)| session.updated)
+                .map(|(id, _)| id.clone());
+            if let Some(oldest) = oldest {
+                self.sessions.remove(&oldest);
+            }
+        }
+        let session = self
+            .sessions
+            .entry(session.to_string())
+            .or_insert_with(|| Session {
+                updated: Instant::now(),
+                relevant: VecDeque::new(),
+                nonrelevant: VecDeque::new(),
+            });
+        session.updated = Instant::now();
+        let documents = match label {
+            FeedbackLabel::Up => &mut session.relevant,
+            FeedbackLabel::Down => &mut session.nonrelevant,
+        };
+        if documents.len() >= SESSION_DOCUMENTS {
+            documents.pop_front();
+        }
+        documents.push_back(document);
+    }
+
+    /// Rocchio's refinement of `query` with the feedback given in `session`:
+    /// `alpha * query + beta * mean(relevant) - gamma * mean(nonrelevant)`.
+    pub fn refine(&self, session: &str, query: &[f32]) -> Option<Vec<f32>> {
+        let session = self
+            .sessions
+            .get(session)
+            .filter(|session| session.updated.elapsed() < SESSION_TTL)?;
+        let mut refined: Vec<f32> = query.iter().map(|x| ROCCHIO_ALPHA * x).collect();
+        for (documents, weight) in [
+            (&session.relevant, ROCCHIO_BETA),
+            (&session.nonrelevant, -ROCCHIO_GAMMA),
+        ] {
+            for document in documents {
+                for (r, x) in refined.iter_mut().zip(document) {
+                    *r += weight * x / documents.len() as f32;
+                }
+            }
+        }
+        Some(refined)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refines_with_the_latest_documents_of_a_session() {
+        let mut sessions = Sessions::default();
+        sessions.add("s", FeedbackLabel::Up, vec![0.0, 4.0]);
+        for _ in 0..SESSION_DOCUMENTS {
+            sessions.add("s", FeedbackLabel::Up, vec![4.0, 0.0]);
+        }
+        sessions.add("s", FeedbackLabel::Down, vec![0.0, 2.0]);
+
+        let session = &sessions.sessions["s"];
+        assert_eq!(session.relevant.len(), SESSION_DOCUMENTS);
+        let refined = sessions.refine("s", &[1.0, 1.0]).unwrap();
+        assert_eq!(
+            refined,
+            [1.0 + ROCCHIO_BETA * 4.0, 1.0 - ROCCHIO_GAMMA * 2.0]
+        );
+        assert_eq!(sessions.refine("other", &[1.0, 1.0]), None);
+    }
+}
@@ -0,0 +1,80 @@
+use serde_json::{Map, Value};
+
+/// Whether `metadata` satisfies `filter`, e.g.
+/// `{"publication": {"$in": ["NYT", "WSJ"]}, "year": {"$gte": 2020}}`.
+/// A field holding a list matches if any of its elements does.
+pub fn matches(filter: &Value, metadata: &Map<String, Value>) -> anyhow::Result<bool> {
+    let Some(filter) = filter.as_object() else {
+        return Err(anyhow::anyhow!("a filter must be an object"));
+    };
+    for (key, condition) in filter {
+        let satisfied = match key.as_str() {
+            "$and" => all_of(condition, metadata, true)?,
+            "$or" => all_of(condition, metadata, false)?,
+            field => field_matches(condition, metadata.get(field))?,
+        };
+        if !satisfied {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// `$and` if `conjunction`, `$or` otherwise.
+fn all_of(
+    filters: &Value,
+    metadata: &Map<String, Value>,
+    conjunction: bool,
+) -> anyhow::Result<bool> {
+    let Some(filters) = filters.as_array() else {
+        return Err(anyhow::anyhow!("$and and $or take a list of filters"));
+    };
+    for filter in filters {
+        if matches(filter, metadata)? != conjunction {
+            return Ok(!conjunction);
+        }
+    }
+    Ok(conjunction)
+}
+
+fn field_matches(condition: &Value, field: Option<&Value>) -> anyhow::Result<bool> {
+    let values: Vec<&Value> = match field {
+        Some(Value::Array(values)) => values.iter().collect(),
+        Some(value) => vec![value],
+        None => vec![],
+    };
+    // a bare value is shorthand for $eq
+    let Some(operators) = condition.as_object() else {
+        return Ok(values.contains(&condition));
+    };
+    for (operator, operand) in operators {
+        let any = |f: &dyn Fn(&Value) -> bool| values.iter().any(|value| f(value));
+        let satisfied = match operator.as_str() {
+            "$eq" => any(&|value| value == operand),
+            "$ne" => !any(&|value| value == operand),
+            "$in" => any(&|value| list(operand).contains(&value)),
+            "$nin" => !any(&|value| list(operand).contains(&value)),
+            "$gt" => any(&|value| compare(value, operand).is_some_and(|o| o.is_gt())),
+            "$gte" => any(&|value| compare(value, operand).is_some_and(|o| o.is_ge())),
+            "$lt" => any(&|value| compare(value, operand).is_some_and(|o| o.is_lt())),
+            "$lte" => any(&|value| compare(value, operand).is_some_and(|o| o.is_le())),
+            _ => return Err(anyhow::anyhow!("unknown filter operator {}", operator)),
+        };
+        if !satisfied {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn list(operand: &Value) -> Vec<&Value> {
+    operand
+        .as_array()
+        .map(|values| values.iter().collect())
+        .unwrap_or_default()
+}
+
+/// Orders numbers; pinecone only supports range operators on numbers.
+fn compare(value: &Value, operand: &Value) -> Option<std::cmp::Ordering> {
+    value.as_f64()?.partial_cmp(&operand.as_f64()?)
+}
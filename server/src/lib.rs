@@ -1,76 +1,1481 @@
+#[cfg(target_arch = "wasm32")]
 cargo_component_bindings::generate!();
 
-use bindings::component::uq_process::types::*;
-use bindings::{
-    get_payload, print_to_terminal, receive, send_and_await_response, send_request, send_requests,
-    send_response, Guest,
-};
+#[cfg(target_arch = "wasm32")]
+use bindings::Guest;
+use process_lib::handler::{self, Effect, Handler};
+use process_lib::kernel::print_to_terminal;
+use process_lib::types::*;
+use process_lib::{http, state, urls};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+mod chunking;
+mod dedup;
+mod embed;
+mod extract;
+mod feedback;
+mod filter;
+mod metrics;
+#[cfg(test)]
+mod mock_pinecone;
+mod paging;
+mod pinecone;
+mod querylog;
+mod ranking;
+mod terminal;
+#[cfg(test)]
+mod tests;
+
+use dedup::FingerprintIndex;
+use feedback::{FeedbackLog, FeedbackRecord, Sessions, Votes};
+use metrics::Latencies;
+use paging::{Cursor, ResultCache};
+use process_lib::protocol::{
+    AnalyticsRequest, ClickRequest, CollectionStats, DeleteRequest, Document, DuplicatePolicy,
+    FeedbackRequest, FetchRequest, FetchResponse, FetchedDocument, IngestRequest, Match, Passage,
+    PayloadIngestRequest, QueryByIdRequest, QueryLogConfig, QueryRequest, QueryResponse,
+    ServerRequest, ServerResponse, Source, StatsResponse, UpdateMetadataRequest, UrlIngestRequest,
+};
+use querylog::{LogEntry, QueryLog};
+use terminal::Command;
+
+// metadata keys set on every chunk vector, stripped again when chunks are
+// collapsed back into documents
+const PARENT_ID_KEY: &str = "parent_id";
+const CHUNK_INDEX_KEY: &str = "chunk_index";
+const CHUNK_START_KEY: &str = "chunk_start";
+const CHUNK_END_KEY: &str = "chunk_end";
+const CHUNK_TEXT_KEY: &str = "chunk_text";
+
+/// several chunks of one document can crowd the top of the result list, so
+/// ask pinecone for this many times more hits than the caller wants
+const CHUNK_OVERFETCH: usize = 4;
+/// default MMR candidate pool, relative to the number of results asked for
+const MMR_CANDIDATES_PER_RESULT: usize = 5;
+/// default re-ranking candidate pool, relative to the number of results asked for
+const RERANK_CANDIDATES_PER_RESULT: usize = 3;
+/// length of the article preview sent with query results, in characters
+const PREVIEW_CHARS: usize = 200;
+const FETCH_TIMEOUT: u64 = 30;
+
+/// pinecone's topK limit for queries that include metadata
+const MAX_TOP_K: usize = 1000;
+
+/// deleted documents are removed from the index once this many are waiting...
+const COMPACTION_THRESHOLD: usize = 64;
+/// ...or once the oldest has waited this long, in seconds
+const COMPACTION_INTERVAL: u64 = 600;
+/// after a failed compaction, the server waits this long before trying again
+const COMPACTION_RETRY: Duration = Duration::from_secs(60);
 
-#[allow(dead_code)]
-mod process_lib;
+/// what the server sends itself to compact the index, see [`compact`]
+const COMPACT_REQUEST: &str = "\"Compact\"";
 
-const PINECONE_API_KEY: &str = include_str!("pinecone-api-key.txt");
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct State {
+    documents: HashMap<String, StoredDocument>,
+    /// deleted documents whose vectors are still in the index, by document id
+    tombstones: HashMap<String, Tombstone>,
+    /// bumped whenever documents are added, removed or updated
+    generation: u64,
+    /// when each namespace was last ingested into, in seconds since the epoch
+    last_ingest: HashMap<String, u64>,
+    query_log: QueryLog,
+    feedback: FeedbackLog,
+    /// thumbs up and down given to each document
+    votes: HashMap<String, Votes>,
+    /// where the HTTP re-ranker is served, if anywhere
+    reranker_uri: Option<String>,
+    /// nodes besides our own that may change the library
+    admins: Vec<String>,
+    /// the fingerprints of `documents`, rebuilt when the state is loaded
+    #[serde(skip)]
+    fingerprints: FingerprintIndex,
+    /// changed since it was last saved
+    #[serde(skip)]
+    unsaved: bool,
+}
+
+/// Version 0 is the same layout, saved before states were versioned.
+impl state::Versioned for State {
+    const VERSION: u32 = 1;
+    const MIGRATIONS: &'static [state::Migration] = &[state::unchanged];
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredDocument {
+    namespace: String,
+    text: String,
+    #[serde(with = "process_lib::as_json")]
+    metadata: serde_json::Map<String, serde_json::Value>,
+    chunk_ids: Vec<String>,
+    /// `None` for texts without any words, which are never near-duplicates
+    fingerprint: Option<u64>,
+    /// the first document of the near-duplicate cluster this one belongs to
+    duplicate_of: Option<String>,
+}
+
+/// Deleted documents are hidden from queries right away, but their vectors are
+/// only removed from the index by the next compaction.
+#[derive(Debug, Serialize, Deserialize)]
+struct Tombstone {
+    namespace: String,
+    vector_ids: Vec<String>,
+    /// seconds since the epoch
+    deleted_at: u64,
+}
+
+impl State {
+    /// Has the state saved once the message being handled is done with.
+    fn mark_changed(&mut self) {
+        self.unsaved = true;
+    }
+
+    /// The effect saving the state, if it changed.
+    fn take_changes(&mut self) -> Option<Effect> {
+        std::mem::take(&mut self.unsaved).then(|| Effect::set_state(self))
+    }
+
+    /// The id of the near-duplicate cluster a document belongs to, which is the
+    /// document's own id unless it was clustered at ingestion.
+    fn cluster_of<'a>(&'a self, id: &'a str) -> &'a str {
+        self.documents
+            .get(id)
+            .and_then(|document| document.duplicate_of.as_deref())
+            .unwrap_or(id)
+    }
+
+    /// Indexes the fingerprints of the documents of a state just loaded.
+    fn index_fingerprints(mut self) -> Self {
+        for (id, document) in &self.documents {
+            if let Some(fingerprint) = document.fingerprint {
+                self.fingerprints.insert(id, fingerprint);
+            }
+        }
+        self
+    }
+
+    /// Adds a document, or replaces the one with its id.
+    fn insert_document(&mut self, id: String, document: StoredDocument) {
+        if let Some(fingerprint) = self
+            .documents
+            .get(&id)
+            .and_then(|previous| previous.fingerprint)
+        {
+            self.fingerprints.remove(&id, fingerprint);
+        }
+        if let Some(fingerprint) = document.fingerprint {
+            self.fingerprints.insert(&id, fingerprint);
+        }
+        self.documents.insert(id, document);
+    }
 
+    /// Removes a document, handing its near-duplicate cluster over to the next
+    /// member if it was the cluster's first document.
+    fn remove_document(&mut self, id: &str) -> Option<StoredDocument> {
+        let document = self.documents.remove(id)?;
+        if let Some(fingerprint) = document.fingerprint {
+            self.fingerprints.remove(id, fingerprint);
+        }
+        if document.duplicate_of.is_none() {
+            let head = self
+                .documents
+                .iter()
+                .filter(|(_, member)| member.duplicate_of.as_deref() == Some(id))
+                .map(|(member_id, _)| member_id.clone())
+                .min();
+            for (member_id, member) in self.documents.iter_mut() {
+                if member.duplicate_of.as_deref() == Some(id) {
+                    member.duplicate_of = if Some(member_id) == head.as_ref() {
+                        None
+                    } else {
+                        head.clone()
+                    };
+                }
+            }
+        }
+        Some(document)
+    }
+
+    fn compaction_due(&self) -> bool {
+        self.tombstones.len() >= COMPACTION_THRESHOLD
+            || self
+                .tombstones
+                .values()
+                .any(|tombstone| now() >= tombstone.deleted_at + COMPACTION_INTERVAL)
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// What the server keeps in memory only, and loses when it restarts.
+#[derive(Default)]
+struct Memory {
+    results: ResultCache,
+    latencies: Latencies,
+    sessions: Sessions,
+    /// a compaction request to ourselves is on its way
+    compaction_requested: bool,
+    /// when the last compaction failed, if the one after it has not succeeded
+    compaction_failed_at: Option<Instant>,
+}
+
+impl Memory {
+    /// Whether to ask ourselves to compact: not while a request is on its way,
+    /// nor until [`COMPACTION_RETRY`] has passed since a compaction failed.
+    fn should_request_compaction(&self, state: &State) -> bool {
+        state.compaction_due()
+            && !self.compaction_requested
+            && self
+                .compaction_failed_at
+                .map_or(true, |failed_at| failed_at.elapsed() >= COMPACTION_RETRY)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
 struct Component;
 
-impl Guest for Component {
-    fn init(our: Address) {
-        print_to_terminal(0, "librarian: start");
+fn handle_query(
+    our: &Address,
+    state: &State,
+    memory: &mut Memory,
+    query: QueryRequest,
+) -> anyhow::Result<serde_json::Value> {
+    let key = paging::query_key(&query);
+    query_page(our, state, memory, key, query)
+}
+
+/// Answers a page of `query`, whose cursor, if it has one, must be for the
+/// query with `key`.
+fn query_page(
+    our: &Address,
+    state: &State,
+    memory: &mut Memory,
+    key: u64,
+    query: QueryRequest,
+) -> anyhow::Result<serde_json::Value> {
+    let cursor = query.cursor.as_deref().map(Cursor::decode).transpose()?;
+    if cursor.as_ref().is_some_and(|cursor| cursor.query != key) {
+        return Err(anyhow::anyhow!(
+            "cursor is for another query; send it with the query it came from"
+        ));
+    }
+    let query = match (query.vector.is_empty(), &query.text) {
+        (false, _) => query,
+        (true, Some(text)) => QueryRequest {
+            vector: embed::embed(our, std::slice::from_ref(text))?.remove(0),
+            ..query
+        },
+        (true, None) => return Err(anyhow::anyhow!("query needs a vector or a text")),
+    };
+    let query = match &query.session {
+        Some(session) => match memory.sessions.refine(session, &query.vector) {
+            Some(vector) => QueryRequest { vector, ..query },
+            None => query,
+        },
+        None => query,
+    };
+
+    // rank one more result than the page needs, to know if there is a next page
+    let depth = cursor.as_ref().map_or(query.offset, |cursor| cursor.offset) + query.top_k + 1;
+    let (ranked, exhausted) = memory.results.get_or_rank(key, depth, || {
+        rank(
+            our,
+            state,
+            &QueryRequest {
+                top_k: depth,
+                ..query.clone()
+            },
+        )
+    })?;
+
+    let start = cursor
+        .as_ref()
+        .map_or(query.offset, |cursor| {
+            cursor.position(ranked, query.mmr.is_some())
+        })
+        .min(ranked.len());
+    let end = (start + query.top_k).min(ranked.len());
+    let next_cursor = (end > start && (end < ranked.len() || !exhausted)).then(|| {
+        let last = &ranked[end - 1];
+        Cursor {
+            query: key,
+            offset: end,
+            last_id: last.id.clone(),
+            last_score: last.rerank_score.unwrap_or(last.score),
+        }
+        .encode()
+    });
+
+    let mut matches = ranked[start..end].to_vec();
+    for m in matches.iter_mut() {
+        if !query.include_values {
+            m.values = None;
+        }
+        if !query.include_metadata {
+            m.metadata = None;
+        }
+        let (Some(metadata), false) = (&mut m.metadata, query.include_content) else {
+            continue;
+        };
+        // full articles are loaded on demand with Fetch, so only send a preview
+        let article = metadata.remove("article");
+        let preview = match (&m.passage, &article) {
+            (Some(passage), _) => passage.text.clone(),
+            (None, Some(serde_json::Value::String(article))) => {
+                let end = article
+                    .char_indices()
+                    .nth(PREVIEW_CHARS)
+                    .map_or(article.len(), |(i, _)| i);
+                article[..end].to_string()
+            }
+            _ => continue,
+        };
+        metadata.insert("preview".to_string(), preview.into());
+    }
+    Ok(serde_json::to_value(QueryResponse {
+        namespace: query.namespace,
+        matches,
+        next_cursor,
+        query_id: None,
+        generation: state.generation,
+    })?)
+}
 
-        loop {
-            let Ok((source, message)) = receive() else {
-                print_to_terminal(0, "librarian: got network error");
+/// Runs the query pipeline, returning the best `query.top_k` documents in order.
+fn rank(our: &Address, state: &State, query: &QueryRequest) -> anyhow::Result<Vec<Match>> {
+    if query.rerank.is_some() && query.text.is_none() {
+        return Err(anyhow::anyhow!("re-ranking needs the query text"));
+    }
+    // each optional stage narrows down a larger pool of candidates from the one before
+    let rerank_pool = query.rerank.as_ref().map(|rerank| {
+        rerank
+            .candidates
+            .unwrap_or(query.top_k * RERANK_CANDIDATES_PER_RESULT)
+            .max(query.top_k)
+    });
+    let first_stage_size = rerank_pool.unwrap_or(query.top_k);
+    let pool_size = match &query.mmr {
+        Some(mmr) => mmr
+            .candidates
+            .unwrap_or(first_stage_size * MMR_CANDIDATES_PER_RESULT)
+            .max(first_stage_size),
+        None => first_stage_size,
+    };
+    let mut pinecone_query = json!({
+        "namespace": query.namespace,
+        "topK": (pool_size * CHUNK_OVERFETCH).min(MAX_TOP_K),
+        "vector": query.vector,
+        // vectors are compared to find near-duplicates and to diversify results
+        "includeValues": query.include_values || query.dedupe.is_some() || query.mmr.is_some(),
+        // chunk metadata is needed to collapse hits, even if the caller did not ask for it
+        "includeMetadata": true,
+    });
+    if let Some(filter) = &query.filter {
+        pinecone_query["filter"] = filter.clone();
+    }
+    let res = pinecone::query(our, &pinecone_query)?;
+    let matches: Vec<Match> = serde_json::from_value(res["matches"].clone())?;
+
+    let mut matches = collapse_chunks(matches);
+    matches.retain(|m| !query.exclude.contains(&m.id) && !state.tombstones.contains_key(&m.id));
+    if let Some(weight) = query.popularity {
+        for m in matches.iter_mut() {
+            if let Some(votes) = state.votes.get(&m.id) {
+                m.score += weight * votes.popularity();
+            }
+        }
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    }
+    if let Some(threshold) = query.dedupe {
+        matches = collapse_duplicates(state, matches, threshold);
+    }
+    if let Some(mmr) = &query.mmr {
+        matches.truncate(pool_size);
+        matches = ranking::mmr(&query.vector, matches, mmr.lambda, first_stage_size);
+    }
+    matches.truncate(first_stage_size);
+    if let (Some(rerank), Some(text)) = (&query.rerank, &query.text) {
+        let reranker = ranking::reranker(our, rerank.reranker, state.reranker_uri.as_deref())?;
+        matches = ranking::rerank(reranker.as_ref(), text, matches)?;
+    }
+    matches.truncate(query.top_k);
+    Ok(matches)
+}
+
+/// Turns chunk hits into document hits, keeping the best-scoring chunk of each
+/// document as its passage. Vectors that were not ingested in chunks pass through.
+fn collapse_chunks(matches: Vec<Match>) -> Vec<Match> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut documents = vec![];
+    // pinecone returns matches by descending score, so the first chunk seen
+    // for a document is its best one
+    for mut m in matches {
+        let parent_id = m
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(PARENT_ID_KEY))
+            .and_then(|id| id.as_str())
+            .map(|id| id.to_string());
+        let Some(parent_id) = parent_id else {
+            if seen.insert(m.id.clone()) {
+                documents.push(m);
+            }
+            continue;
+        };
+        if !seen.insert(parent_id.clone()) {
+            continue;
+        }
+
+        let mut metadata = m.metadata.take().unwrap_or_default();
+        let offset = |key: &str| {
+            metadata
+                .get(key)
+                .and_then(|v| v.as_u64())
+                .unwrap_or_default() as usize
+        };
+        let passage = Passage {
+            text: metadata
+                .get(CHUNK_TEXT_KEY)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            chunk_index: offset(CHUNK_INDEX_KEY),
+            start: offset(CHUNK_START_KEY),
+            end: offset(CHUNK_END_KEY),
+        };
+        for key in [
+            PARENT_ID_KEY,
+            CHUNK_INDEX_KEY,
+            CHUNK_START_KEY,
+            CHUNK_END_KEY,
+            CHUNK_TEXT_KEY,
+        ] {
+            metadata.remove(key);
+        }
+
+        documents.push(Match {
+            id: parent_id,
+            score: m.score,
+            values: m.values,
+            metadata: Some(metadata),
+            passage: Some(passage),
+            also_published_in: vec![],
+            rerank_score: None,
+        });
+    }
+    documents
+}
+
+/// Folds each match into the first higher-scoring match it duplicates, listing
+/// it there as another source of the same story.
+fn collapse_duplicates(state: &State, matches: Vec<Match>, threshold: f32) -> Vec<Match> {
+    let mut kept: Vec<Match> = vec![];
+    for m in matches {
+        let original = kept.iter_mut().find(|k| {
+            state.cluster_of(&k.id) == state.cluster_of(&m.id)
+                || match (&k.values, &m.values) {
+                    (Some(a), Some(b)) => dedup::cosine_similarity(a, b) >= threshold,
+                    _ => false,
+                }
+        });
+        match original {
+            Some(original) => {
+                let field = |key: &str| {
+                    m.metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.get(key))
+                        .and_then(|value| value.as_str())
+                        .map(|value| value.to_string())
+                };
+                original.also_published_in.push(Source {
+                    id: m.id.clone(),
+                    score: m.score,
+                    publication: field("publication"),
+                    url: field("url"),
+                });
+            }
+            None => kept.push(m),
+        }
+    }
+    kept
+}
+
+fn handle_query_by_id(
+    our: &Address,
+    state: &State,
+    memory: &mut Memory,
+    query_by_id: QueryByIdRequest,
+) -> anyhow::Result<serde_json::Value> {
+    let mut query = query_by_id.query;
+    query.exclude.push(query_by_id.id.clone());
+    // keyed before the vector is filled in, as the client sent it
+    let key = paging::query_key(&query);
+    query.vector = document_vector(our, state, &query_by_id.id, &query.namespace)?;
+    query_page(our, state, memory, key, query)
+}
+
+/// The vector a document was indexed with. Documents ingested in chunks are
+/// represented by the mean of their chunk vectors.
+fn document_vector(
+    our: &Address,
+    state: &State,
+    id: &str,
+    namespace: &str,
+) -> anyhow::Result<Vec<f32>> {
+    if state.tombstones.contains_key(id) {
+        return Err(anyhow::anyhow!("no document {}", id));
+    }
+    let (namespace, ids) = match state.documents.get(id) {
+        Some(document) => (document.namespace.as_str(), document.chunk_ids.clone()),
+        None => (namespace, vec![id.to_string()]),
+    };
+    let vectors: Vec<Vec<f32>> = pinecone::fetch(our, namespace, &ids)?
+        .values()
+        .filter_map(|vector| serde_json::from_value(vector["values"].clone()).ok())
+        .collect();
+    let Some(first) = vectors.first() else {
+        return Err(anyhow::anyhow!("no document {}", id));
+    };
+    let mut mean = vec![0.0; first.len()];
+    for vector in &vectors {
+        for (m, x) in mean.iter_mut().zip(vector) {
+            *m += x;
+        }
+    }
+    for m in mean.iter_mut() {
+        *m /= vectors.len() as f32;
+    }
+    Ok(mean)
+}
+
+/// Looks documents up by id. Documents ingested through the server are served
+/// from state; older ones only live in pinecone, with their body in `metadata.article`.
+fn handle_fetch(
+    our: &Address,
+    state: &State,
+    fetch: FetchRequest,
+) -> anyhow::Result<serde_json::Value> {
+    let mut documents = vec![];
+    let mut unknown = vec![];
+    let mut missing = vec![];
+    for id in fetch.ids {
+        if state.tombstones.contains_key(&id) {
+            missing.push(id);
+            continue;
+        }
+        match state.documents.get(&id) {
+            Some(document) => documents.push(FetchedDocument {
+                id,
+                namespace: document.namespace.clone(),
+                metadata: document.metadata.clone(),
+                text: document.text.clone(),
+            }),
+            None => unknown.push(id),
+        }
+    }
+
+    if !unknown.is_empty() {
+        let vectors = pinecone::fetch(our, &fetch.namespace, &unknown)?;
+        for id in unknown {
+            let Some(vector) = vectors.get(&id) else {
+                missing.push(id);
                 continue;
             };
-            let Message::Request(_request) = message else {
-                print_to_terminal(0, "librarian: got unexpected Response");
-                continue;
+            let mut metadata = vector["metadata"].as_object().cloned().unwrap_or_default();
+            let text = match metadata.remove("article") {
+                Some(serde_json::Value::String(text)) => text,
+                _ => String::new(),
             };
+            documents.push(FetchedDocument {
+                id,
+                namespace: fetch.namespace.clone(),
+                metadata,
+                text,
+            });
+        }
+    }
 
-            if source.process.to_string() == "librarian:librarian:drew.uq" {
-                print_to_terminal(0, "librarian server: got message from client");
-                let _res = send_and_await_response(
-                    &Address {
-                        node: our.clone().node,
-                        process: ProcessId::from_str("http_client:sys:uqbar").unwrap()
-                    },
-                    &Request {
-                        inherit: false,
-                        metadata: None,
-                        expects_response: Some(10),
-                        ipc: Some(json!({
-                            "method": "POST",
-                            "headers": {
-                                "Api-Key": PINECONE_API_KEY,
-                                "accept": "application/json",
-                                "content-type": "application/json"
-                            },
-                            "uri": "https://article-recommendations-8a4cf60.svc.us-west4-gcp.pinecone.io/query"
-                        }).to_string())
-                    },
-                    Some(&Payload {
-                        mime: Some("application/octet-stream".to_string()),
-                        bytes: get_payload().unwrap().bytes,
-                    })
-                );
-                print_to_terminal(0, "librarian server: sending response");
-                send_response(
-                    &Response {
-                        inherit: false,
-                        ipc: Some(json!({}).to_string()),
-                        metadata: None,
-                    },
-                    Some(&Payload {
-                        mime: Some("application/octet-stream".to_string()),
-                        bytes: get_payload().unwrap().bytes,
-                    })
+    Ok(serde_json::to_value(FetchResponse { documents, missing })?)
+}
+
+fn handle_ingest(
+    our: &Address,
+    state: &mut State,
+    ingest: IngestRequest,
+) -> anyhow::Result<serde_json::Value> {
+    let mut vectors = vec![];
+    // vectors of earlier versions of the documents that were not overwritten, by namespace
+    let mut stale_ids: HashMap<String, Vec<String>> = HashMap::new();
+    let mut ingested: Vec<(String, StoredDocument)> = vec![];
+    let mut duplicates = vec![];
+    for document in ingest.documents {
+        let fingerprint = dedup::simhash(&document.text);
+        let original = fingerprint.and_then(|fingerprint| {
+            let indexed = state
+                .fingerprints
+                .near_duplicates(fingerprint)
+                .into_iter()
+                .filter_map(|id| state.documents.get_key_value(id));
+            indexed
+                .chain(ingested.iter().map(|(id, document)| (id, document)))
+                .find(|(id, existing)| {
+                    **id != document.id
+                        && existing
+                            .fingerprint
+                            .is_some_and(|existing| dedup::is_near_duplicate(existing, fingerprint))
+                })
+                .map(|(id, existing)| existing.duplicate_of.clone().unwrap_or(id.clone()))
+        });
+        let duplicate_of = match (ingest.duplicates, original) {
+            (DuplicatePolicy::Allow, _) | (_, None) => None,
+            (DuplicatePolicy::Reject, Some(original)) => {
+                duplicates
+                    .push(json!({ "id": document.id, "duplicate_of": original, "rejected": true }));
+                continue;
+            }
+            (DuplicatePolicy::Cluster, Some(original)) => {
+                duplicates.push(
+                    json!({ "id": document.id, "duplicate_of": original, "rejected": false }),
                 );
-            } else {
-                print_to_terminal(0, "librarian: got message from unknown source");
+                Some(original)
             }
+        };
+
+        let chunks = chunking::chunk(&document.text, &ingest.chunking);
+        let embeddings = embed::embed(
+            our,
+            &chunks.iter().map(|c| c.text.clone()).collect::<Vec<_>>(),
+        )?;
+
+        let mut chunk_ids = vec![];
+        for (chunk, values) in chunks.into_iter().zip(embeddings) {
+            let id = format!("{}#{}", document.id, chunk.index);
+            let mut metadata = document.metadata.clone();
+            metadata.insert(PARENT_ID_KEY.into(), document.id.clone().into());
+            metadata.insert(CHUNK_INDEX_KEY.into(), chunk.index.into());
+            metadata.insert(CHUNK_START_KEY.into(), chunk.start.into());
+            metadata.insert(CHUNK_END_KEY.into(), chunk.end.into());
+            metadata.insert(CHUNK_TEXT_KEY.into(), chunk.text.into());
+            vectors.push(json!({
+                "id": id,
+                "values": values,
+                "metadata": metadata,
+            }));
+            chunk_ids.push(id);
         }
+
+        // a re-ingested document may now have fewer chunks than before, or
+        // may have been deleted and not compacted yet
+        let previous = state
+            .documents
+            .get(&document.id)
+            .map(|previous| (&previous.namespace, &previous.chunk_ids))
+            .or_else(|| {
+                state
+                    .tombstones
+                    .get(&document.id)
+                    .map(|tombstone| (&tombstone.namespace, &tombstone.vector_ids))
+            });
+        if let Some((namespace, ids)) = previous {
+            stale_ids.entry(namespace.clone()).or_default().extend(
+                ids.iter()
+                    .filter(|id| *namespace != ingest.namespace || !chunk_ids.contains(id))
+                    .cloned(),
+            );
+        }
+        ingested.push((
+            document.id,
+            StoredDocument {
+                namespace: ingest.namespace.clone(),
+                text: document.text,
+                metadata: document.metadata,
+                chunk_ids,
+                fingerprint,
+                duplicate_of,
+            },
+        ));
+    }
+
+    pinecone::upsert(our, &ingest.namespace, &vectors)?;
+    for (namespace, ids) in &stale_ids {
+        pinecone::delete(our, namespace, ids)?;
+    }
+    let documents = ingested.len();
+    if documents > 0 {
+        state.last_ingest.insert(ingest.namespace.clone(), now());
+    }
+    for (id, document) in ingested {
+        state.tombstones.remove(&id);
+        state.insert_document(id, document);
+    }
+    state.generation += 1;
+    state.mark_changed();
+
+    Ok(json!({
+        "documents": documents,
+        "chunks": vectors.len(),
+        "duplicates": duplicates,
+        "generation": state.generation,
+    }))
+}
+
+fn handle_ingest_payload(
+    our: &Address,
+    state: &mut State,
+    ingest: PayloadIngestRequest,
+    payload: Option<Payload>,
+) -> anyhow::Result<serde_json::Value> {
+    let payload = payload.ok_or(anyhow::anyhow!(
+        "IngestPayload expects the document as payload"
+    ))?;
+    let extracted = extract::extract(payload.mime.as_deref(), &payload.bytes)?;
+    let mut metadata = ingest.metadata;
+    let text = extracted.merge_into(&mut metadata);
+    if text.is_empty() {
+        return Err(anyhow::anyhow!("no text found in document"));
+    }
+    let id = ingest
+        .id
+        .or_else(|| {
+            metadata
+                .get("url")
+                .and_then(|url| url.as_str())
+                .map(|url| url.to_string())
+        })
+        .unwrap_or_else(|| {
+            let mut hasher = DefaultHasher::new();
+            text.hash(&mut hasher);
+            format!("{:016x}", hasher.finish())
+        });
+    handle_ingest(
+        our,
+        state,
+        IngestRequest {
+            namespace: ingest.namespace,
+            documents: vec![Document { id, text, metadata }],
+            chunking: ingest.chunking,
+            duplicates: ingest.duplicates,
+        },
+    )
+}
+
+/// Finds an ingested document whose `url` metadata normalizes to `url`.
+fn find_by_url<'a>(state: &'a State, url: &str) -> Option<&'a String> {
+    state.documents.iter().find_map(|(id, document)| {
+        let stored = document.metadata.get("url")?.as_str()?;
+        (urls::normalize(stored).ok()? == url).then_some(id)
+    })
+}
+
+fn handle_ingest_url(
+    our: &Address,
+    state: &mut State,
+    ingest: UrlIngestRequest,
+) -> anyhow::Result<serde_json::Value> {
+    let requested = urls::normalize(&ingest.url)?;
+    if !ingest.refresh {
+        if let Some(id) = find_by_url(state, &requested) {
+            return Ok(json!({ "id": id, "duplicate": true }));
+        }
+    }
+
+    let res = http::Request::get(&ingest.url)
+        .header("accept", "text/html, text/markdown;q=0.9, text/plain;q=0.8")
+        .timeout(FETCH_TIMEOUT)
+        .send(our)?;
+    if !res.is_success() {
+        return Err(anyhow::anyhow!(
+            "fetching {} returned {}",
+            ingest.url,
+            res.status
+        ));
+    }
+    if res
+        .header("x-robots-tag")
+        .is_some_and(extract::forbids_indexing)
+    {
+        return Err(anyhow::anyhow!("{} asks not to be indexed", ingest.url));
+    }
+    let content_type = res
+        .header("content-type")
+        .ok_or(anyhow::anyhow!("{} has no content type", ingest.url))?;
+    let mut extracted = extract::extract(Some(content_type), &res.body)?;
+    if extracted.noindex {
+        return Err(anyhow::anyhow!("{} asks not to be indexed", ingest.url));
+    }
+
+    // the page's own canonical link is the best key for spotting the same
+    // article reached through different urls; relative links are relative to
+    // wherever redirects ended up
+    let canonical = match extracted.url.take() {
+        Some(href) => urls::normalize(&urls::resolve(&res.uri, &href)).unwrap_or(requested),
+        None => requested,
+    };
+    if !ingest.refresh {
+        if let Some(id) = find_by_url(state, &canonical) {
+            return Ok(json!({ "id": id, "duplicate": true }));
+        }
+    }
+
+    let mut metadata = ingest.metadata;
+    metadata.insert("url".to_string(), canonical.clone().into());
+    let text = extracted.merge_into(&mut metadata);
+    if text.is_empty() {
+        return Err(anyhow::anyhow!("no text found at {}", ingest.url));
+    }
+    let mut res = handle_ingest(
+        our,
+        state,
+        IngestRequest {
+            namespace: ingest.namespace,
+            documents: vec![Document {
+                id: canonical.clone(),
+                text,
+                metadata,
+            }],
+            chunking: ingest.chunking,
+            duplicates: ingest.duplicates,
+        },
+    )?;
+    res["id"] = canonical.into();
+    Ok(res)
+}
+
+/// Tombstones documents, which hides them from queries and fetches right away.
+/// Their vectors are removed from the index by [`compact`] later.
+fn handle_delete(state: &mut State, delete: DeleteRequest) -> anyhow::Result<serde_json::Value> {
+    let ids = match (delete.ids.is_empty(), &delete.filter) {
+        (false, None) => delete.ids,
+        (true, Some(filter)) => {
+            let mut ids = vec![];
+            for (id, document) in &state.documents {
+                if document.namespace == delete.namespace
+                    && filter::matches(filter, &document.metadata)?
+                {
+                    ids.push(id.clone());
+                }
+            }
+            ids
+        }
+        _ => return Err(anyhow::anyhow!("Delete takes either ids or a filter")),
+    };
+
+    let mut deleted = vec![];
+    for id in ids {
+        if state.tombstones.contains_key(&id) {
+            continue;
+        }
+        // documents the server did not ingest itself are a single vector
+        let (namespace, vector_ids) = match state.remove_document(&id) {
+            Some(document) => (document.namespace, document.chunk_ids),
+            None => (delete.namespace.clone(), vec![id.clone()]),
+        };
+        state.tombstones.insert(
+            id.clone(),
+            Tombstone {
+                namespace,
+                vector_ids,
+                deleted_at: now(),
+            },
+        );
+        deleted.push(id);
+    }
+    if !deleted.is_empty() {
+        state.generation += 1;
+        state.mark_changed();
+    }
+
+    Ok(json!({
+        "deleted": deleted,
+        "generation": state.generation,
+    }))
+}
+
+/// Removes the vectors of deleted documents from the index.
+fn compact(our: &Address, state: &mut State) -> anyhow::Result<usize> {
+    let mut by_namespace: HashMap<&str, Vec<String>> = HashMap::new();
+    for tombstone in state.tombstones.values() {
+        by_namespace
+            .entry(&tombstone.namespace)
+            .or_default()
+            .extend(tombstone.vector_ids.iter().cloned());
+    }
+    for (namespace, ids) in by_namespace {
+        pinecone::delete(our, namespace, &ids)?;
+    }
+    let compacted = state.tombstones.len();
+    state.tombstones.clear();
+    state.mark_changed();
+    Ok(compacted)
+}
+
+fn handle_update_metadata(
+    our: &Address,
+    state: &mut State,
+    update: UpdateMetadataRequest,
+) -> anyhow::Result<serde_json::Value> {
+    if let Some(key) = update.patch.keys().find(|key| {
+        [
+            PARENT_ID_KEY,
+            CHUNK_INDEX_KEY,
+            CHUNK_START_KEY,
+            CHUNK_END_KEY,
+            CHUNK_TEXT_KEY,
+        ]
+        .contains(&key.as_str())
+    }) {
+        return Err(anyhow::anyhow!("{} is reserved metadata", key));
+    }
+    if state.tombstones.contains_key(&update.id) {
+        return Err(anyhow::anyhow!("no document {}", update.id));
+    }
+    let (namespace, ids) = match state.documents.get(&update.id) {
+        Some(document) => (document.namespace.clone(), document.chunk_ids.clone()),
+        None => (update.namespace, vec![update.id.clone()]),
+    };
+
+    // pinecone can only add metadata in place, so patched vectors are upserted whole
+    let mut vectors: Vec<serde_json::Value> = pinecone::fetch(our, &namespace, &ids)?
+        .into_iter()
+        .map(|(_, vector)| vector)
+        .collect();
+    if vectors.is_empty() {
+        return Err(anyhow::anyhow!("no document {}", update.id));
+    }
+    for vector in vectors.iter_mut() {
+        if !vector["metadata"].is_object() {
+            vector["metadata"] = json!({});
+        }
+        apply_patch(vector["metadata"].as_object_mut().unwrap(), &update.patch);
+    }
+    pinecone::upsert(our, &namespace, &vectors)?;
+
+    let metadata = match state.documents.get_mut(&update.id) {
+        Some(document) => {
+            apply_patch(&mut document.metadata, &update.patch);
+            document.metadata.clone()
+        }
+        None => vectors[0]["metadata"]
+            .as_object()
+            .cloned()
+            .unwrap_or_default(),
+    };
+    state.generation += 1;
+    state.mark_changed();
+
+    Ok(json!({
+        "id": update.id,
+        "metadata": metadata,
+        "generation": state.generation,
+    }))
+}
+
+/// Sets the keys of `patch` in `metadata`, removing those set to `null`.
+fn apply_patch(
+    metadata: &mut serde_json::Map<String, serde_json::Value>,
+    patch: &serde_json::Map<String, serde_json::Value>,
+) {
+    for (key, value) in patch {
+        if value.is_null() {
+            metadata.remove(key);
+        } else {
+            metadata.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+fn handle_stats(
+    our: &Address,
+    state: &State,
+    latencies: &Latencies,
+) -> anyhow::Result<serde_json::Value> {
+    let index = pinecone::describe_index_stats(our)?;
+    // queries do not need the controller api, so stats go on without it
+    let database = match pinecone::describe_index(our) {
+        Ok(res) => res["database"].clone(),
+        Err(e) => {
+            print_to_terminal(
+                0,
+                &format!("librarian server: could not describe index: {}", e),
+            );
+            serde_json::Value::Null
+        }
+    };
+    let dimension = index["dimension"].as_u64().unwrap_or_default();
+    let metric = database["metric"].as_str().map(|metric| metric.to_string());
+    let index_type = database.is_object().then(|| {
+        database["pod_type"]
+            .as_str()
+            .unwrap_or("serverless")
+            .to_string()
+    });
+    let collection = |vectors: u64| CollectionStats {
+        vectors,
+        documents: 0,
+        dimension,
+        metric: metric.clone(),
+        index_type: index_type.clone(),
+        // values are stored as 32-bit floats
+        memory_bytes: vectors * dimension * 4,
+        disk_bytes: 0,
+        last_ingest: None,
+    };
+
+    let mut collections: BTreeMap<String, CollectionStats> = BTreeMap::new();
+    if let Some(namespaces) = index["namespaces"].as_object() {
+        for (name, namespace) in namespaces {
+            collections.insert(
+                name.clone(),
+                collection(namespace["vectorCount"].as_u64().unwrap_or_default()),
+            );
+        }
+    }
+    for document in state.documents.values() {
+        let stats = collections
+            .entry(document.namespace.clone())
+            .or_insert_with(|| collection(0));
+        stats.documents += 1;
+        stats.disk_bytes += (document.text.len()
+            + serde_json::to_string(&document.metadata).map_or(0, |metadata| metadata.len()))
+            as u64;
+    }
+    for (name, time) in &state.last_ingest {
+        if let Some(stats) = collections.get_mut(name) {
+            stats.last_ingest = Some(*time);
+        }
+    }
+
+    Ok(serde_json::to_value(StatsResponse {
+        vectors: index["totalVectorCount"].as_u64().unwrap_or_default(),
+        documents: state.documents.len(),
+        deleted: state.tombstones.len(),
+        generation: state.generation,
+        fullness: index["indexFullness"].as_f64().unwrap_or_default(),
+        collections,
+        query_latency: latencies.stats(),
+    })?)
+}
+
+fn handle_configure_query_log(
+    our: &Address,
+    state: &mut State,
+    config: Option<QueryLogConfig>,
+) -> anyhow::Result<serde_json::Value> {
+    state.query_log.configure(our, config)?;
+    state.mark_changed();
+    Ok(json!({ "config": state.query_log.config }))
+}
+
+fn handle_configure_reranker(
+    state: &mut State,
+    uri: Option<String>,
+) -> anyhow::Result<serde_json::Value> {
+    state.reranker_uri = uri.as_deref().map(urls::normalize).transpose()?;
+    state.mark_changed();
+    Ok(json!({ "uri": state.reranker_uri }))
+}
+
+fn handle_configure_admins(
+    state: &mut State,
+    admins: Vec<String>,
+) -> anyhow::Result<serde_json::Value> {
+    state.admins = admins;
+    state.mark_changed();
+    Ok(json!({ "admins": state.admins }))
+}
+
+fn handle_click(
+    our: &Address,
+    state: &mut State,
+    source: &Address,
+    click: ClickRequest,
+) -> anyhow::Result<serde_json::Value> {
+    if !state.query_log.enabled() {
+        return Ok(json!({ "logged": false }));
+    }
+    if state.query_log.append(
+        our,
+        &LogEntry::Click {
+            timestamp: now(),
+            source: source.node.clone(),
+            query_id: click.query_id,
+            id: click.id,
+            position: click.position,
+        },
+    )? {
+        state.mark_changed();
+    }
+    Ok(json!({ "logged": true }))
+}
+
+fn handle_analytics(
+    our: &Address,
+    state: &mut State,
+    analytics: AnalyticsRequest,
+) -> anyhow::Result<serde_json::Value> {
+    if !state.query_log.enabled() {
+        return Err(anyhow::anyhow!("the query log is off"));
+    }
+    let since = analytics
+        .days
+        .map_or(0, |days| now().saturating_sub(days * 24 * 60 * 60));
+    let entries = state.query_log.entries(our, since)?;
+    Ok(serde_json::to_value(querylog::analyze(
+        &entries,
+        analytics.limit,
+    ))?)
+}
+
+/// Logs a first page of query results, if the query log is on, and tags the
+/// results with the id of the log entry.
+fn log_query(
+    our: &Address,
+    state: &mut State,
+    source: &Address,
+    query: &QueryRequest,
+    latency: Duration,
+    res: &mut serde_json::Value,
+) -> anyhow::Result<()> {
+    if !state.query_log.enabled() {
+        return Ok(());
+    }
+    let mut hasher = DefaultHasher::new();
+    SystemTime::now().hash(&mut hasher);
+    source.node.hash(&mut hasher);
+    let id = format!("{:016x}", hasher.finish());
+    let results = res["matches"]
+        .as_array()
+        .map(|matches| {
+            matches
+                .iter()
+                .filter_map(|m| m["id"].as_str().map(|id| id.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    if state.query_log.append(
+        our,
+        &LogEntry::Query {
+            id: id.clone(),
+            timestamp: now(),
+            source: source.node.clone(),
+            text: query.text.clone(),
+            filter: query.filter.clone(),
+            latency_ms: latency.as_secs_f64() * 1000.0,
+            results,
+        },
+    )? {
+        state.mark_changed();
+    }
+    res["queryId"] = id.into();
+    Ok(())
+}
+
+fn handle_feedback(
+    our: &Address,
+    state: &mut State,
+    memory: &mut Memory,
+    source: &Address,
+    feedback: FeedbackRequest,
+) -> anyhow::Result<serde_json::Value> {
+    let document = match &feedback.session {
+        Some(_) => Some(document_vector(
+            our,
+            state,
+            &feedback.id,
+            &feedback.namespace,
+        )?),
+        None => None,
+    };
+    // only counted once the record is kept, so that a vote that fails can be
+    // sent again
+    state.feedback.append(
+        our,
+        FeedbackRecord {
+            timestamp: now(),
+            source: source.node.clone(),
+            session: feedback.session.clone(),
+            query: feedback.vector,
+            id: feedback.id.clone(),
+            label: feedback.label,
+        },
+    )?;
+    if let (Some(session), Some(document)) = (&feedback.session, document) {
+        memory.sessions.add(session, feedback.label, document);
+    }
+    let votes = state.votes.entry(feedback.id.clone()).or_default();
+    votes.add(feedback.label);
+    let popularity = votes.popularity();
+    state.mark_changed();
+
+    Ok(json!({
+        "id": feedback.id,
+        "popularity": popularity,
+    }))
+}
+
+fn handle_command(
+    our: &Address,
+    state: &mut State,
+    memory: &mut Memory,
+    source: &Address,
+    line: &str,
+) -> anyhow::Result<serde_json::Value> {
+    if line.trim() == "help" {
+        return Ok(json!({ "output": terminal::HELP }));
+    }
+    let command = Command::parse(line)?;
+    let res = handle_request(our, state, memory, source, command.request()?, None)?;
+    Ok(json!({ "output": command.format(res)? }))
+}
+
+/// Anyone may search the library, but only our own node and its admins may
+/// change it, configure it or read its query log, and only our own node may
+/// say who its admins are. Terminal commands are checked as the action each
+/// one runs.
+fn authorize(
+    our: &Address,
+    state: &State,
+    source: &Address,
+    action: &ServerRequest,
+) -> anyhow::Result<()> {
+    let allowed = match action {
+        ServerRequest::Ingest(_)
+        | ServerRequest::IngestPayload(_)
+        | ServerRequest::IngestUrl(_)
+        | ServerRequest::Delete(_)
+        | ServerRequest::UpdateMetadata(_)
+        | ServerRequest::ConfigureQueryLog(_)
+        | ServerRequest::ConfigureReranker(_)
+        | ServerRequest::Analytics(_) => {
+            source.node == our.node || state.admins.contains(&source.node)
+        }
+        ServerRequest::ConfigureAdmins(_) => source.node == our.node,
+        _ => true,
+    };
+    match allowed {
+        true => Ok(()),
+        false => Err(anyhow::anyhow!(
+            "{} is not an admin of this library",
+            source.node
+        )),
+    }
+}
+
+fn handle_request(
+    our: &Address,
+    state: &mut State,
+    memory: &mut Memory,
+    source: &Address,
+    action: ServerRequest,
+    payload: Option<Payload>,
+) -> anyhow::Result<serde_json::Value> {
+    authorize(our, state, source, &action)?;
+    let start = Instant::now();
+    let query = match &action {
+        ServerRequest::Query(query) => Some(query.clone()),
+        ServerRequest::QueryById(query) => Some(query.query.clone()),
+        _ => None,
+    };
+    let mut res = match action {
+        ServerRequest::Query(query) => handle_query(our, state, memory, query),
+        ServerRequest::QueryById(query) => handle_query_by_id(our, state, memory, query),
+        ServerRequest::Fetch(fetch) => handle_fetch(our, state, fetch),
+        // anything that changes the library makes cached rankings stale
+        ServerRequest::Ingest(ingest) => {
+            memory.results.invalidate();
+            handle_ingest(our, state, ingest)
+        }
+        ServerRequest::IngestPayload(ingest) => {
+            memory.results.invalidate();
+            handle_ingest_payload(our, state, ingest, payload)
+        }
+        ServerRequest::IngestUrl(ingest) => {
+            memory.results.invalidate();
+            handle_ingest_url(our, state, ingest)
+        }
+        ServerRequest::Delete(delete) => {
+            memory.results.invalidate();
+            handle_delete(state, delete)
+        }
+        ServerRequest::UpdateMetadata(update) => {
+            memory.results.invalidate();
+            handle_update_metadata(our, state, update)
+        }
+        ServerRequest::Stats => handle_stats(our, state, &memory.latencies),
+        ServerRequest::Ping => Ok(json!({})),
+        ServerRequest::ConfigureQueryLog(config) => handle_configure_query_log(our, state, config),
+        ServerRequest::ConfigureReranker(uri) => handle_configure_reranker(state, uri),
+        ServerRequest::ConfigureAdmins(admins) => handle_configure_admins(state, admins),
+        // votes change popularity-weighted rankings
+        ServerRequest::Feedback(feedback) => {
+            memory.results.invalidate();
+            handle_feedback(our, state, memory, source, feedback)
+        }
+        ServerRequest::Click(click) => handle_click(our, state, source, click),
+        ServerRequest::Analytics(analytics) => handle_analytics(our, state, analytics),
+        ServerRequest::Command(line) => handle_command(our, state, memory, source, &line),
+    };
+
+    if let Some(query) = query {
+        let latency = start.elapsed();
+        memory.latencies.record(latency);
+        // following pages are not new queries
+        if let (Ok(res), None) = (&mut res, &query.cursor) {
+            if let Err(e) = log_query(our, state, source, &query, latency, res) {
+                print_to_terminal(0, &format!("librarian server: could not log query: {}", e));
+            }
+        }
+    }
+    res
+}
+
+/// The response to the client, and the error to print if there is one.
+fn server_response(result: anyhow::Result<serde_json::Value>) -> Vec<Effect> {
+    let mut effects = vec![Effect::print("librarian server: sending response")];
+    let (ipc, body) = match result {
+        Ok(body) => (ServerResponse::Ok, body),
+        Err(e) => {
+            effects.insert(0, Effect::print(format!("librarian server: error: {}", e)));
+            (
+                ServerResponse::Err(e.to_string()),
+                json!({ "error": e.to_string() }),
+            )
+        }
+    };
+    effects.push(Effect::Respond {
+        response: Response {
+            inherit: false,
+            ipc: Some(serde_json::to_string(&ipc).unwrap()),
+            metadata: None,
+        },
+        payload: Some(Payload {
+            mime: Some("application/json".to_string()),
+            bytes: body.to_string().into_bytes(),
+        }),
+    });
+    effects
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Guest for Component {
+    fn init(our: bindings::Address) {
+        // our own bindings only serve the entry point; everything else goes
+        // through process_lib's
+        run(Address {
+            node: our.node,
+            process: ProcessId::new(
+                &our.process.process_name,
+                &our.process.package_name,
+                &our.process.publisher_node,
+            ),
+        })
+    }
+}
+
+/// The process itself, which `init` starts. On native targets tests run it
+/// against process_lib's mock kernel.
+pub fn run(our: Address) {
+    print_to_terminal(0, "librarian: start");
+
+    handler::run(&mut Server {
+        state: state::load_or_default::<State>(&our).index_fingerprints(),
+        our,
+        memory: Memory::default(),
+    });
+}
+
+/// The server as its receive loop drives it. Its answers, saved state, prints
+/// and the compaction requests it sends itself are effects, but handling a
+/// request calls Pinecone, the embedding service and the filesystem directly
+/// and awaits them, so tests run it against the mock kernel.
+struct Server {
+    our: Address,
+    state: State,
+    memory: Memory,
+}
+
+impl Handler for Server {
+    fn handle(
+        &mut self,
+        source: &Address,
+        message: Message,
+        payload: Option<Payload>,
+    ) -> Vec<Effect> {
+        let Message::Request(request) = message else {
+            return vec![Effect::print("librarian: got unexpected Response")];
+        };
+        let (our, state, memory) = (&self.our, &mut self.state, &mut self.memory);
+
+        let mut effects = if source == our && request.ipc.as_deref() == Some(COMPACT_REQUEST) {
+            memory.compaction_requested = false;
+            match state.compaction_due().then(|| compact(our, state)) {
+                Some(Ok(compacted)) => {
+                    memory.compaction_failed_at = None;
+                    vec![Effect::print(format!(
+                        "librarian server: compacted {} deleted documents",
+                        compacted
+                    ))]
+                }
+                Some(Err(e)) => {
+                    memory.compaction_failed_at = Some(Instant::now());
+                    vec![Effect::print(format!(
+                        "librarian server: compaction failed: {}",
+                        e
+                    ))]
+                }
+                None => vec![],
+            }
+        } else if source.process == "terminal:terminal:uqbar" && source.node == our.node {
+            // commands typed into the terminal come as plain text
+            let line = request.ipc.unwrap_or_default();
+            vec![Effect::print(
+                match handle_command(our, state, memory, source, &line) {
+                    Ok(res) => res["output"].as_str().unwrap_or_default().to_string(),
+                    Err(e) => format!("librarian server: {}", e),
+                },
+            )]
+        } else if source.process == "librarian:librarian:drew.uq" {
+            let result = process_lib::parse_message_ipc::<ServerRequest>(request.ipc)
+                .and_then(|action| handle_request(our, state, memory, source, action, payload));
+            let mut effects = vec![Effect::print("librarian server: got message from client")];
+            effects.extend(server_response(result));
+            effects
+        } else {
+            vec![Effect::print("librarian: got message from unknown source")]
+        };
+
+        // there are no timers, so once enough deletions have piled up, the
+        // server asks itself to compact, which it gets to after answering
+        if memory.should_request_compaction(state) {
+            memory.compaction_requested = true;
+            effects.push(Effect::Send {
+                target: our.clone(),
+                request: Request {
+                    inherit: false,
+                    expects_response: None,
+                    ipc: Some(COMPACT_REQUEST.to_string()),
+                    metadata: None,
+                },
+                context: None,
+                payload: None,
+            });
+        }
+        effects.extend(state.take_changes());
+        effects
+    }
+
+    fn handle_send_error(&mut self, _: SendError, _: Option<Context>) -> Vec<Effect> {
+        vec![Effect::print("librarian: got network error")]
     }
 }
@@ -0,0 +1,42 @@
+use process_lib::protocol::LatencyStats;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// how many of the most recent queries latency percentiles are computed over
+const LATENCY_WINDOW: usize = 1000;
+
+/// Recent query latencies. Kept in memory only, so they start over when the
+/// server restarts.
+#[derive(Default)]
+pub struct Latencies {
+    recent: VecDeque<Duration>,
+}
+
+impl Latencies {
+    pub fn record(&mut self, latency: Duration) {
+        if self.recent.len() == LATENCY_WINDOW {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(latency);
+    }
+
+    pub fn stats(&self) -> LatencyStats {
+        let mut sorted: Vec<f64> = self
+            .recent
+            .iter()
+            .map(|latency| latency.as_secs_f64() * 1000.0)
+            .collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        // nearest-rank percentiles
+        let percentile = |p: f64| {
+            let rank = (p * sorted.len() as f64).ceil() as usize;
+            sorted.get(rank.max(1) - 1).copied()
+        };
+        LatencyStats {
+            count: sorted.len(),
+            p50_ms: percentile(0.5),
+            p90_ms: percentile(0.9),
+            p99_ms: percentile(0.99),
+        }
+    }
+}
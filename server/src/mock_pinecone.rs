@@ -0,0 +1,424 @@
+//! An in-memory stand-in for the Pinecone REST API, so tests can run the server
+//! against an index offline. It answers the requests the server sends through
+//! http_client, see [`MockPinecone::answer`], and can be told to fail the next
+//! requests with an error status, a slow answer or a body that is not JSON.
+//! It evaluates filters and scores with its own code rather than the server's,
+//! so that a bug in those shows up in tests instead of being mirrored.
+
+use process_lib::mock::{self, Reply};
+use process_lib::types::*;
+use process_lib::urls;
+use serde_json::{json, Map, Value};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, VecDeque};
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Something to go wrong with a request.
+#[derive(Debug, Clone)]
+pub enum Fault {
+    /// answer with this status and a JSON error body
+    Status(u16),
+    /// answer this much later; at or beyond the request's timeout, it times out
+    Latency(Duration),
+    /// answer 200 with a body that is not JSON
+    Malformed,
+}
+
+#[derive(Debug, Clone)]
+struct Vector {
+    values: Vec<f32>,
+    metadata: Map<String, Value>,
+}
+
+#[derive(Default)]
+struct Index {
+    dimension: usize,
+    namespaces: BTreeMap<String, BTreeMap<String, Vector>>,
+    faults: VecDeque<Fault>,
+    /// `METHOD /path` of every request answered, oldest first
+    calls: Vec<String>,
+}
+
+/// A handle on the index; clones share it, so a test keeps one to check the
+/// index after the server has run.
+#[derive(Clone)]
+pub struct MockPinecone {
+    index: Rc<RefCell<Index>>,
+}
+
+impl MockPinecone {
+    /// An empty index of vectors with `dimension` values.
+    pub fn new(dimension: usize) -> Self {
+        MockPinecone {
+            index: Rc::new(RefCell::new(Index {
+                dimension,
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Whether `uri` is one of Pinecone's.
+    pub fn serves(uri: &str) -> bool {
+        uri.split('/')
+            .nth(2)
+            .is_some_and(|host| host.ends_with(".pinecone.io"))
+    }
+
+    /// Makes the next request go wrong; faults apply in the order given.
+    pub fn fail_next(&self, fault: Fault) {
+        self.index.borrow_mut().faults.push_back(fault);
+    }
+
+    pub fn upsert(&self, namespace: &str, id: &str, values: Vec<f32>, metadata: Value) {
+        self.index
+            .borrow_mut()
+            .namespaces
+            .entry(namespace.to_string())
+            .or_default()
+            .insert(
+                id.to_string(),
+                Vector {
+                    values,
+                    metadata: metadata.as_object().cloned().unwrap_or_default(),
+                },
+            );
+    }
+
+    /// The ids in `namespace`, in order.
+    pub fn ids(&self, namespace: &str) -> Vec<String> {
+        self.index
+            .borrow()
+            .namespaces
+            .get(namespace)
+            .map(|vectors| vectors.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// `METHOD /path` of every request answered, oldest first.
+    pub fn calls(&self) -> Vec<String> {
+        self.index.borrow().calls.clone()
+    }
+
+    /// Answers a request to http_client for a Pinecone uri.
+    pub fn answer(&self, request: &Request, payload: Option<&Payload>) -> Reply {
+        let ipc: Value = serde_json::from_str(request.ipc.as_deref().unwrap_or_default())
+            .expect("http_client requests are JSON");
+        let method = ipc["method"].as_str().unwrap_or_default();
+        let uri = ipc["uri"].as_str().unwrap_or_default();
+        let (path, query) = uri
+            .splitn(4, '/')
+            .nth(3)
+            .map(|path| path.split_once('?').unwrap_or((path, "")))
+            .unwrap_or_default();
+        let path = format!("/{}", path);
+
+        let mut index = self.index.borrow_mut();
+        index.calls.push(format!("{} {}", method, path));
+        let (status, body) = match index.faults.pop_front() {
+            Some(Fault::Status(status)) => (status, json!({ "message": "mock failure" })),
+            Some(Fault::Malformed) => return respond(200, b"<html>oops</html>".to_vec()),
+            Some(Fault::Latency(latency)) => {
+                if latency.as_secs() >= request.expects_response.unwrap_or(u64::MAX) {
+                    return Err(SendErrorKind::Timeout);
+                }
+                std::thread::sleep(latency);
+                index.route(method, &path, query, &ipc["headers"], payload)
+            }
+            None => index.route(method, &path, query, &ipc["headers"], payload),
+        };
+        respond(status, body.to_string().into_bytes())
+    }
+}
+
+fn respond(status: u16, body: Vec<u8>) -> Reply {
+    mock::reply(
+        &json!({
+            "status": status,
+            "headers": { "content-type": "application/json" },
+        })
+        .to_string(),
+        Some(Payload {
+            mime: Some("application/json".to_string()),
+            bytes: body,
+        }),
+    )
+}
+
+fn error(status: u16, message: &str) -> (u16, Value) {
+    (status, json!({ "code": status, "message": message }))
+}
+
+impl Index {
+    fn route(
+        &mut self,
+        method: &str,
+        path: &str,
+        query: &str,
+        headers: &Value,
+        payload: Option<&Payload>,
+    ) -> (u16, Value) {
+        let has_key = headers.as_object().is_some_and(|headers| {
+            headers
+                .iter()
+                .any(|(name, value)| name.eq_ignore_ascii_case("api-key") && value != "")
+        });
+        if !has_key {
+            return error(401, "missing Api-Key");
+        }
+        let body: Value = match payload {
+            Some(payload) if !payload.bytes.is_empty() => {
+                match serde_json::from_slice(&payload.bytes) {
+                    Ok(body) => body,
+                    Err(e) => return error(400, &e.to_string()),
+                }
+            }
+            _ => json!({}),
+        };
+        let result = match (method, path) {
+            ("POST", "/query") => self.query(&body),
+            ("POST", "/vectors/upsert") => self.upsert(&body),
+            ("GET", "/vectors/fetch") => Ok(self.fetch(query)),
+            ("POST", "/vectors/delete") => self.delete(&body),
+            ("POST", "/describe_index_stats") => Ok(self.describe_index_stats()),
+            ("GET", path) if path.starts_with("/databases/") => Ok(json!({
+                "database": {
+                    "name": &path["/databases/".len()..],
+                    "dimension": self.dimension,
+                    "metric": "cosine",
+                    "pod_type": "p1.x1",
+                },
+                "status": { "ready": true, "state": "Ready" },
+            })),
+            _ => return error(404, "not found"),
+        };
+        match result {
+            Ok(body) => (200, body),
+            Err(message) => error(400, &message),
+        }
+    }
+
+    fn namespace(body: &Value) -> String {
+        body["namespace"].as_str().unwrap_or_default().to_string()
+    }
+
+    fn check_dimension(&self, values: &[f32]) -> Result<(), String> {
+        match values.len() == self.dimension {
+            true => Ok(()),
+            false => Err(format!(
+                "Vector dimension {} does not match the dimension of the index {}",
+                values.len(),
+                self.dimension
+            )),
+        }
+    }
+
+    fn query(&self, body: &Value) -> Result<Value, String> {
+        let namespace = Index::namespace(body);
+        let vectors = self.namespaces.get(&namespace);
+        let query: Vec<f32> = match (&body["vector"], body["id"].as_str()) {
+            (Value::Array(_), _) => {
+                serde_json::from_value(body["vector"].clone()).map_err(|e| e.to_string())?
+            }
+            (_, Some(id)) => match vectors.and_then(|vectors| vectors.get(id)) {
+                Some(vector) => vector.values.clone(),
+                None => return Ok(json!({ "matches": [], "namespace": namespace })),
+            },
+            _ => return Err("one of vector or id is required".to_string()),
+        };
+        self.check_dimension(&query)?;
+        let top_k = body["topK"].as_u64().ok_or("topK is required")? as usize;
+        let mut matches = vec![];
+        for (id, vector) in vectors.into_iter().flatten() {
+            if let Some(filter) = body.get("filter") {
+                if !satisfies(filter, &vector.metadata)? {
+                    continue;
+                }
+            }
+            let mut m = json!({
+                "id": id,
+                "score": cosine(&query, &vector.values),
+            });
+            if body["includeValues"] == true {
+                m["values"] = json!(vector.values);
+            }
+            if body["includeMetadata"] == true {
+                m["metadata"] = Value::Object(vector.metadata.clone());
+            }
+            matches.push(m);
+        }
+        matches.sort_by(|a, b| {
+            let score = |m: &Value| m["score"].as_f64().unwrap_or_default();
+            score(b).total_cmp(&score(a))
+        });
+        matches.truncate(top_k);
+        Ok(json!({ "matches": matches, "namespace": namespace }))
+    }
+
+    fn upsert(&mut self, body: &Value) -> Result<Value, String> {
+        let vectors = body["vectors"].as_array().ok_or("vectors is required")?;
+        let mut upserted = vec![];
+        for vector in vectors {
+            let id = vector["id"].as_str().ok_or("every vector needs an id")?;
+            let values: Vec<f32> =
+                serde_json::from_value(vector["values"].clone()).map_err(|e| e.to_string())?;
+            self.check_dimension(&values)?;
+            upserted.push((
+                id.to_string(),
+                Vector {
+                    values,
+                    metadata: vector["metadata"].as_object().cloned().unwrap_or_default(),
+                },
+            ));
+        }
+        let count = upserted.len();
+        self.namespaces
+            .entry(Index::namespace(body))
+            .or_default()
+            .extend(upserted);
+        Ok(json!({ "upsertedCount": count }))
+    }
+
+    fn fetch(&self, query: &str) -> Value {
+        let mut ids = vec![];
+        let mut namespace = String::new();
+        for param in query.split('&') {
+            match param.split_once('=') {
+                Some(("ids", id)) => ids.push(urls::decode_component(id)),
+                Some(("namespace", name)) => namespace = urls::decode_component(name),
+                _ => {}
+            }
+        }
+        let vectors: Map<String, Value> = ids
+            .into_iter()
+            .filter_map(|id| {
+                let vector = self.namespaces.get(&namespace)?.get(&id)?;
+                Some((
+                    id.clone(),
+                    json!({ "id": id, "values": vector.values, "metadata": vector.metadata }),
+                ))
+            })
+            .collect();
+        json!({ "vectors": vectors, "namespace": namespace })
+    }
+
+    fn delete(&mut self, body: &Value) -> Result<Value, String> {
+        let Some(vectors) = self.namespaces.get_mut(&Index::namespace(body)) else {
+            return Ok(json!({}));
+        };
+        if body["deleteAll"] == true {
+            vectors.clear();
+        } else if let Some(ids) = body["ids"].as_array() {
+            for id in ids {
+                vectors.remove(id.as_str().unwrap_or_default());
+            }
+        } else if let Some(filter) = body.get("filter") {
+            let mut error = None;
+            vectors.retain(|_, vector| match satisfies(filter, &vector.metadata) {
+                Ok(matches) => !matches,
+                Err(e) => {
+                    error = Some(e);
+                    true
+                }
+            });
+            if let Some(error) = error {
+                return Err(error);
+            }
+        } else {
+            return Err("one of ids, deleteAll or filter is required".to_string());
+        }
+        Ok(json!({}))
+    }
+
+    fn describe_index_stats(&self) -> Value {
+        let namespaces: Map<String, Value> = self
+            .namespaces
+            .iter()
+            .filter(|(_, vectors)| !vectors.is_empty())
+            .map(|(name, vectors)| (name.clone(), json!({ "vectorCount": vectors.len() })))
+            .collect();
+        json!({
+            "namespaces": namespaces,
+            "dimension": self.dimension,
+            "indexFullness": 0.0,
+            "totalVectorCount": self.namespaces.values().map(BTreeMap::len).sum::<usize>(),
+        })
+    }
+}
+
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norms = norm(a) * norm(b);
+    if norms == 0.0 {
+        0.0
+    } else {
+        dot / norms
+    }
+}
+
+/// Whether `metadata` passes a Pinecone metadata filter. A field holding a
+/// list passes if any of its elements does.
+fn satisfies(filter: &Value, metadata: &Map<String, Value>) -> Result<bool, String> {
+    let filter = filter.as_object().ok_or("filter must be an object")?;
+    for (key, condition) in filter {
+        let passes = match key.as_str() {
+            "$and" | "$or" => {
+                let filters = condition
+                    .as_array()
+                    .ok_or(format!("{} takes a list of filters", key))?;
+                let mut results = vec![];
+                for filter in filters {
+                    results.push(satisfies(filter, metadata)?);
+                }
+                match key.as_str() {
+                    "$and" => results.iter().all(|&passes| passes),
+                    _ => results.iter().any(|&passes| passes),
+                }
+            }
+            field => {
+                let values: Vec<&Value> = match metadata.get(field) {
+                    Some(Value::Array(values)) => values.iter().collect(),
+                    Some(value) => vec![value],
+                    None => vec![],
+                };
+                let operators = match condition.as_object() {
+                    Some(operators) => operators.clone(),
+                    None => Map::from_iter([("$eq".to_string(), condition.clone())]),
+                };
+                let mut passes = true;
+                for (operator, operand) in &operators {
+                    passes &= holds(operator, operand, &values)?;
+                }
+                passes
+            }
+        };
+        if !passes {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn holds(operator: &str, operand: &Value, values: &[&Value]) -> Result<bool, String> {
+    let listed = |value: &&Value| operand.as_array().is_some_and(|list| list.contains(value));
+    let compared = |keep: fn(f64, f64) -> bool| {
+        values
+            .iter()
+            .any(|value| match (value.as_f64(), operand.as_f64()) {
+                (Some(value), Some(operand)) => keep(value, operand),
+                _ => false,
+            })
+    };
+    Ok(match operator {
+        "$eq" => values.contains(&operand),
+        "$ne" => !values.contains(&operand),
+        "$in" => values.iter().any(listed),
+        "$nin" => !values.iter().any(listed),
+        "$gt" => compared(|value, operand| value > operand),
+        "$gte" => compared(|value, operand| value >= operand),
+        "$lt" => compared(|value, operand| value < operand),
+        "$lte" => compared(|value, operand| value <= operand),
+        _ => return Err(format!("unsupported filter operator {}", operator)),
+    })
+}
@@ -0,0 +1,340 @@
+use process_lib::protocol::{Match, QueryRequest};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use super::dedup::fnv1a;
+
+/// how long a ranked result list stays around for following pages after it
+/// was last ranked
+const CACHE_TTL: Duration = Duration::from_secs(300);
+const CACHE_CAPACITY: usize = 32;
+
+/// Identifies a query by everything but which page is asked for, so that all
+/// its pages share one key.
+pub fn query_key(query: &QueryRequest) -> u64 {
+    let query = QueryRequest {
+        top_k: 0,
+        offset: 0,
+        cursor: None,
+        ..query.clone()
+    };
+    fnv1a(serde_json::to_string(&query).unwrap().as_bytes())
+}
+
+/// Where a page of results ended. Handed to clients as an opaque string, which
+/// they send back with the same query to get its next page. It only refers to
+/// the query by its key, and resumes from the last result seen, so it stays
+/// valid while no results are cached and across restarts.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Cursor {
+    /// the [`query_key`] of the query it pages through
+    pub query: u64,
+    /// index of the first result of the next page
+    pub offset: usize,
+    pub last_id: String,
+    pub last_score: f32,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        base64url_encode(serde_json::to_string(self).unwrap().as_bytes())
+    }
+
+    pub fn decode(cursor: &str) -> anyhow::Result<Self> {
+        let bytes = base64url_decode(cursor).ok_or(anyhow::anyhow!("malformed cursor"))?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// Where the next page starts in `ranked`: right after the last result
+    /// seen, even if documents were added or removed in front of it since.
+    /// MMR results are not ordered by score, which `mmr` says they are.
+    pub fn position(&self, ranked: &[Match], mmr: bool) -> usize {
+        if let Some(i) = ranked.iter().position(|m| m.id == self.last_id) {
+            return i + 1;
+        }
+        // the last result seen is gone. everything but MMR resumes below the
+        // last score seen
+        if mmr {
+            return self.offset;
+        }
+        ranked
+            .iter()
+            .position(|m| m.rerank_score.unwrap_or(m.score) < self.last_score)
+            .unwrap_or(ranked.len())
+    }
+}
+
+/// Recently ranked result lists by [`query_key`], so that following a cursor
+/// does not usually rerun the whole query pipeline.
+pub struct ResultCache {
+    entries: HashMap<u64, CachedResults>,
+    ttl: Duration,
+}
+
+struct CachedResults {
+    ranked_at: Instant,
+    matches: Vec<Match>,
+    /// whether `matches` holds every result there is
+    exhausted: bool,
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        ResultCache::with_ttl(CACHE_TTL)
+    }
+}
+
+impl ResultCache {
+    fn with_ttl(ttl: Duration) -> Self {
+        ResultCache {
+            entries: HashMap::new(),
+            ttl,
+        }
+    }
+
+    /// Returns the results of the query with `key` and whether they are all
+    /// there is, ranking at least `depth` of them with `rank` unless they are
+    /// cached already.
+    pub fn get_or_rank(
+        &mut self,
+        key: u64,
+        depth: usize,
+        rank: impl FnOnce() -> anyhow::Result<Vec<Match>>,
+    ) -> anyhow::Result<(&[Match], bool)> {
+        let ttl = self.ttl;
+        self.entries
+            .retain(|_, entry| entry.ranked_at.elapsed() < ttl);
+        let cached = self
+            .entries
+            .get(&key)
+            .is_some_and(|entry| entry.exhausted || entry.matches.len() >= depth);
+        if !cached {
+            let matches = rank()?;
+            if !self.entries.contains_key(&key) && self.entries.len() >= CACHE_CAPACITY {
+                let oldest = self
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.ranked_at)
+                    .map(|(&key, _)| key);
+                if let Some(oldest) = oldest {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.entries.insert(
+                key,
+                CachedResults {
+                    ranked_at: Instant::now(),
+                    exhausted: matches.len() < depth,
+                    matches,
+                },
+            );
+        }
+        let entry = &self.entries[&key];
+        Ok((&entry.matches, entry.exhausted))
+    }
+
+    /// Forgets every ranking, as the library changed. Cursors stay valid, and
+    /// their queries are ranked again.
+    pub fn invalidate(&mut self) {
+        self.entries.clear();
+    }
+}
+
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Unpadded base64url, so cursors can go in query strings as-is.
+fn base64url_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len() * 4 / 3 + 3);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &byte)| n | (byte as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            encoded.push(BASE64URL_ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+        }
+    }
+    encoded
+}
+
+fn base64url_decode(text: &str) -> Option<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(text.len() * 3 / 4);
+    for chunk in text.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut n = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let value = BASE64URL_ALPHABET.iter().position(|&a| a == c)? as u32;
+            n |= value << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            decoded.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranked(scores: &[(&str, f32)]) -> Vec<Match> {
+        scores
+            .iter()
+            .map(|&(id, score)| Match {
+                id: id.to_string(),
+                score,
+                values: None,
+                metadata: None,
+                passage: None,
+                also_published_in: vec![],
+                rerank_score: None,
+            })
+            .collect()
+    }
+
+    fn query(text: &str) -> QueryRequest {
+        serde_json::from_value(serde_json::json!({ "text": text, "topK": 2 })).unwrap()
+    }
+
+    fn cursor(last_id: &str, last_score: f32) -> Cursor {
+        Cursor {
+            query: query_key(&query("rust")),
+            offset: 2,
+            last_id: last_id.to_string(),
+            last_score,
+        }
+    }
+
+    /// The ids cached for `key`, ranking `scores` if they are not deep enough.
+    fn rank_once(
+        cache: &mut ResultCache,
+        key: u64,
+        depth: usize,
+        scores: &[(&str, f32)],
+    ) -> (Vec<String>, bool) {
+        let (matches, exhausted) = cache
+            .get_or_rank(key, depth, || Ok(ranked(scores)))
+            .unwrap();
+        (matches.iter().map(|m| m.id.clone()).collect(), exhausted)
+    }
+
+    /// Whether `depth` results for `key` are cached, without ranking any.
+    fn cached(cache: &mut ResultCache, key: u64, depth: usize) -> bool {
+        cache
+            .get_or_rank(key, depth, || Err(anyhow::anyhow!("not cached")))
+            .is_ok()
+    }
+
+    #[test]
+    fn base64url_round_trips() {
+        for (bytes, encoded) in [
+            (&b""[..], ""),
+            (b"f", "Zg"),
+            (b"fo", "Zm8"),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg"),
+            (b"\xff\xfe\xfd", "__79"),
+        ] {
+            assert_eq!(base64url_encode(bytes), encoded);
+            assert_eq!(base64url_decode(encoded).as_deref(), Some(bytes));
+        }
+        assert_eq!(base64url_decode("Zm9vY"), None);
+        assert_eq!(base64url_decode("Zm9v+A"), None);
+    }
+
+    #[test]
+    fn cursors_round_trip_and_stay_short() {
+        let cursor = cursor("some-document#3", 0.75);
+        let encoded = cursor.encode();
+        assert!(encoded.len() < 120, "{} is too long", encoded);
+        assert!(encoded
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'));
+        assert_eq!(Cursor::decode(&encoded).unwrap(), cursor);
+        assert!(Cursor::decode("not a cursor!").is_err());
+        assert!(Cursor::decode(&base64url_encode(b"{}")).is_err());
+    }
+
+    #[test]
+    fn resumes_after_the_last_result_seen() {
+        let ranked = ranked(&[("new", 0.95), ("a", 0.9), ("b", 0.8), ("c", 0.7)]);
+        assert_eq!(cursor("b", 0.8).position(&ranked, false), 3);
+        // b was deleted: resume below its score, or where it was for MMR
+        let without_b: Vec<Match> = ranked.into_iter().filter(|m| m.id != "b").collect();
+        assert_eq!(cursor("b", 0.8).position(&without_b, false), 2);
+        assert_eq!(cursor("b", 0.8).position(&without_b, true), 2);
+        assert_eq!(cursor("b", 0.1).position(&without_b, false), 3);
+    }
+
+    #[test]
+    fn queries_differing_only_in_the_page_share_a_key() {
+        let key = query_key(&query("rust"));
+        let next_page = QueryRequest {
+            offset: 4,
+            top_k: 10,
+            cursor: Some(cursor("a", 0.9).encode()),
+            ..query("rust")
+        };
+        assert_eq!(query_key(&next_page), key);
+        assert_ne!(query_key(&query("bread")), key);
+        let filtered = QueryRequest {
+            filter: Some(serde_json::json!({ "year": 2023 })),
+            ..query("rust")
+        };
+        assert_ne!(query_key(&filtered), key);
+    }
+
+    #[test]
+    fn ranks_again_when_the_results_are_not_deep_enough() {
+        let mut cache = ResultCache::default();
+        assert!(!cached(&mut cache, 1, 1));
+        let scores = [("a", 0.9), ("b", 0.8)];
+        assert_eq!(
+            rank_once(&mut cache, 1, 2, &scores),
+            (vec!["a".to_string(), "b".to_string()], false)
+        );
+        assert!(cached(&mut cache, 1, 2));
+        assert!(!cached(&mut cache, 1, 3));
+        // fewer results than asked for are all there is
+        assert!(rank_once(&mut cache, 1, 3, &scores).1);
+        assert!(cached(&mut cache, 1, 10));
+    }
+
+    #[test]
+    fn invalidating_forgets_rankings() {
+        let mut cache = ResultCache::default();
+        rank_once(&mut cache, 1, 1, &[("a", 0.9)]);
+        cache.invalidate();
+        assert!(!cached(&mut cache, 1, 1));
+    }
+
+    #[test]
+    fn rankings_expire() {
+        let mut cache = ResultCache::with_ttl(Duration::ZERO);
+        rank_once(&mut cache, 1, 1, &[("a", 0.9)]);
+        assert!(!cached(&mut cache, 1, 1));
+        assert!(cache.entries.len() <= 1);
+    }
+
+    #[test]
+    fn evicts_the_oldest_results_when_full() {
+        let mut cache = ResultCache::default();
+        for key in 0..=CACHE_CAPACITY as u64 {
+            rank_once(&mut cache, key, 1, &[("a", 0.9)]);
+        }
+        assert_eq!(cache.entries.len(), CACHE_CAPACITY);
+        assert!(!cached(&mut cache, 0, 1));
+    }
+
+    #[test]
+    fn keeps_the_results_a_failed_ranking_would_have_replaced() {
+        let mut cache = ResultCache::default();
+        rank_once(&mut cache, 1, 1, &[("a", 0.9)]);
+        assert!(!cached(&mut cache, 1, 5));
+        assert!(cached(&mut cache, 1, 1));
+    }
+}
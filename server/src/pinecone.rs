@@ -0,0 +1,124 @@
+use process_lib::types::*;
+use process_lib::{http, urls};
+use serde_json::json;
+
+#[cfg(target_arch = "wasm32")]
+const PINECONE_API_KEY: &str = include_str!("pinecone-api-key.txt");
+/// tests run without the keys, which are not checked in
+#[cfg(not(target_arch = "wasm32"))]
+const PINECONE_API_KEY: &str = "test";
+const PINECONE_INDEX_URI: &str =
+    "https://article-recommendations-8a4cf60.svc.us-west4-gcp.pinecone.io";
+/// the controller api, which holds the configuration of the index
+const PINECONE_CONTROLLER_URI: &str = "https://controller.us-west4-gcp.pinecone.io";
+const PINECONE_INDEX_NAME: &str = "article-recommendations";
+const PINECONE_TIMEOUT: u64 = 10;
+/// pinecone recommends upserting at most 100 vectors per request
+const UPSERT_BATCH_SIZE: usize = 100;
+
+fn call(
+    our: &Address,
+    method: &str,
+    path: &str,
+    body: Option<&serde_json::Value>,
+) -> anyhow::Result<serde_json::Value> {
+    call_uri(
+        our,
+        method,
+        &format!("{}{}", PINECONE_INDEX_URI, path),
+        body,
+    )
+}
+
+fn call_uri(
+    our: &Address,
+    method: &str,
+    uri: &str,
+    body: Option<&serde_json::Value>,
+) -> anyhow::Result<serde_json::Value> {
+    let mut request = http::Request::new(method, uri)
+        .credential("Api-Key", PINECONE_API_KEY.trim())
+        .header("accept", "application/json")
+        .timeout(PINECONE_TIMEOUT);
+    if let Some(body) = body {
+        request = request.json(body)?;
+    }
+    Ok(request.send(our)?.error_for_status()?.json()?)
+}
+
+/// Runs a raw pinecone query; `body` is passed through as-is.
+pub fn query(our: &Address, body: &serde_json::Value) -> anyhow::Result<serde_json::Value> {
+    call(our, "POST", "/query", Some(body))
+}
+
+/// The configuration of the index: `{"database": {"dimension", "metric",
+/// "pod_type", ...}, "status": {...}}`.
+pub fn describe_index(our: &Address) -> anyhow::Result<serde_json::Value> {
+    call_uri(
+        our,
+        "GET",
+        &format!(
+            "{}/databases/{}",
+            PINECONE_CONTROLLER_URI, PINECONE_INDEX_NAME
+        ),
+        None,
+    )
+}
+
+/// Vector counts of the index and each of its namespaces.
+pub fn describe_index_stats(our: &Address) -> anyhow::Result<serde_json::Value> {
+    call(our, "POST", "/describe_index_stats", Some(&json!({})))
+}
+
+/// Upserts `{id, values, metadata}` vectors into `namespace`.
+pub fn upsert(our: &Address, namespace: &str, vectors: &[serde_json::Value]) -> anyhow::Result<()> {
+    for batch in vectors.chunks(UPSERT_BATCH_SIZE) {
+        call(
+            our,
+            "POST",
+            "/vectors/upsert",
+            Some(&json!({
+                "namespace": namespace,
+                "vectors": batch,
+            })),
+        )?;
+    }
+    Ok(())
+}
+
+pub fn delete(our: &Address, namespace: &str, ids: &[String]) -> anyhow::Result<()> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+    call(
+        our,
+        "POST",
+        "/vectors/delete",
+        Some(&json!({
+            "namespace": namespace,
+            "ids": ids,
+        })),
+    )?;
+    Ok(())
+}
+
+/// Fetches vectors by id, returning a map from id to `{id, values, metadata}`.
+/// Ids that are not in the index are absent from the map.
+pub fn fetch(
+    our: &Address,
+    namespace: &str,
+    ids: &[String],
+) -> anyhow::Result<serde_json::Map<String, serde_json::Value>> {
+    let mut query: Vec<String> = ids
+        .iter()
+        .map(|id| format!("ids={}", urls::encode_component(id)))
+        .collect();
+    query.push(format!("namespace={}", urls::encode_component(namespace)));
+    let res = call(
+        our,
+        "GET",
+        &format!("/vectors/fetch?{}", query.join("&")),
+        None,
+    )?;
+    Ok(res["vectors"].as_object().cloned().unwrap_or_default())
+}
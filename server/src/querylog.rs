@@ -0,0 +1,230 @@
+use process_lib::protocol::{AnalyticsResponse, Count, QueryLogConfig};
+use process_lib::types::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use process_lib::fs;
+
+/// entries per log file; retention drops whole files
+const SEGMENT_ENTRIES: usize = 1000;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// The query log, kept as JSON lines in filesystem files. Off unless an
+/// operator turns it on. Only which files make up the log is saved with the
+/// server's state: how full they are is read back from the files themselves,
+/// so that logging a query does not rewrite the state.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct QueryLog {
+    /// `None` while logging is off
+    pub config: Option<QueryLogConfig>,
+    /// oldest first; entries are appended to the last one
+    segments: Vec<Segment>,
+    /// whether the segments' entries and times have been read from their files
+    #[serde(skip)]
+    counted: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Segment {
+    file: u128,
+    #[serde(skip)]
+    entries: usize,
+    /// time of the newest entry, in seconds since the epoch
+    #[serde(skip)]
+    last: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum LogEntry {
+    Query {
+        id: String,
+        timestamp: u64,
+        source: String,
+        text: Option<String>,
+        filter: Option<serde_json::Value>,
+        latency_ms: f64,
+        results: Vec<String>,
+    },
+    Click {
+        timestamp: u64,
+        source: String,
+        query_id: Option<String>,
+        id: String,
+        position: Option<usize>,
+    },
+}
+
+impl LogEntry {
+    fn timestamp(&self) -> u64 {
+        match self {
+            LogEntry::Query { timestamp, .. } | LogEntry::Click { timestamp, .. } => *timestamp,
+        }
+    }
+}
+
+impl QueryLog {
+    pub fn enabled(&self) -> bool {
+        self.config.is_some()
+    }
+
+    /// Turns logging on or off. Turning it off deletes the log.
+    pub fn configure(
+        &mut self,
+        our: &Address,
+        config: Option<QueryLogConfig>,
+    ) -> anyhow::Result<()> {
+        if config.is_none() {
+            for segment in self.segments.drain(..) {
+                fs::delete(our, segment.file)?;
+            }
+        }
+        self.config = config;
+        Ok(())
+    }
+
+    /// Reads how many entries each file holds and when the newest was logged,
+    /// the first time the log is used.
+    fn count(&mut self, our: &Address) -> anyhow::Result<()> {
+        if self.counted {
+            return Ok(());
+        }
+        for segment in &mut self.segments {
+            let entries = read_segment(our, segment.file)?;
+            segment.entries = entries.len();
+            segment.last = entries.iter().map(LogEntry::timestamp).max().unwrap_or(0);
+        }
+        self.counted = true;
+        Ok(())
+    }
+
+    /// Appends an entry if logging is on, then drops whatever the retention
+    /// limits no longer cover. Returns whether the log's files changed, in
+    /// which case the caller saves the state.
+    pub fn append(&mut self, our: &Address, entry: &LogEntry) -> anyhow::Result<bool> {
+        let Some(config) = &self.config else {
+            return Ok(false);
+        };
+        let (max_entries, retention) =
+            (config.max_entries, config.retention_days * SECONDS_PER_DAY);
+        self.count(our)?;
+        let mut changed = false;
+
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+        match self.segments.last_mut() {
+            Some(segment) if segment.entries < SEGMENT_ENTRIES => {
+                fs::append(our, Some(segment.file), line)?;
+                segment.entries += 1;
+                segment.last = entry.timestamp();
+            }
+            _ => {
+                let file = fs::append(our, None, line)?;
+                self.segments.push(Segment {
+                    file,
+                    entries: 1,
+                    last: entry.timestamp(),
+                });
+                changed = true;
+            }
+        }
+
+        let cutoff = entry.timestamp().saturating_sub(retention);
+        let mut total: usize = self.segments.iter().map(|segment| segment.entries).sum();
+        while self.segments.len() > 1
+            && (self.segments[0].last < cutoff || total - self.segments[0].entries >= max_entries)
+        {
+            let segment = self.segments.remove(0);
+            total -= segment.entries;
+            fs::delete(our, segment.file)?;
+            changed = true;
+        }
+        Ok(changed)
+    }
+
+    /// Every entry in the log at or after `since`, oldest first.
+    pub fn entries(&mut self, our: &Address, since: u64) -> anyhow::Result<Vec<LogEntry>> {
+        self.count(our)?;
+        let mut entries = vec![];
+        for segment in self.segments.iter().filter(|segment| segment.last >= since) {
+            entries.extend(
+                read_segment(our, segment.file)?
+                    .into_iter()
+                    .filter(|entry| entry.timestamp() >= since),
+            );
+        }
+        Ok(entries)
+    }
+}
+
+fn read_segment(our: &Address, file: u128) -> anyhow::Result<Vec<LogEntry>> {
+    let bytes = fs::read(our, file)?;
+    Ok(bytes
+        .split(|&byte| byte == b'\n')
+        // a line cut short by a failed append is skipped
+        .filter_map(|line| serde_json::from_slice(line).ok())
+        .collect())
+}
+
+/// Aggregates log entries into the most frequent queries, the most frequent
+/// queries without results, and how often results get clicked.
+pub fn analyze(entries: &[LogEntry], limit: usize) -> AnalyticsResponse {
+    let mut queries = 0;
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut zero_result_counts: HashMap<String, usize> = HashMap::new();
+    let mut clicks = 0;
+    let mut clicked_queries: HashSet<&str> = HashSet::new();
+    let mut logged_queries: HashSet<&str> = HashSet::new();
+    let mut document_clicks: HashMap<String, usize> = HashMap::new();
+    for entry in entries {
+        match entry {
+            LogEntry::Query {
+                id, text, results, ..
+            } => {
+                queries += 1;
+                logged_queries.insert(id);
+                // vector-only queries cannot be told apart, so they only count in totals
+                let Some(text) = text else {
+                    continue;
+                };
+                let text = text.trim().to_lowercase();
+                if results.is_empty() {
+                    *zero_result_counts.entry(text.clone()).or_default() += 1;
+                }
+                *counts.entry(text).or_default() += 1;
+            }
+            LogEntry::Click { query_id, id, .. } => {
+                clicks += 1;
+                *document_clicks.entry(id.clone()).or_default() += 1;
+                if let Some(query_id) = query_id {
+                    clicked_queries.insert(query_id);
+                }
+            }
+        }
+    }
+    // clicks on results of queries that already left the log do not count
+    let clicked = clicked_queries.intersection(&logged_queries).count();
+
+    AnalyticsResponse {
+        queries,
+        clicks,
+        click_through_rate: match queries {
+            0 => 0.0,
+            queries => clicked as f64 / queries as f64,
+        },
+        top_queries: top(counts, limit),
+        zero_result_queries: top(zero_result_counts, limit),
+        top_clicked: top(document_clicks, limit),
+    }
+}
+
+/// The `limit` most frequent keys, most frequent first.
+fn top(counts: HashMap<String, usize>, limit: usize) -> Vec<Count> {
+    let mut counts: Vec<Count> = counts
+        .into_iter()
+        .map(|(key, count)| Count { key, count })
+        .collect();
+    counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.key.cmp(&b.key)));
+    counts.truncate(limit);
+    counts
+}
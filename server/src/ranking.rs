@@ -0,0 +1,359 @@
+use process_lib::http;
+use process_lib::protocol::{Match, RerankerKind};
+use process_lib::types::*;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+
+use super::dedup::cosine_similarity;
+
+const RERANKER_TIMEOUT: u64 = 30;
+/// documents without a chunk passage are re-scored on this much of their article
+const MAX_PASSAGE_CHARS: usize = 2000;
+
+/// Selects `k` of `candidates` by maximal marginal relevance: each pick maximizes
+/// `lambda * relevance - (1 - lambda) * similarity to the closest earlier pick`.
+/// `lambda` of 1 is plain relevance order, lower values favor diversity.
+/// Candidates need their `values`; those without fall back to their score and
+/// are never considered redundant.
+pub fn mmr(query: &[f32], candidates: Vec<Match>, lambda: f32, k: usize) -> Vec<Match> {
+    let lambda = lambda.clamp(0.0, 1.0);
+    let relevance: Vec<f32> = candidates
+        .iter()
+        .map(|m| {
+            m.values
+                .as_deref()
+                .map_or(m.score, |values| cosine_similarity(query, values))
+        })
+        .collect();
+    let similarity = |i: usize, j: usize| match (&candidates[i].values, &candidates[j].values) {
+        (Some(a), Some(b)) => cosine_similarity(a, b),
+        _ => 0.0,
+    };
+
+    let mut remaining: Vec<usize> = (0..candidates.len()).collect();
+    let mut selected: Vec<usize> = vec![];
+    while selected.len() < k && !remaining.is_empty() {
+        let (best, _) = remaining
+            .iter()
+            .enumerate()
+            .map(|(position, &i)| {
+                let redundancy = selected
+                    .iter()
+                    .map(|&j| similarity(i, j))
+                    .fold(0.0, f32::max);
+                (
+                    position,
+                    lambda * relevance[i] - (1.0 - lambda) * redundancy,
+                )
+            })
+            // on ties, prefer the candidate that ranked higher to begin with
+            .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)))
+            .unwrap();
+        selected.push(remaining.remove(best));
+    }
+
+    let mut candidates: Vec<Option<Match>> = candidates.into_iter().map(Some).collect();
+    selected
+        .into_iter()
+        .filter_map(|i| candidates[i].take())
+        .collect()
+}
+
+/// Scores how well each passage answers the query; higher is better.
+pub trait Reranker {
+    fn score(&self, query: &str, passages: &[String]) -> anyhow::Result<Vec<f32>>;
+}
+
+/// The re-ranker of `kind`; the HTTP one needs the url of its endpoint.
+pub fn reranker<'a>(
+    our: &'a Address,
+    kind: RerankerKind,
+    uri: Option<&'a str>,
+) -> anyhow::Result<Box<dyn Reranker + 'a>> {
+    match (kind, uri) {
+        (RerankerKind::Http, Some(uri)) => Ok(Box::new(HttpReranker { our, uri })),
+        (RerankerKind::Http, None) => Err(anyhow::anyhow!(
+            "no HTTP re-ranker is configured; set its url with `reranker <url>` in the server's terminal"
+        )),
+        (RerankerKind::Lexical, _) => Ok(Box::new(LexicalReranker)),
+    }
+}
+
+/// Re-scores `matches` with `reranker` and sorts them by the new score.
+pub fn rerank(
+    reranker: &dyn Reranker,
+    query: &str,
+    mut matches: Vec<Match>,
+) -> anyhow::Result<Vec<Match>> {
+    if matches.is_empty() {
+        return Ok(matches);
+    }
+    let passages: Vec<String> = matches.iter().map(passage_text).collect();
+    let scores = reranker.score(query, &passages)?;
+    if scores.len() != matches.len() {
+        return Err(anyhow::anyhow!(
+            "re-ranker returned {} scores for {} passages",
+            scores.len(),
+            matches.len()
+        ));
+    }
+    for (m, score) in matches.iter_mut().zip(scores) {
+        m.rerank_score = Some(score);
+    }
+    matches.sort_by(|a, b| b.rerank_score.unwrap().total_cmp(&a.rerank_score.unwrap()));
+    Ok(matches)
+}
+
+/// The text a match is re-scored on: its best passage, or else the start of its article.
+fn passage_text(m: &Match) -> String {
+    if let Some(passage) = &m.passage {
+        return passage.text.clone();
+    }
+    let field = |key: &str| {
+        m.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(key))
+            .and_then(|value| value.as_str())
+            .unwrap_or_default()
+    };
+    let article = field("article");
+    let end = article
+        .char_indices()
+        .nth(MAX_PASSAGE_CHARS)
+        .map_or(article.len(), |(i, _)| i);
+    format!("{}\n{}", field("title"), &article[..end])
+        .trim()
+        .to_string()
+}
+
+/// A text-embeddings-inference style `/rerank` endpoint serving a cross-encoder.
+pub struct HttpReranker<'a> {
+    our: &'a Address,
+    uri: &'a str,
+}
+
+#[derive(Deserialize)]
+struct RerankedPassage {
+    index: usize,
+    score: f32,
+}
+
+impl Reranker for HttpReranker<'_> {
+    fn score(&self, query: &str, passages: &[String]) -> anyhow::Result<Vec<f32>> {
+        // the service answers in its own order of relevance
+        let ranked: Vec<RerankedPassage> = http::Request::post(self.uri)
+            .json(&json!({
+                "query": query,
+                "texts": passages,
+            }))?
+            .timeout(RERANKER_TIMEOUT)
+            .send(self.our)?
+            .error_for_status()?
+            .json()?;
+        let mut scores = vec![f32::NEG_INFINITY; passages.len()];
+        for passage in ranked {
+            *scores.get_mut(passage.index).ok_or(anyhow::anyhow!(
+                "re-ranker returned unknown index {}",
+                passage.index
+            ))? = passage.score;
+        }
+        Ok(scores)
+    }
+}
+
+/// Okapi BM25 over the candidate passages themselves. Much weaker than a
+/// cross-encoder, but rewards exact term matches the embedding may have missed.
+pub struct LexicalReranker;
+
+const BM25_K1: f32 = 1.2;
+const BM25_B: f32 = 0.75;
+
+fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+        .collect()
+}
+
+impl Reranker for LexicalReranker {
+    fn score(&self, query: &str, passages: &[String]) -> anyhow::Result<Vec<f32>> {
+        let query_terms: HashSet<String> = terms(query).into_iter().collect();
+        let documents: Vec<Vec<String>> = passages.iter().map(|p| terms(p)).collect();
+        let count = documents.len() as f32;
+        let average_length = documents.iter().map(|d| d.len()).sum::<usize>() as f32 / count;
+
+        let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+        for document in &documents {
+            let unique: HashSet<&str> = document.iter().map(|term| term.as_str()).collect();
+            for term in unique {
+                *document_frequency.entry(term).or_default() += 1;
+            }
+        }
+
+        Ok(documents
+            .iter()
+            .map(|document| {
+                let length_norm =
+                    1.0 - BM25_B + BM25_B * document.len() as f32 / average_length.max(1.0);
+                query_terms
+                    .iter()
+                    .map(|term| {
+                        let frequency = document.iter().filter(|t| *t == term).count() as f32;
+                        if frequency == 0.0 {
+                            return 0.0;
+                        }
+                        let n = document_frequency[term.as_str()] as f32;
+                        let idf = ((count - n + 0.5) / (n + 0.5) + 1.0).ln();
+                        idf * frequency * (BM25_K1 + 1.0) / (frequency + BM25_K1 * length_norm)
+                    })
+                    .sum()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(id: &str, score: f32, values: Option<Vec<f32>>) -> Match {
+        Match {
+            id: id.to_string(),
+            score,
+            values,
+            metadata: None,
+            passage: None,
+            also_published_in: vec![],
+            rerank_score: None,
+        }
+    }
+
+    /// `close` is nearly `best` again, `other` is less relevant but different
+    fn candidates() -> Vec<Match> {
+        vec![
+            candidate("best", 0.0, Some(vec![1.0, 0.0])),
+            candidate("close", 0.0, Some(vec![0.99, 0.1])),
+            candidate("other", 0.0, Some(vec![0.8, 0.6])),
+        ]
+    }
+
+    fn ids(matches: &[Match]) -> Vec<&str> {
+        matches.iter().map(|m| m.id.as_str()).collect()
+    }
+
+    const QUERY: [f32; 2] = [1.0, 0.0];
+
+    #[test]
+    fn lambda_of_one_keeps_relevance_order() {
+        let picked = mmr(&QUERY, candidates(), 1.0, 3);
+        assert_eq!(ids(&picked), vec!["best", "close", "other"]);
+    }
+
+    #[test]
+    fn lower_lambda_favors_diversity() {
+        let picked = mmr(&QUERY, candidates(), 0.3, 3);
+        assert_eq!(ids(&picked), vec!["best", "other", "close"]);
+        // out of range lambdas are clamped, so all diversity after the first pick
+        let picked = mmr(&QUERY, candidates(), -2.0, 3);
+        assert_eq!(ids(&picked), vec!["best", "other", "close"]);
+    }
+
+    #[test]
+    fn lambda_of_zero_starts_from_the_top_ranked_candidate() {
+        let mut reversed = candidates();
+        reversed.reverse();
+        let picked = mmr(&QUERY, reversed, 0.0, 2);
+        assert_eq!(ids(&picked), vec!["other", "best"]);
+    }
+
+    #[test]
+    fn picks_at_most_the_candidates_there_are() {
+        assert_eq!(mmr(&QUERY, candidates(), 0.5, 10).len(), 3);
+        assert!(mmr(&QUERY, candidates(), 0.5, 0).is_empty());
+        assert!(mmr(&QUERY, vec![], 0.5, 3).is_empty());
+    }
+
+    #[test]
+    fn candidates_without_values_keep_their_score() {
+        let picked = mmr(
+            &QUERY,
+            vec![
+                candidate("low", 0.2, None),
+                candidate("high", 0.9, None),
+                candidate("vector", 0.0, Some(vec![0.6, 0.8])),
+            ],
+            0.5,
+            3,
+        );
+        assert_eq!(ids(&picked), vec!["high", "vector", "low"]);
+    }
+
+    /// answers with the same scores whatever it is asked
+    struct Fixed(Vec<f32>);
+
+    impl Reranker for Fixed {
+        fn score(&self, _query: &str, _passages: &[String]) -> anyhow::Result<Vec<f32>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn article(value: serde_json::Value) -> Match {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn reranking_sorts_by_the_new_score_and_keeps_the_first() {
+        let reranked = rerank(&Fixed(vec![0.1, 0.9, 0.5]), "query", candidates()).unwrap();
+        assert_eq!(ids(&reranked), vec!["close", "other", "best"]);
+        assert_eq!(reranked[0].rerank_score, Some(0.9));
+        assert_eq!(reranked[0].score, 0.0);
+    }
+
+    #[test]
+    fn rejects_a_score_per_passage_mismatch() {
+        let e = rerank(&Fixed(vec![0.1]), "query", candidates()).unwrap_err();
+        assert_eq!(e.to_string(), "re-ranker returned 1 scores for 3 passages");
+        // nothing to re-rank, so the re-ranker is not asked
+        assert!(rerank(&Fixed(vec![0.1]), "query", vec![])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn rescores_the_passage_or_else_the_start_of_the_article() {
+        let chunked = article(json!({
+            "id": "chunked",
+            "score": 0.5,
+            "metadata": { "title": "Bread", "article": "Knead the dough." },
+            "passage": { "text": "the dough", "chunk_index": 1, "start": 9, "end": 18 },
+        }));
+        assert_eq!(passage_text(&chunked), "the dough");
+
+        let whole = article(json!({
+            "id": "whole",
+            "score": 0.5,
+            "metadata": { "title": "Bread", "article": "é".repeat(MAX_PASSAGE_CHARS + 10) },
+        }));
+        let text = passage_text(&whole);
+        assert!(text.starts_with("Bread\né"));
+        assert_eq!(text.chars().count(), "Bread\n".len() + MAX_PASSAGE_CHARS);
+
+        let untitled = article(json!({ "id": "bare", "score": 0.5 }));
+        assert_eq!(passage_text(&untitled), "");
+    }
+
+    #[test]
+    fn bm25_rewards_rare_query_terms_in_short_passages() {
+        let passages = [
+            "Sourdough bread",
+            "bread, butter and jam and a pot of tea",
+            "the borrow checker",
+        ]
+        .map(String::from);
+        let scores = LexicalReranker.score("sourdough BREAD", &passages).unwrap();
+        assert!(scores[0] > scores[1], "{:?}", scores);
+        assert!(scores[1] > 0.0);
+        assert_eq!(scores[2], 0.0);
+    }
+}
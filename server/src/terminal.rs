@@ -0,0 +1,282 @@
+use process_lib::protocol::{
+    AnalyticsResponse, FetchResponse, QueryResponse, ServerRequest, StatsResponse,
+};
+use serde_json::json;
+
+use super::now;
+
+pub const HELP: &str = "commands:
+    search <text>     query the library
+    show <id>         print a document
+    stats             summarize the library
+    collections       list collections and their sizes
+    delete <id>       remove a document
+    querylog on [days]
+                      log queries and clicks, keeping them for 30 or [days] days
+    querylog off      stop logging and delete the log
+    analytics [days]  summarize the query log, over the last [days] days
+    reranker <url>    re-rank with the cross-encoder served at <url>
+    reranker off      forget the re-ranker's url
+    admins <node>...  let these nodes change the library too
+    admins none       let only this node change the library";
+
+/// A command typed into the terminal.
+pub enum Command {
+    Search(String),
+    Show(String),
+    Stats,
+    Collections,
+    Delete(String),
+    /// with the retention in days, if not the default
+    QueryLogOn(Option<u64>),
+    QueryLogOff,
+    Analytics(Option<u64>),
+    /// with the url of the re-ranker, or none to unset it
+    Reranker(Option<String>),
+    /// the nodes to make admins, replacing the current ones
+    Admins(Vec<String>),
+}
+
+impl Command {
+    pub fn parse(line: &str) -> anyhow::Result<Self> {
+        let line = line.trim();
+        let (name, argument) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let argument = argument.trim();
+        let command = match name {
+            "search" => Command::Search(argument.to_string()),
+            "show" => Command::Show(argument.to_string()),
+            "stats" => Command::Stats,
+            "collections" => Command::Collections,
+            "delete" => Command::Delete(argument.to_string()),
+            "querylog" => match argument.split_whitespace().collect::<Vec<_>>()[..] {
+                ["on"] => Command::QueryLogOn(None),
+                ["on", days] => Command::QueryLogOn(Some(days.parse()?)),
+                ["off"] => Command::QueryLogOff,
+                _ => return Err(anyhow::anyhow!("usage: querylog on [days] | off")),
+            },
+            "analytics" => Command::Analytics(match argument {
+                "" => None,
+                days => Some(days.parse()?),
+            }),
+            "reranker" => match argument {
+                "" => return Err(anyhow::anyhow!("usage: reranker <url> | off")),
+                "off" => Command::Reranker(None),
+                url => Command::Reranker(Some(url.to_string())),
+            },
+            "admins" => match argument {
+                "" => return Err(anyhow::anyhow!("usage: admins <node>... | none")),
+                "none" => Command::Admins(vec![]),
+                nodes => Command::Admins(nodes.split_whitespace().map(str::to_string).collect()),
+            },
+            _ => return Err(anyhow::anyhow!("unknown command {:?}\n{}", name, HELP)),
+        };
+        match &command {
+            Command::Search(argument) | Command::Show(argument) | Command::Delete(argument)
+                if argument.is_empty() =>
+            {
+                Err(anyhow::anyhow!("{} needs an argument\n{}", name, HELP))
+            }
+            _ => Ok(command),
+        }
+    }
+
+    /// The request the command stands for.
+    pub fn request(&self) -> anyhow::Result<ServerRequest> {
+        let request = match self {
+            Command::Search(text) => json!({ "Query": { "text": text, "includeMetadata": true } }),
+            Command::Show(id) => json!({ "Fetch": { "ids": [id] } }),
+            Command::Stats | Command::Collections => json!("Stats"),
+            Command::Delete(id) => json!({ "Delete": { "ids": [id] } }),
+            Command::QueryLogOn(None) => json!({ "ConfigureQueryLog": {} }),
+            Command::QueryLogOn(Some(days)) => {
+                json!({ "ConfigureQueryLog": { "retentionDays": days } })
+            }
+            Command::QueryLogOff => json!({ "ConfigureQueryLog": null }),
+            Command::Analytics(days) => json!({ "Analytics": { "days": days } }),
+            Command::Reranker(uri) => json!({ "ConfigureReranker": uri }),
+            Command::Admins(nodes) => json!({ "ConfigureAdmins": nodes }),
+        };
+        Ok(serde_json::from_value(request)?)
+    }
+
+    /// Formats the answer to [`Command::request`] for the terminal.
+    pub fn format(&self, res: serde_json::Value) -> anyhow::Result<String> {
+        let lines: Vec<String> = match self {
+            Command::Search(_) => {
+                let res: QueryResponse = serde_json::from_value(res)?;
+                if res.matches.is_empty() {
+                    return Ok("no results".to_string());
+                }
+                res.matches
+                    .iter()
+                    .enumerate()
+                    .map(|(i, m)| {
+                        let field = |key: &str| {
+                            m.metadata
+                                .as_ref()
+                                .and_then(|metadata| metadata.get(key))
+                                .and_then(|value| value.as_str())
+                                .unwrap_or_default()
+                        };
+                        let title = match field("title") {
+                            "" => m.id.as_str(),
+                            title => title,
+                        };
+                        format!(
+                            "{:>2}. {:.3}  {}  {}\n      {}",
+                            i + 1,
+                            m.rerank_score.unwrap_or(m.score),
+                            title,
+                            field("publication"),
+                            m.id
+                        )
+                    })
+                    .collect()
+            }
+            Command::Show(id) => {
+                let res: FetchResponse = serde_json::from_value(res)?;
+                let Some(document) = res.documents.first() else {
+                    return Ok(format!("no document {}", id));
+                };
+                let mut lines: Vec<String> = document
+                    .metadata
+                    .iter()
+                    .map(|(key, value)| match value.as_str() {
+                        Some(value) => format!("{}: {}", key, value),
+                        None => format!("{}: {}", key, value),
+                    })
+                    .collect();
+                lines.push(String::new());
+                lines.push(document.text.clone());
+                lines
+            }
+            Command::Stats => {
+                let res: StatsResponse = serde_json::from_value(res)?;
+                let latency = match (
+                    res.query_latency.p50_ms,
+                    res.query_latency.p90_ms,
+                    res.query_latency.p99_ms,
+                ) {
+                    (Some(p50), Some(p90), Some(p99)) => format!(
+                        "{:.0}/{:.0}/{:.0} ms (p50/p90/p99 over {} queries)",
+                        p50, p90, p99, res.query_latency.count
+                    ),
+                    _ => "no queries yet".to_string(),
+                };
+                vec![
+                    format!(
+                        "documents: {} ({} deleted, awaiting compaction)",
+                        res.documents, res.deleted
+                    ),
+                    format!(
+                        "vectors: {} ({:.1}% of the index's capacity)",
+                        res.vectors,
+                        res.fullness * 100.0
+                    ),
+                    format!("collections: {}", res.collections.len()),
+                    format!("query latency: {}", latency),
+                    format!("generation: {}", res.generation),
+                ]
+            }
+            Command::Collections => {
+                let res: StatsResponse = serde_json::from_value(res)?;
+                if res.collections.is_empty() {
+                    return Ok("no collections".to_string());
+                }
+                res.collections
+                    .iter()
+                    .map(|(name, collection)| {
+                        [
+                            name.clone(),
+                            format!(
+                                "    {} documents, {} vectors",
+                                collection.documents, collection.vectors
+                            ),
+                            format!(
+                                "    dimension {}, {} metric, {} index",
+                                collection.dimension,
+                                collection.metric.as_deref().unwrap_or("unknown"),
+                                collection.index_type.as_deref().unwrap_or("unknown"),
+                            ),
+                            format!(
+                                "    {} KiB of vectors, {} KiB of documents",
+                                collection.memory_bytes / 1024,
+                                collection.disk_bytes / 1024
+                            ),
+                            format!(
+                                "    last ingest {}",
+                                collection.last_ingest.map_or("never".to_string(), ago)
+                            ),
+                        ]
+                        .join("\n")
+                    })
+                    .collect()
+            }
+            Command::Delete(id) => {
+                let deleted = res["deleted"].as_array().map_or(0, |ids| ids.len());
+                vec![match deleted {
+                    0 => format!("{} was already deleted", id),
+                    _ => format!("deleted {}", id),
+                }]
+            }
+            Command::QueryLogOn(_) | Command::QueryLogOff => vec![match &res["config"] {
+                serde_json::Value::Null => "query log off".to_string(),
+                config => format!(
+                    "query log on, keeping {} days and at most {} entries",
+                    config["retentionDays"], config["maxEntries"]
+                ),
+            }],
+            Command::Reranker(_) => vec![match res["uri"].as_str() {
+                Some(uri) => format!("re-ranking with {}", uri),
+                None => "no HTTP re-ranker".to_string(),
+            }],
+            Command::Admins(_) => vec![match res["admins"].as_array() {
+                Some(admins) if !admins.is_empty() => format!(
+                    "admins: {}",
+                    admins
+                        .iter()
+                        .filter_map(|admin| admin.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                _ => "only this node may change the library".to_string(),
+            }],
+            Command::Analytics(_) => {
+                let res: AnalyticsResponse = serde_json::from_value(res)?;
+                let mut lines = vec![
+                    format!("queries: {}", res.queries),
+                    format!(
+                        "clicks: {} ({:.1}% of queries had a result clicked)",
+                        res.clicks,
+                        res.click_through_rate * 100.0
+                    ),
+                ];
+                for (title, counts) in [
+                    ("top queries", &res.top_queries),
+                    ("queries without results", &res.zero_result_queries),
+                    ("most clicked", &res.top_clicked),
+                ] {
+                    lines.push(format!("{}:", title));
+                    lines.extend(
+                        counts
+                            .iter()
+                            .map(|count| format!("    {:>5}  {}", count.count, count.key)),
+                    );
+                }
+                lines
+            }
+        };
+        Ok(lines.join("\n"))
+    }
+}
+
+/// How long ago a time in seconds since the epoch was, roughly.
+fn ago(time: u64) -> String {
+    let seconds = now().saturating_sub(time);
+    match seconds {
+        0..=119 => format!("{}s ago", seconds),
+        120..=7199 => format!("{}m ago", seconds / 60),
+        7200..=172_799 => format!("{}h ago", seconds / 3600),
+        _ => format!("{}d ago", seconds / 86400),
+    }
+}
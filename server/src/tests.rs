@@ -0,0 +1,1103 @@
+//! The server run against process_lib's mock kernel.
+
+use process_lib::fs::FsAction;
+use process_lib::handler::{Effect, Handler};
+use process_lib::mock::{self, MockKernel, SentResponse};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::mock_pinecone::{Fault, MockPinecone};
+use super::*;
+
+/// of the stand-in embeddings
+const DIMENSION: usize = 16;
+
+fn our() -> Address {
+    "drew.uq@server:librarian:drew.uq".parse().unwrap()
+}
+
+/// The librarian on our own node.
+fn client() -> Address {
+    "drew.uq@librarian:librarian:drew.uq".parse().unwrap()
+}
+
+/// The librarian on someone else's node.
+fn reader() -> Address {
+    "reader.uq@librarian:librarian:drew.uq".parse().unwrap()
+}
+
+fn terminal() -> Address {
+    "drew.uq@terminal:terminal:uqbar".parse().unwrap()
+}
+
+/// The ipc and JSON body of a response to the client.
+fn answer(response: &SentResponse) -> (Value, Value) {
+    (
+        serde_json::from_str(response.response.ipc.as_deref().unwrap()).unwrap(),
+        serde_json::from_slice(&response.payload.as_ref().unwrap().bytes).unwrap(),
+    )
+}
+
+fn run_server(kernel: MockKernel) -> MockKernel {
+    kernel.run(|| run(our()))
+}
+
+/// Stands in for the embedding service: words are hashed into buckets, so
+/// texts sharing words get similar vectors.
+fn embed(text: &str) -> Vec<f32> {
+    let mut vector = vec![0.0; DIMENSION];
+    for word in text.split_whitespace() {
+        let mut hasher = DefaultHasher::new();
+        word.to_lowercase().hash(&mut hasher);
+        vector[hasher.finish() as usize % DIMENSION] += 1.0;
+    }
+    vector
+}
+
+/// Answers the server's HTTP requests: Pinecone's from `pinecone`, and
+/// embedding requests with [`embed`].
+fn serve_http(kernel: &mut MockKernel, pinecone: &MockPinecone) {
+    serve_pages(kernel, pinecone, vec![]);
+}
+
+/// The status, headers and body of a web page.
+type Page = (u16, Value, &'static str);
+
+/// Answers the server's HTTP requests as [`serve_http`] does, and requests
+/// for the urls of `pages` with those pages.
+fn serve_pages(kernel: &mut MockKernel, pinecone: &MockPinecone, pages: Vec<(&str, Page)>) {
+    let pinecone = pinecone.clone();
+    let pages: HashMap<String, Page> = pages
+        .into_iter()
+        .map(|(url, page)| (url.to_string(), page))
+        .collect();
+    kernel.on_request("http_client:sys:uqbar", move |_, request, payload| {
+        let ipc: Value = serde_json::from_str(request.ipc.as_deref().unwrap()).unwrap();
+        let uri = ipc["uri"].as_str().unwrap();
+        if let Some((status, headers, body)) = pages.get(uri) {
+            return mock::reply(
+                &json!({ "status": status, "headers": headers }).to_string(),
+                Some(Payload {
+                    mime: None,
+                    bytes: body.as_bytes().to_vec(),
+                }),
+            );
+        }
+        if MockPinecone::serves(uri) {
+            return pinecone.answer(request, payload);
+        }
+        let body: Value = serde_json::from_slice(&payload.unwrap().bytes).unwrap();
+        let vectors: Vec<Vec<f32>> = body["inputs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|text| embed(text.as_str().unwrap()))
+            .collect();
+        mock::reply(
+            &json!({ "status": 200, "headers": {} }).to_string(),
+            Some(Payload {
+                mime: None,
+                bytes: json!(vectors).to_string().into_bytes(),
+            }),
+        )
+    });
+}
+
+/// Files by uuid, kept across runs of the server.
+type Files = Rc<RefCell<HashMap<u128, Vec<u8>>>>;
+
+/// Stands in for the filesystem, keeping `files` in memory.
+fn serve_fs(kernel: &mut MockKernel, files: &Files) {
+    let files = files.clone();
+    kernel.on_request("filesystem:sys:uqbar", move |_, request, payload| {
+        let mut files = files.borrow_mut();
+        let (res, payload) = match serde_json::from_str(request.ipc.as_deref().unwrap()).unwrap() {
+            FsAction::Append(file) => {
+                let file = file.unwrap_or_else(|| files.keys().max().map_or(1, |file| file + 1));
+                files
+                    .entry(file)
+                    .or_default()
+                    .extend(&payload.unwrap().bytes);
+                (json!({ "Append": file as u64 }), None)
+            }
+            FsAction::Read(file) => (
+                json!({ "Read": file as u64 }),
+                Some(Payload {
+                    mime: None,
+                    bytes: files[&file].clone(),
+                }),
+            ),
+            FsAction::Delete(file) => {
+                files.remove(&file);
+                (json!({ "Delete": file as u64 }), None)
+            }
+            action => panic!("unexpected filesystem action {:?}", action),
+        };
+        mock::reply(&json!({ "Ok": res }).to_string(), payload)
+    });
+}
+
+fn push_ingest(kernel: &mut MockKernel) {
+    kernel.push_request(
+        &client(),
+        &json!({ "Ingest": { "documents": [
+            { "id": "rust", "text": "the rust borrow checker rejects dangling references" },
+            { "id": "bread", "text": "knead the dough and let the bread rise overnight" },
+        ] } })
+        .to_string(),
+        None,
+    );
+}
+
+fn push_query(kernel: &mut MockKernel, text: &str) {
+    kernel.push_request(
+        &client(),
+        &json!({ "Query": { "vector": embed(text), "topK": 1 } }).to_string(),
+        None,
+    );
+}
+
+#[test]
+fn answers_pings() {
+    let mut kernel = MockKernel::new(our());
+    kernel.push_request(&client(), "\"Ping\"", None);
+    let kernel = run_server(kernel);
+
+    assert_eq!(kernel.responses.len(), 1);
+    assert_eq!(answer(&kernel.responses[0]), (json!("Ok"), json!({})));
+    assert!(kernel.requests.is_empty());
+}
+
+#[test]
+fn reports_malformed_requests() {
+    let mut kernel = MockKernel::new(our());
+    kernel.push_request(&client(), "{\"Explode\": {}}", None);
+    let kernel = run_server(kernel);
+
+    let (ipc, body) = answer(&kernel.responses[0]);
+    assert!(ipc["Err"].is_string());
+    assert_eq!(body["error"], ipc["Err"]);
+}
+
+#[test]
+fn fetches_documents_it_did_not_ingest_from_pinecone() {
+    let mut kernel = MockKernel::new(our());
+    kernel.on_request("http_client:sys:uqbar", |_, request, _| {
+        let ipc: Value = serde_json::from_str(request.ipc.as_deref().unwrap()).unwrap();
+        assert_eq!(ipc["method"], "GET");
+        assert!(ipc["uri"]
+            .as_str()
+            .unwrap()
+            .ends_with("/vectors/fetch?ids=a%20b&ids=gone&namespace=default"));
+        mock::reply(
+            &json!({ "status": 200, "headers": {} }).to_string(),
+            Some(Payload {
+                mime: None,
+                bytes: json!({ "vectors": { "a b": {
+                    "id": "a b",
+                    "values": [0.5],
+                    "metadata": { "title": "A", "article": "the text" },
+                } } })
+                .to_string()
+                .into_bytes(),
+            }),
+        )
+    });
+    kernel.push_request(
+        &client(),
+        "{\"Fetch\": {\"ids\": [\"a b\", \"gone\"]}}",
+        None,
+    );
+    let kernel = run_server(kernel);
+
+    assert_eq!(kernel.requests_to("http_client:sys:uqbar").len(), 1);
+    let (ipc, body) = answer(&kernel.responses[0]);
+    assert_eq!(ipc, json!("Ok"));
+    assert_eq!(body["documents"][0]["id"], "a b");
+    assert_eq!(body["documents"][0]["text"], "the text");
+    assert_eq!(body["documents"][0]["metadata"], json!({ "title": "A" }));
+}
+
+#[test]
+fn reports_pinecone_errors_to_the_client() {
+    let mut kernel = MockKernel::new(our());
+    kernel.on_request("http_client:sys:uqbar", |_, _, _| {
+        Err(SendErrorKind::Timeout)
+    });
+    kernel.push_request(&client(), "{\"Fetch\": {\"ids\": [\"a\"]}}", None);
+    let kernel = run_server(kernel);
+
+    let (ipc, _) = answer(&kernel.responses[0]);
+    assert!(ipc["Err"].as_str().unwrap().contains("Timeout"));
+}
+
+#[test]
+fn takes_commands_from_the_terminal() {
+    let mut kernel = MockKernel::new(our());
+    kernel.push_request(&terminal(), "help", None);
+    let kernel = run_server(kernel);
+
+    assert!(kernel.printed.contains(&terminal::HELP.to_string()));
+    assert!(kernel.responses.is_empty());
+}
+
+#[test]
+fn ignores_other_processes() {
+    let mut kernel = MockKernel::new(our());
+    kernel.push_request(
+        &"drew.uq@stranger:stranger:drew.uq".parse().unwrap(),
+        "\"Ping\"",
+        None,
+    );
+    let kernel = run_server(kernel);
+
+    assert!(kernel.responses.is_empty());
+}
+
+#[test]
+fn ingests_and_queries_documents() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_ingest(&mut kernel);
+    kernel.push_request(
+        &client(),
+        &json!({ "Query": { "text": "borrow checker", "topK": 1 } }).to_string(),
+        None,
+    );
+    kernel.push_request(&client(), "\"Stats\"", None);
+    let kernel = run_server(kernel);
+
+    assert_eq!(pinecone.ids("default"), vec!["bread#0", "rust#0"]);
+    let (ipc, body) = answer(&kernel.responses[1]);
+    assert_eq!(ipc, json!("Ok"));
+    assert_eq!(body["matches"].as_array().unwrap().len(), 1);
+    assert_eq!(body["matches"][0]["id"], "rust");
+
+    let (_, stats) = answer(&kernel.responses[2]);
+    assert_eq!(stats["vectors"], 2);
+    assert_eq!(stats["collections"]["default"]["documents"], 2);
+    assert_eq!(stats["collections"]["default"]["metric"], "cosine");
+    assert_eq!(stats["query_latency"]["count"], 1);
+}
+
+#[test]
+fn hides_deleted_documents_until_compaction() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_ingest(&mut kernel);
+    kernel.push_request(&client(), "{\"Delete\": {\"ids\": [\"rust\"]}}", None);
+    push_query(&mut kernel, "the rust borrow checker");
+    let kernel = run_server(kernel);
+
+    let (_, body) = answer(&kernel.responses[2]);
+    assert_eq!(body["matches"][0]["id"], "bread");
+    assert!(pinecone.ids("default").contains(&"rust#0".to_string()));
+}
+
+#[test]
+fn reports_pinecone_error_statuses() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    pinecone.fail_next(Fault::Status(503));
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_query(&mut kernel, "anything");
+    let kernel = run_server(kernel);
+
+    let (ipc, _) = answer(&kernel.responses[0]);
+    assert!(ipc["Err"].as_str().unwrap().contains("returned 503"));
+}
+
+#[test]
+fn waits_for_slow_answers_until_the_timeout() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    pinecone.upsert("default", "a", embed("anything"), json!({}));
+    pinecone.fail_next(Fault::Latency(Duration::from_millis(20)));
+    pinecone.fail_next(Fault::Latency(Duration::from_secs(60)));
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_query(&mut kernel, "anything");
+    // a different query, as the same one would be answered from the result cache
+    push_query(&mut kernel, "anything else");
+    let kernel = run_server(kernel);
+
+    let (_, body) = answer(&kernel.responses[0]);
+    assert_eq!(body["matches"][0]["id"], "a");
+    let (ipc, _) = answer(&kernel.responses[1]);
+    assert!(ipc["Err"].as_str().unwrap().contains("Timeout"));
+}
+
+#[test]
+fn reports_malformed_pinecone_responses() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    pinecone.fail_next(Fault::Malformed);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_query(&mut kernel, "anything");
+    let kernel = run_server(kernel);
+
+    let (ipc, _) = answer(&kernel.responses[0]);
+    assert!(ipc["Err"].as_str().unwrap().contains("bad JSON"));
+    assert_eq!(pinecone.calls(), vec!["POST /query"]);
+}
+
+#[test]
+fn reports_vectors_of_the_wrong_dimension() {
+    let pinecone = MockPinecone::new(DIMENSION + 1);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_ingest(&mut kernel);
+    let kernel = run_server(kernel);
+
+    let (ipc, _) = answer(&kernel.responses[0]);
+    assert!(ipc["Err"].as_str().unwrap().contains("returned 400"));
+    assert!(pinecone.ids("default").is_empty());
+}
+
+#[test]
+fn handles_messages_without_side_effects() {
+    // no kernel is running, so any side effect would panic
+    let mut server = Server {
+        our: our(),
+        state: State::default(),
+        memory: Memory::default(),
+    };
+    let effects = server.handle(
+        &client(),
+        Message::Request(Request {
+            inherit: false,
+            expects_response: Some(5),
+            ipc: Some("\"Ping\"".to_string()),
+            metadata: None,
+        }),
+        None,
+    );
+
+    let Some(Effect::Respond { response, payload }) = effects.last() else {
+        panic!("expected a response, got {:?}", effects);
+    };
+    assert_eq!(response.ipc.as_deref(), Some("\"Ok\""));
+    assert_eq!(payload.as_ref().unwrap().bytes, b"{}");
+    assert!(!effects
+        .iter()
+        .any(|effect| matches!(effect, Effect::SetState(_))));
+}
+
+#[test]
+fn compacts_after_answering() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    pinecone.upsert("default", "old#0", embed("old news"), json!({}));
+    let mut kernel = MockKernel::new(our());
+    kernel.set_state(&State {
+        tombstones: HashMap::from([(
+            "old".to_string(),
+            Tombstone {
+                namespace: "default".to_string(),
+                vector_ids: vec!["old#0".to_string()],
+                deleted_at: 0,
+            },
+        )]),
+        ..Default::default()
+    });
+    serve_http(&mut kernel, &pinecone);
+    kernel.push_request(&client(), "\"Ping\"", None);
+    let kernel = run_server(kernel);
+
+    assert_eq!(answer(&kernel.responses[0]).0, json!("Ok"));
+    let compactions = kernel.requests_to(&our().to_string());
+    assert_eq!(compactions.len(), 1);
+    assert_eq!(compactions[0].request.ipc.as_deref(), Some(COMPACT_REQUEST));
+    assert_eq!(pinecone.calls(), vec!["POST /vectors/delete"]);
+    assert!(pinecone.ids("default").is_empty());
+    assert!(kernel.get_state::<State>().unwrap().tombstones.is_empty());
+}
+
+#[test]
+fn waits_before_compacting_again_after_a_failure() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    pinecone.upsert("default", "old#0", embed("old news"), json!({}));
+    pinecone.fail_next(Fault::Status(400));
+    let mut kernel = MockKernel::new(our());
+    kernel.set_state(&State {
+        tombstones: HashMap::from([(
+            "old".to_string(),
+            Tombstone {
+                namespace: "default".to_string(),
+                vector_ids: vec!["old#0".to_string()],
+                deleted_at: 0,
+            },
+        )]),
+        ..Default::default()
+    });
+    serve_http(&mut kernel, &pinecone);
+    kernel.push_request(&client(), "\"Ping\"", None);
+    kernel.push_request(&client(), "\"Ping\"", None);
+    let kernel = run_server(kernel);
+
+    assert_eq!(kernel.responses.len(), 2);
+    assert_eq!(kernel.requests_to(&our().to_string()).len(), 1);
+    assert_eq!(pinecone.calls(), vec!["POST /vectors/delete"]);
+    assert!(kernel
+        .printed
+        .iter()
+        .any(|line| line.starts_with("librarian server: compaction failed")));
+    assert_eq!(pinecone.ids("default"), vec!["old#0"]);
+    assert!(kernel
+        .get_state::<State>()
+        .unwrap()
+        .tombstones
+        .contains_key("old"));
+}
+
+fn push_ingest_url(kernel: &mut MockKernel, url: &str) {
+    kernel.push_request(
+        &client(),
+        &json!({ "IngestUrl": { "url": url } }).to_string(),
+        None,
+    );
+}
+
+fn html(head: &'static str) -> Page {
+    (
+        200,
+        json!({ "Content-Type": "text/html; charset=utf-8" }),
+        head,
+    )
+}
+
+#[test]
+fn ingests_one_document_per_canonical_url() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_pages(
+        &mut kernel,
+        &pinecone,
+        vec![
+            (
+                "https://news.example/story?utm_source=feed",
+                html(
+                    "<head><link rel=canonical href=/story></head>\
+                     <article><p>The council voted to expand the bike lanes.</p></article>",
+                ),
+            ),
+            (
+                "https://m.news.example/story",
+                html(
+                    "<head><meta property=og:url content=https://news.example/story></head>\
+                     <article><p>The council voted to expand the bike lanes.</p></article>",
+                ),
+            ),
+        ],
+    );
+    push_ingest_url(&mut kernel, "https://news.example/story?utm_source=feed");
+    push_ingest_url(&mut kernel, "https://m.news.example/story");
+    // known by its canonical url, so not even fetched
+    push_ingest_url(&mut kernel, "https://NEWS.example/story/#comments");
+    let kernel = run_server(kernel);
+
+    let id = "https://news.example/story";
+    let (ipc, first) = answer(&kernel.responses[0]);
+    assert_eq!(ipc, "Ok");
+    assert_eq!(first["id"], id);
+    for response in &kernel.responses[1..] {
+        assert_eq!(answer(response).1, json!({ "id": id, "duplicate": true }));
+    }
+    let fetches: Vec<String> = kernel
+        .requests_to("http_client:sys:uqbar")
+        .iter()
+        .map(|sent| {
+            let ipc: Value = serde_json::from_str(sent.request.ipc.as_deref().unwrap()).unwrap();
+            ipc["uri"].as_str().unwrap().to_string()
+        })
+        .filter(|uri| uri.contains("news.example"))
+        .collect();
+    assert_eq!(fetches.len(), 2);
+    let state: State = kernel.get_state().unwrap();
+    assert_eq!(state.documents.len(), 1);
+    assert_eq!(state.documents[id].metadata["url"], id);
+}
+
+#[test]
+fn refuses_pages_that_ask_not_to_be_indexed() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    let mut tagged = html("<p>Members only.</p>");
+    tagged.1["X-Robots-Tag"] = "googlebot: noindex".into();
+    serve_pages(
+        &mut kernel,
+        &pinecone,
+        vec![
+            ("https://example.org/tagged", tagged),
+            (
+                "https://example.org/meta",
+                html("<head><meta name=robots content=noindex></head><p>Draft.</p>"),
+            ),
+        ],
+    );
+    push_ingest_url(&mut kernel, "https://example.org/tagged");
+    push_ingest_url(&mut kernel, "https://example.org/meta");
+    let kernel = run_server(kernel);
+
+    for (response, url) in kernel
+        .responses
+        .iter()
+        .zip(["https://example.org/tagged", "https://example.org/meta"])
+    {
+        let (ipc, _) = answer(response);
+        assert_eq!(ipc["Err"], format!("{} asks not to be indexed", url));
+    }
+    assert!(pinecone.ids("default").is_empty());
+}
+
+#[test]
+fn refuses_pages_it_cannot_fetch_or_read() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_pages(
+        &mut kernel,
+        &pinecone,
+        vec![
+            ("https://example.org/gone", (404, json!({}), "not found")),
+            (
+                "https://example.org/paper",
+                (
+                    200,
+                    json!({ "Content-Type": "application/pdf" }),
+                    "%PDF-1.7",
+                ),
+            ),
+        ],
+    );
+    push_ingest_url(&mut kernel, "https://example.org/gone");
+    push_ingest_url(&mut kernel, "https://example.org/paper");
+    let kernel = run_server(kernel);
+
+    let (ipc, _) = answer(&kernel.responses[0]);
+    assert_eq!(ipc["Err"], "fetching https://example.org/gone returned 404");
+    let (ipc, _) = answer(&kernel.responses[1]);
+    assert_eq!(
+        ipc["Err"],
+        "cannot extract text from application/pdf content"
+    );
+    assert!(pinecone.ids("default").is_empty());
+    assert!(kernel.get_state::<State>().is_none());
+}
+
+fn chunk_match(parent: &str, index: u64, score: f32, text: &str) -> Match {
+    serde_json::from_value(json!({
+        "id": format!("{}#{}", parent, index),
+        "score": score,
+        "metadata": {
+            PARENT_ID_KEY: parent,
+            CHUNK_INDEX_KEY: index,
+            CHUNK_START_KEY: index * 10,
+            CHUNK_END_KEY: index * 10 + 10,
+            CHUNK_TEXT_KEY: text,
+            "publication": "The Rustacean",
+        },
+    }))
+    .unwrap()
+}
+
+fn unchunked() -> Match {
+    serde_json::from_value(json!({ "id": "whole", "score": 0.7 })).unwrap()
+}
+
+#[test]
+fn collapses_chunks_into_their_best_passage() {
+    let documents = collapse_chunks(vec![
+        chunk_match("rust", 2, 0.9, "borrow checker"),
+        unchunked(),
+        chunk_match("bread", 0, 0.6, "knead"),
+        chunk_match("rust", 0, 0.5, "intro"),
+        unchunked(),
+    ]);
+
+    let ids: Vec<&str> = documents.iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(ids, vec!["rust", "whole", "bread"]);
+    let rust = &documents[0];
+    assert_eq!(rust.score, 0.9);
+    let passage = rust.passage.as_ref().unwrap();
+    assert_eq!(passage.text, "borrow checker");
+    assert_eq!(
+        (passage.chunk_index, passage.start, passage.end),
+        (2, 20, 30)
+    );
+    // only the document's own metadata is left
+    assert_eq!(
+        Value::Object(rust.metadata.clone().unwrap()),
+        json!({ "publication": "The Rustacean" })
+    );
+    assert!(documents[1].passage.is_none());
+}
+
+#[test]
+fn collapses_nothing_when_there_are_no_matches() {
+    assert!(collapse_chunks(vec![]).is_empty());
+}
+
+#[test]
+fn clusters_near_duplicates_by_default() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_ingest(&mut kernel);
+    kernel.push_request(
+        &client(),
+        &json!({ "Ingest": { "documents": [{
+            "id": "rust-mirror",
+            "text": "The Rust borrow checker rejects dangling references!",
+            "metadata": { "publication": "Mirror" },
+        }] } })
+        .to_string(),
+        None,
+    );
+    kernel.push_request(
+        &client(),
+        &json!({ "Query": { "text": "rust borrow checker", "topK": 3, "dedupe": 1.1 } })
+            .to_string(),
+        None,
+    );
+    let kernel = run_server(kernel);
+
+    let (_, ingested) = answer(&kernel.responses[1]);
+    assert_eq!(
+        ingested["duplicates"],
+        json!([{ "id": "rust-mirror", "duplicate_of": "rust", "rejected": false }])
+    );
+    // clustered documents are still indexed
+    assert!(pinecone
+        .ids("default")
+        .contains(&"rust-mirror#0".to_string()));
+
+    let (_, body) = answer(&kernel.responses[2]);
+    let ids: Vec<&str> = body["matches"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| m["id"].as_str().unwrap())
+        .collect();
+    assert!(!ids.contains(&"rust-mirror"));
+    let rust = &body["matches"][0];
+    assert_eq!(rust["id"], "rust");
+    assert_eq!(rust["also_published_in"][0]["id"], "rust-mirror");
+    assert_eq!(rust["also_published_in"][0]["publication"], "Mirror");
+}
+
+#[test]
+fn rejects_near_duplicates_when_asked() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_ingest(&mut kernel);
+    kernel.push_request(
+        &client(),
+        &json!({ "Ingest": { "duplicates": "reject", "documents": [{
+            "id": "rust-mirror",
+            "text": "the rust borrow checker rejects dangling references",
+        }] } })
+        .to_string(),
+        None,
+    );
+    let kernel = run_server(kernel);
+
+    let (_, ingested) = answer(&kernel.responses[1]);
+    assert_eq!(ingested["documents"], 0);
+    assert_eq!(ingested["duplicates"][0]["rejected"], true);
+    assert_eq!(pinecone.ids("default"), vec!["bread#0", "rust#0"]);
+    let state: State = kernel.get_state().unwrap();
+    assert!(!state.documents.contains_key("rust-mirror"));
+}
+
+#[test]
+fn never_takes_texts_without_words_for_duplicates() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    kernel.push_request(
+        &client(),
+        &json!({ "Ingest": { "duplicates": "reject", "documents": [
+            { "id": "cheer", "text": "!!!" },
+            { "id": "shrug", "text": "?! ..." },
+        ] } })
+        .to_string(),
+        None,
+    );
+    let kernel = run_server(kernel);
+
+    let (_, ingested) = answer(&kernel.responses[0]);
+    assert_eq!(ingested["documents"], 2);
+    assert_eq!(ingested["duplicates"], json!([]));
+    let state: State = kernel.get_state().unwrap();
+    assert_eq!(state.documents["shrug"].fingerprint, None);
+}
+
+#[test]
+fn reranks_in_process_by_default() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_ingest(&mut kernel);
+    kernel.push_request(
+        &client(),
+        &json!({ "Query": { "text": "bread dough", "topK": 2, "rerank": {} } }).to_string(),
+        None,
+    );
+    let kernel = run_server(kernel);
+
+    let (ipc, body) = answer(&kernel.responses[1]);
+    assert_eq!(ipc, json!("Ok"));
+    assert_eq!(body["matches"][0]["id"], "bread");
+    assert!(body["matches"][0]["rerank_score"].as_f64().unwrap() > 0.0);
+}
+
+#[test]
+fn needs_a_url_to_rerank_over_http() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_ingest(&mut kernel);
+    let query = json!({ "Query": {
+        "text": "bread dough",
+        "topK": 2,
+        "rerank": { "reranker": "http" },
+    } });
+    kernel.push_request(&client(), &query.to_string(), None);
+    kernel.push_request(
+        &terminal(),
+        "reranker HTTP://Reranker.example:80/rerank/",
+        None,
+    );
+    let kernel = run_server(kernel);
+
+    let (ipc, _) = answer(&kernel.responses[1]);
+    assert!(ipc["Err"]
+        .as_str()
+        .unwrap()
+        .contains("no HTTP re-ranker is configured"));
+    assert!(kernel
+        .printed
+        .contains(&"re-ranking with http://reranker.example/rerank".to_string()));
+    let state: State = kernel.get_state().unwrap();
+    assert_eq!(
+        state.reranker_uri.as_deref(),
+        Some("http://reranker.example/rerank")
+    );
+}
+
+#[test]
+fn loads_states_saved_before_they_were_versioned() {
+    let mut kernel = MockKernel::new(our());
+    let old = State {
+        generation: 7,
+        last_ingest: HashMap::from([("default".to_string(), 1)]),
+        ..Default::default()
+    };
+    kernel.state = Some(bincode::serialize(&old).unwrap());
+    kernel.push_request(&terminal(), "reranker off", None);
+    let kernel = run_server(kernel);
+
+    let state: State = kernel.get_state().unwrap();
+    assert_eq!(state.generation, 7);
+    assert_eq!(state.last_ingest["default"], 1);
+}
+
+#[test]
+fn finds_documents_like_another_but_not_the_same() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    kernel.push_request(
+        &client(),
+        &json!({ "Ingest": { "documents": [
+            { "id": "rust", "text": "the rust borrow checker rejects dangling references" },
+            { "id": "lifetimes", "text": "the borrow checker and rust lifetimes" },
+            { "id": "bread", "text": "knead the dough and let the bread rise overnight" },
+        ] } })
+        .to_string(),
+        None,
+    );
+    kernel.push_request(
+        &client(),
+        &json!({ "QueryById": { "id": "rust", "topK": 5 } }).to_string(),
+        None,
+    );
+    kernel.push_request(
+        &client(),
+        &json!({ "QueryById": { "id": "missing", "topK": 5 } }).to_string(),
+        None,
+    );
+    let kernel = run_server(kernel);
+
+    let (_, res) = answer(&kernel.responses[1]);
+    let ids: Vec<&str> = res["matches"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| m["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids[0], "lifetimes");
+    assert!(!ids.contains(&"rust"));
+    let (ipc, _) = answer(&kernel.responses[2]);
+    assert!(ipc["Err"].as_str().unwrap().contains("no document missing"));
+}
+
+#[test]
+fn follows_cursors_across_restarts() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_ingest(&mut kernel);
+    let query = json!({ "text": "rust borrow checker", "topK": 1 });
+    kernel.push_request(&client(), &json!({ "Query": query }).to_string(), None);
+    let mut kernel = run_server(kernel);
+
+    let (_, first) = answer(&kernel.responses[1]);
+    assert_eq!(first["matches"][0]["id"], "rust");
+    let cursor = first["nextCursor"].as_str().unwrap().to_string();
+    assert!(cursor.len() < 120);
+
+    // a restarted server has no results cached, so it ranks them again
+    let mut next = query.clone();
+    next["cursor"] = cursor.clone().into();
+    kernel.push_request(&client(), &json!({ "Query": next }).to_string(), None);
+    let mut other = json!({ "text": "bread", "topK": 1 });
+    other["cursor"] = cursor.into();
+    kernel.push_request(&client(), &json!({ "Query": other }).to_string(), None);
+    kernel.push_request(
+        &client(),
+        &json!({ "Query": { "text": "rust", "cursor": "bm90IGEgY3Vyc29y" } }).to_string(),
+        None,
+    );
+    let kernel = run_server(kernel);
+
+    let (ipc, second) = answer(&kernel.responses[2]);
+    assert_eq!(ipc, "Ok");
+    assert_eq!(second["matches"][0]["id"], "bread");
+    assert!(second["nextCursor"].is_null());
+    let (ipc, _) = answer(&kernel.responses[3]);
+    assert!(ipc["Err"]
+        .as_str()
+        .unwrap()
+        .starts_with("cursor is for another query"));
+    let (ipc, _) = answer(&kernel.responses[4]);
+    assert!(ipc["Err"].is_string());
+}
+
+#[test]
+fn only_lets_our_node_and_admins_change_the_library() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_ingest(&mut kernel);
+    let delete = json!({ "Delete": { "ids": ["rust"] } }).to_string();
+    kernel.push_request(&reader(), &delete, None);
+    kernel.push_request(
+        &reader(),
+        &json!({ "ConfigureAdmins": ["reader.uq"] }).to_string(),
+        None,
+    );
+    kernel.push_request(
+        &reader(),
+        &json!({ "Fetch": { "ids": ["rust"] } }).to_string(),
+        None,
+    );
+    let mut kernel = run_server(kernel);
+
+    let (ipc, _) = answer(&kernel.responses[1]);
+    assert_eq!(ipc["Err"], "reader.uq is not an admin of this library");
+    let (ipc, _) = answer(&kernel.responses[2]);
+    assert!(ipc["Err"].is_string());
+    let (_, fetched) = answer(&kernel.responses[3]);
+    assert_eq!(fetched["documents"][0]["id"], "rust");
+    assert!(kernel.requests_to("/vectors/delete").is_empty());
+
+    kernel.push_request(&terminal(), "admins reader.uq", None);
+    kernel.push_request(&reader(), &delete, None);
+    let kernel = run_server(kernel);
+
+    assert!(kernel.printed.contains(&"admins: reader.uq".to_string()));
+    let (_, deleted) = answer(&kernel.responses[4]);
+    assert_eq!(deleted["deleted"], json!(["rust"]));
+    let state: State = kernel.get_state().unwrap();
+    assert_eq!(state.admins, vec!["reader.uq"]);
+}
+
+#[test]
+fn runs_read_only_commands_for_anyone() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    push_ingest(&mut kernel);
+    for line in ["search borrow checker", "show rust", "stats", "delete rust"] {
+        kernel.push_request(&reader(), &json!({ "Command": line }).to_string(), None);
+    }
+    let kernel = run_server(kernel);
+
+    let outputs: Vec<String> = kernel.responses[1..4]
+        .iter()
+        .map(|response| {
+            let (ipc, res) = answer(response);
+            assert_eq!(ipc, "Ok");
+            res["output"].as_str().unwrap().to_string()
+        })
+        .collect();
+    assert!(outputs[0].contains("rust"), "{}", outputs[0]);
+    assert!(outputs[1].contains("borrow checker"), "{}", outputs[1]);
+    assert!(outputs[2].starts_with("documents: 2"), "{}", outputs[2]);
+    // but the commands that change the library are still checked
+    let (ipc, _) = answer(&kernel.responses[4]);
+    assert_eq!(ipc["Err"], "reader.uq is not an admin of this library");
+    let state: State = kernel.get_state().unwrap();
+    assert!(state.documents.contains_key("rust"));
+}
+
+#[test]
+fn only_lets_our_node_and_admins_configure_the_server() {
+    let mut kernel = MockKernel::new(our());
+    for action in [
+        json!({ "ConfigureQueryLog": {} }),
+        json!({ "ConfigureReranker": "http://localhost:8080" }),
+        json!({ "Analytics": {} }),
+    ] {
+        kernel.push_request(&reader(), &action.to_string(), None);
+    }
+    let kernel = run_server(kernel);
+
+    for response in &kernel.responses {
+        let (ipc, _) = answer(response);
+        assert_eq!(ipc["Err"], "reader.uq is not an admin of this library");
+    }
+    assert_eq!(kernel.responses.len(), 3);
+    assert!(kernel.get_state::<State>().is_none());
+}
+
+#[test]
+fn logs_queries_without_saving_the_state_each_time() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let files = Files::default();
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    serve_fs(&mut kernel, &files);
+    kernel.push_request(&terminal(), "querylog on", None);
+    push_ingest(&mut kernel);
+    push_query(&mut kernel, "rust");
+    let mut kernel = run_server(kernel);
+    let saved = kernel.state.clone();
+
+    // the log's file is already there, so only it changes
+    push_query(&mut kernel, "bread");
+    push_query(&mut kernel, "dough");
+    let mut kernel = run_server(kernel);
+    assert_eq!(kernel.state, saved);
+    assert_eq!(files.borrow().len(), 1);
+
+    kernel.push_request(&terminal(), "analytics", None);
+    let kernel = run_server(kernel);
+    assert!(kernel
+        .printed
+        .iter()
+        .any(|printed| printed.starts_with("queries: 3\n")));
+}
+
+#[test]
+fn keeps_feedback_records_in_files_of_their_own() {
+    let files = Files::default();
+    let mut kernel = MockKernel::new(our());
+    serve_fs(&mut kernel, &files);
+    let vote = json!({ "Feedback": { "id": "rust", "label": "up", "vector": embed("rust") } });
+    kernel.push_request(&client(), &vote.to_string(), None);
+    kernel.push_request(&reader(), &vote.to_string(), None);
+    let kernel = run_server(kernel);
+
+    let (_, res) = answer(&kernel.responses[1]);
+    assert_eq!(res["id"], "rust");
+    let state: State = kernel.get_state().unwrap();
+    assert_eq!(state.votes["rust"].up, 2);
+    let files = files.borrow();
+    assert_eq!(files.len(), 1);
+    let records: Vec<FeedbackRecord> = files
+        .values()
+        .next()
+        .unwrap()
+        .split(|&byte| byte == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(|line| serde_json::from_slice(line).unwrap())
+        .collect();
+    let ids: Vec<&str> = records.iter().map(|record| record.id.as_str()).collect();
+    assert_eq!(ids, ["rust", "rust"]);
+    assert_eq!(records[1].source, "reader.uq");
+}
+
+#[test]
+fn counts_a_vote_only_once_its_record_is_kept() {
+    let mut kernel = MockKernel::new(our());
+    let vote = json!({ "Feedback": { "id": "rust", "label": "up", "vector": embed("rust") } });
+    // nothing answers for the filesystem yet
+    kernel.push_request(&client(), &vote.to_string(), None);
+    let mut kernel = run_server(kernel);
+
+    let (ipc, _) = answer(&kernel.responses[0]);
+    assert!(ipc["Err"].as_str().unwrap().contains("filesystem"));
+    let state: Option<State> = kernel.get_state();
+    assert!(state.map_or(true, |state| state.votes.is_empty()));
+
+    let files = Files::default();
+    serve_fs(&mut kernel, &files);
+    kernel.push_request(&client(), &vote.to_string(), None);
+    let kernel = run_server(kernel);
+
+    let state: State = kernel.get_state().unwrap();
+    assert_eq!(state.votes["rust"].up, 1);
+    assert_eq!(files.borrow().len(), 1);
+}
+
+#[test]
+fn filters_queries_on_metadata() {
+    let pinecone = MockPinecone::new(DIMENSION);
+    let mut kernel = MockKernel::new(our());
+    serve_http(&mut kernel, &pinecone);
+    kernel.push_request(
+        &client(),
+        &json!({ "Ingest": { "documents": [
+            { "id": "old", "text": "the rust borrow checker", "metadata": { "year": 2015, "tags": ["rust"] } },
+            { "id": "new", "text": "the rust borrow checker again", "metadata": { "year": 2023, "tags": ["rust", "news"] } },
+            { "id": "bread", "text": "knead the dough", "metadata": { "year": 2023, "tags": ["food"] } },
+        ] } })
+        .to_string(),
+        None,
+    );
+    for filter in [
+        json!({ "year": { "$gte": 2020 } }),
+        json!({ "tags": "rust", "year": { "$lt": 2020 } }),
+        json!({ "$or": [{ "tags": { "$in": ["food"] } }, { "year": 2015 }] }),
+        json!({ "year": { "$regex": "20.*" } }),
+    ] {
+        kernel.push_request(
+            &client(),
+            &json!({ "Query": { "text": "rust borrow checker", "topK": 5, "filter": filter } })
+                .to_string(),
+            None,
+        );
+    }
+    let kernel = run_server(kernel);
+
+    let ids = |i: usize| {
+        let (_, res) = answer(&kernel.responses[i]);
+        let mut ids: Vec<String> = res["matches"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["id"].as_str().unwrap().to_string())
+            .collect();
+        ids.sort();
+        ids
+    };
+    assert_eq!(ids(1), ["bread", "new"]);
+    assert_eq!(ids(2), ["old"]);
+    assert_eq!(ids(3), ["bread", "old"]);
+    let (ipc, _) = answer(&kernel.responses[4]);
+    assert!(ipc["Err"].as_str().unwrap().contains("$regex"));
+}